@@ -23,7 +23,7 @@ use crate::widget;
 use crate::{Alignment, Length, Padding, Point, Size};
 
 /// The main axis of a flex layout.
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Axis {
     /// The horizontal axis
     Horizontal,
@@ -33,21 +33,21 @@ pub enum Axis {
 }
 
 impl Axis {
-    fn main(&self, size: Size) -> f32 {
+    pub(crate) fn main(&self, size: Size) -> f32 {
         match self {
             Axis::Horizontal => size.width,
             Axis::Vertical => size.height,
         }
     }
 
-    fn cross(&self, size: Size) -> f32 {
+    pub(crate) fn cross(&self, size: Size) -> f32 {
         match self {
             Axis::Horizontal => size.height,
             Axis::Vertical => size.width,
         }
     }
 
-    fn pack<T>(&self, main: T, cross: T) -> (T, T) {
+    pub(crate) fn pack<T>(&self, main: T, cross: T) -> (T, T) {
         match self {
             Axis::Horizontal => (main, cross),
             Axis::Vertical => (cross, main),
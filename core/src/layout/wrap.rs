@@ -0,0 +1,385 @@
+//! Distribute elements in lines that wrap when running out of space.
+use crate::layout::flex::Axis;
+use crate::layout::{Limits, Node};
+use crate::widget;
+use crate::{Alignment, Element, Length, Padding, Point, Size};
+
+/// Computes the wrap layout with the given axis and limits, applying
+/// spacing, padding and alignment to the items as needed.
+///
+/// Items are placed one after another along the main axis. Whenever an item
+/// does not fit in the current line, a new line is started after the
+/// previous one along the cross axis.
+///
+/// - `spacing` is the space between items of the same line.
+/// - `line_spacing` is the space between lines.
+/// - `align_items` aligns the items of a line along the cross axis.
+/// - `align_lines` aligns each line along the main axis.
+///
+/// It returns a new layout [`Node`].
+pub fn resolve<Message, Theme, Renderer>(
+    axis: Axis,
+    renderer: &Renderer,
+    limits: &Limits,
+    width: Length,
+    height: Length,
+    padding: Padding,
+    spacing: f32,
+    line_spacing: f32,
+    align_items: Alignment,
+    align_lines: Alignment,
+    items: &[Element<'_, Message, Theme, Renderer>],
+    trees: &mut [widget::Tree],
+) -> Node
+where
+    Renderer: crate::Renderer,
+{
+    let limits = limits.width(width).height(height).shrink(padding);
+    let max_main = axis.main(limits.max());
+    let child_limits = Limits::new(Size::ZERO, limits.max());
+
+    let mut nodes: Vec<Node> = items
+        .iter()
+        .zip(trees)
+        .map(|(child, tree)| {
+            child.as_widget().layout(tree, renderer, &child_limits)
+        })
+        .collect();
+
+    let lines =
+        break_lines(&axis, nodes.iter().map(Node::size), max_main, spacing);
+
+    let intrinsic_main = lines.iter().map(|line| line.main).fold(0.0, f32::max);
+
+    let intrinsic_cross = lines.iter().map(|line| line.cross).sum::<f32>()
+        + line_spacing * lines.len().saturating_sub(1) as f32;
+
+    let (intrinsic_width, intrinsic_height) =
+        axis.pack(intrinsic_main, intrinsic_cross);
+
+    let size = limits.resolve(
+        width,
+        height,
+        Size::new(intrinsic_width, intrinsic_height),
+    );
+
+    let available = axis.main(size);
+    let pad = axis.pack(padding.left, padding.top);
+    let mut cross = pad.1;
+
+    for line in &lines {
+        let mut main = pad.0 + align(align_lines, available - line.main);
+
+        for node in &mut nodes[line.start..line.end] {
+            let size = node.size();
+            let offset = align(align_items, line.cross - axis.cross(size));
+            let (x, y) = axis.pack(main, cross + offset);
+
+            node.move_to_mut(Point::new(x, y));
+
+            main += axis.main(size) + spacing;
+        }
+
+        cross += line.cross + line_spacing;
+    }
+
+    Node::with_children(size.expand(padding), nodes)
+}
+
+/// A line of items of a wrap layout.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Line {
+    start: usize,
+    end: usize,
+    main: f32,
+    cross: f32,
+}
+
+/// Splits the items with the given sizes in lines that fit in `max_main`.
+///
+/// A line always contains at least one item, even if it does not fit.
+fn break_lines(
+    axis: &Axis,
+    sizes: impl Iterator<Item = Size>,
+    max_main: f32,
+    spacing: f32,
+) -> Vec<Line> {
+    let mut lines = Vec::new();
+    let mut line = Line {
+        start: 0,
+        end: 0,
+        main: 0.0,
+        cross: 0.0,
+    };
+
+    for (i, size) in sizes.enumerate() {
+        let main = axis.main(size);
+
+        if line.end > line.start {
+            if line.main + spacing + main > max_main {
+                lines.push(line);
+
+                line = Line {
+                    start: i,
+                    end: i,
+                    main: 0.0,
+                    cross: 0.0,
+                };
+            } else {
+                line.main += spacing;
+            }
+        }
+
+        line.main += main;
+        line.cross = line.cross.max(axis.cross(size));
+        line.end = i + 1;
+    }
+
+    if line.end > line.start {
+        lines.push(line);
+    }
+
+    lines
+}
+
+fn align(alignment: Alignment, space: f32) -> f32 {
+    match alignment {
+        Alignment::Start => 0.0,
+        Alignment::Center => (space / 2.0).max(0.0),
+        Alignment::End => space.max(0.0),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::layout::Layout;
+    use crate::mouse;
+    use crate::renderer::{self, Null};
+    use crate::{Rectangle, Widget};
+
+    struct Block(Size);
+
+    impl Widget<(), (), Null> for Block {
+        fn size(&self) -> Size<Length> {
+            Size::new(Length::Fixed(self.0.width), Length::Fixed(self.0.height))
+        }
+
+        fn layout(
+            &self,
+            _tree: &mut widget::Tree,
+            _renderer: &Null,
+            _limits: &Limits,
+        ) -> Node {
+            Node::new(self.0)
+        }
+
+        fn draw(
+            &self,
+            _tree: &widget::Tree,
+            _renderer: &mut Null,
+            _theme: &(),
+            _style: &renderer::Style,
+            _layout: Layout<'_>,
+            _cursor: mouse::Cursor,
+            _viewport: &Rectangle,
+        ) {
+        }
+    }
+
+    fn layout(
+        axis: Axis,
+        max: Size,
+        spacing: f32,
+        line_spacing: f32,
+        align_items: Alignment,
+        align_lines: Alignment,
+        blocks: &[(f32, f32)],
+    ) -> Node {
+        let items: Vec<Element<'_, (), (), Null>> = blocks
+            .iter()
+            .map(|(width, height)| {
+                Element::new(Block(Size::new(*width, *height)))
+            })
+            .collect();
+
+        let mut trees: Vec<_> = items.iter().map(widget::Tree::new).collect();
+
+        resolve(
+            axis,
+            &Null,
+            &Limits::new(Size::ZERO, max),
+            Length::Shrink,
+            Length::Shrink,
+            Padding::ZERO,
+            spacing,
+            line_spacing,
+            align_items,
+            align_lines,
+            &items,
+            &mut trees,
+        )
+    }
+
+    fn positions(node: &Node) -> Vec<(f32, f32)> {
+        node.children()
+            .iter()
+            .map(|child| (child.bounds().x, child.bounds().y))
+            .collect()
+    }
+
+    #[test]
+    fn single_line() {
+        let node = layout(
+            Axis::Horizontal,
+            Size::new(100.0, f32::INFINITY),
+            10.0,
+            5.0,
+            Alignment::Start,
+            Alignment::Start,
+            &[(20.0, 10.0), (20.0, 10.0), (20.0, 10.0)],
+        );
+
+        assert_eq!(node.size(), Size::new(80.0, 10.0));
+        assert_eq!(
+            positions(&node),
+            vec![(0.0, 0.0), (30.0, 0.0), (60.0, 0.0)]
+        );
+    }
+
+    #[test]
+    fn wraps_horizontally() {
+        let node = layout(
+            Axis::Horizontal,
+            Size::new(70.0, f32::INFINITY),
+            10.0,
+            5.0,
+            Alignment::Start,
+            Alignment::Start,
+            &[(20.0, 10.0), (30.0, 20.0), (20.0, 10.0), (40.0, 10.0)],
+        );
+
+        assert_eq!(node.size(), Size::new(70.0, 35.0));
+        assert_eq!(
+            positions(&node),
+            vec![(0.0, 0.0), (30.0, 0.0), (0.0, 25.0), (30.0, 25.0)]
+        );
+    }
+
+    #[test]
+    fn wraps_vertically() {
+        let node = layout(
+            Axis::Vertical,
+            Size::new(f32::INFINITY, 50.0),
+            0.0,
+            10.0,
+            Alignment::Start,
+            Alignment::Start,
+            &[(10.0, 20.0), (20.0, 20.0), (10.0, 20.0)],
+        );
+
+        assert_eq!(node.size(), Size::new(40.0, 40.0));
+        assert_eq!(
+            positions(&node),
+            vec![(0.0, 0.0), (0.0, 20.0), (30.0, 0.0)]
+        );
+    }
+
+    #[test]
+    fn oversized_item_takes_its_own_line() {
+        let node = layout(
+            Axis::Horizontal,
+            Size::new(50.0, f32::INFINITY),
+            10.0,
+            0.0,
+            Alignment::Start,
+            Alignment::Start,
+            &[(20.0, 10.0), (80.0, 10.0), (20.0, 10.0)],
+        );
+
+        assert_eq!(node.size(), Size::new(50.0, 30.0));
+        assert_eq!(
+            positions(&node),
+            vec![(0.0, 0.0), (0.0, 10.0), (0.0, 20.0)]
+        );
+    }
+
+    #[test]
+    fn aligns_items_in_line() {
+        let node = layout(
+            Axis::Horizontal,
+            Size::new(100.0, f32::INFINITY),
+            0.0,
+            0.0,
+            Alignment::Center,
+            Alignment::Start,
+            &[(20.0, 30.0), (20.0, 10.0)],
+        );
+
+        assert_eq!(positions(&node), vec![(0.0, 0.0), (20.0, 10.0)]);
+
+        let node = layout(
+            Axis::Horizontal,
+            Size::new(100.0, f32::INFINITY),
+            0.0,
+            0.0,
+            Alignment::End,
+            Alignment::Start,
+            &[(20.0, 30.0), (20.0, 10.0)],
+        );
+
+        assert_eq!(positions(&node), vec![(0.0, 0.0), (20.0, 20.0)]);
+    }
+
+    #[test]
+    fn aligns_lines() {
+        let blocks = [(40.0, 10.0), (40.0, 10.0), (20.0, 10.0)];
+
+        let node = layout(
+            Axis::Horizontal,
+            Size::new(90.0, f32::INFINITY),
+            10.0,
+            0.0,
+            Alignment::Start,
+            Alignment::Center,
+            &blocks,
+        );
+
+        assert_eq!(
+            positions(&node),
+            vec![(0.0, 0.0), (50.0, 0.0), (35.0, 10.0)]
+        );
+
+        let node = layout(
+            Axis::Horizontal,
+            Size::new(90.0, f32::INFINITY),
+            10.0,
+            0.0,
+            Alignment::Start,
+            Alignment::End,
+            &blocks,
+        );
+
+        assert_eq!(
+            positions(&node),
+            vec![(0.0, 0.0), (50.0, 0.0), (70.0, 10.0)]
+        );
+    }
+
+    #[test]
+    fn empty() {
+        let node = layout(
+            Axis::Horizontal,
+            Size::new(100.0, f32::INFINITY),
+            10.0,
+            10.0,
+            Alignment::Start,
+            Alignment::Start,
+            &[],
+        );
+
+        assert_eq!(node.size(), Size::ZERO);
+        assert!(node.children().is_empty());
+    }
+}
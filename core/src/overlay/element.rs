@@ -195,6 +195,10 @@ where
             fn custom(&mut self, state: &mut dyn Any, id: Option<&widget::Id>) {
                 self.operation.custom(state, id);
             }
+
+            fn bounds(&mut self, id: Option<&widget::Id>, bounds: Rectangle) {
+                self.operation.bounds(id, bounds);
+            }
         }
 
         self.content
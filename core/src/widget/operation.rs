@@ -47,6 +47,12 @@ pub trait Operation<T> {
     /// Operates on a custom widget with some state.
     fn custom(&mut self, _state: &mut dyn Any, _id: Option<&Id>) {}
 
+    /// Operates on a widget that occupies the given bounds, without
+    /// containing other widgets.
+    ///
+    /// It is called right before the other operations on the same widget.
+    fn bounds(&mut self, _id: Option<&Id>, _bounds: Rectangle) {}
+
     /// Finishes the [`Operation`] and returns its [`Outcome`].
     fn finish(&self) -> Outcome<T> {
         Outcome::None
@@ -151,6 +157,10 @@ where
                 fn custom(&mut self, state: &mut dyn Any, id: Option<&Id>) {
                     self.operation.custom(state, id);
                 }
+
+                fn bounds(&mut self, id: Option<&Id>, bounds: Rectangle) {
+                    self.operation.bounds(id, bounds);
+                }
             }
 
             let Self { operation, .. } = self;
@@ -183,6 +193,10 @@ where
             self.operation.custom(state, id);
         }
 
+        fn bounds(&mut self, id: Option<&Id>, bounds: Rectangle) {
+            self.operation.bounds(id, bounds);
+        }
+
         fn finish(&self) -> Outcome<B> {
             match self.operation.finish() {
                 Outcome::None => Outcome::None,
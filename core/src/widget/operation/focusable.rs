@@ -1,4 +1,5 @@
 //! Operate on widgets that can be focused.
+use crate::widget::operation::scrollable::{self, Anchor};
 use crate::widget::operation::{Operation, Outcome};
 use crate::widget::Id;
 use crate::Rectangle;
@@ -58,18 +59,20 @@ pub fn focus<T>(target: Id) -> impl Operation<T> {
 
 /// Produces an [`Operation`] that generates a [`Count`] and chains it with the
 /// provided function to build a new [`Operation`].
-pub fn count<T, O>(f: fn(Count) -> O) -> impl Operation<T>
+pub fn count<T, O, F>(f: F) -> impl Operation<T>
 where
     O: Operation<T> + 'static,
+    F: Fn(Count) -> O,
 {
-    struct CountFocusable<O> {
+    struct CountFocusable<F> {
         count: Count,
-        next: fn(Count) -> O,
+        next: F,
     }
 
-    impl<T, O> Operation<T> for CountFocusable<O>
+    impl<T, O, F> Operation<T> for CountFocusable<F>
     where
         O: Operation<T> + 'static,
+        F: Fn(Count) -> O,
     {
         fn focusable(&mut self, state: &mut dyn Focusable, _id: Option<&Id>) {
             if state.is_focused() {
@@ -103,9 +106,20 @@ where
 /// - if found, focuses the previous focusable widget.
 /// - if not found, focuses the last focusable widget.
 pub fn focus_previous<T>() -> impl Operation<T> {
+    focus_previous_with(None)
+}
+
+/// Produces an [`Operation`] that focuses the previous focusable widget, like
+/// [`focus_previous`], and then scrolls it into view with the given [`Anchor`].
+pub fn focus_previous_into_view<T>(anchor: Anchor) -> impl Operation<T> {
+    focus_previous_with(Some(anchor))
+}
+
+fn focus_previous_with<T>(anchor: Option<Anchor>) -> impl Operation<T> {
     struct FocusPrevious {
         count: Count,
         current: usize,
+        anchor: Option<Anchor>,
     }
 
     impl<T> Operation<T> for FocusPrevious {
@@ -134,18 +148,37 @@ pub fn focus_previous<T>() -> impl Operation<T> {
         ) {
             operate_on_children(self);
         }
+
+        fn finish(&self) -> Outcome<T> {
+            reveal(self.anchor)
+        }
     }
 
-    count(|count| FocusPrevious { count, current: 0 })
+    count(move |count| FocusPrevious {
+        count,
+        current: 0,
+        anchor,
+    })
 }
 
 /// Produces an [`Operation`] that searches for the current focused widget, and
 /// - if found, focuses the next focusable widget.
 /// - if not found, focuses the first focusable widget.
 pub fn focus_next<T>() -> impl Operation<T> {
+    focus_next_with(None)
+}
+
+/// Produces an [`Operation`] that focuses the next focusable widget, like
+/// [`focus_next`], and then scrolls it into view with the given [`Anchor`].
+pub fn focus_next_into_view<T>(anchor: Anchor) -> impl Operation<T> {
+    focus_next_with(Some(anchor))
+}
+
+fn focus_next_with<T>(anchor: Option<Anchor>) -> impl Operation<T> {
     struct FocusNext {
         count: Count,
         current: usize,
+        anchor: Option<Anchor>,
     }
 
     impl<T> Operation<T> for FocusNext {
@@ -168,9 +201,28 @@ pub fn focus_next<T>() -> impl Operation<T> {
         ) {
             operate_on_children(self);
         }
+
+        fn finish(&self) -> Outcome<T> {
+            reveal(self.anchor)
+        }
     }
 
-    count(|count| FocusNext { count, current: 0 })
+    count(move |count| FocusNext {
+        count,
+        current: 0,
+        anchor,
+    })
+}
+
+/// Chains an [`Operation`] that scrolls the focused widget into view, if an
+/// [`Anchor`] is given.
+fn reveal<T>(anchor: Option<Anchor>) -> Outcome<T> {
+    match anchor {
+        Some(anchor) => Outcome::Chain(Box::new(
+            scrollable::ScrollIntoView::focused(anchor),
+        )),
+        None => Outcome::None,
+    }
 }
 
 /// Produces an [`Operation`] that searches for the current focused widget
@@ -1,4 +1,5 @@
 //! Operate on widgets that can be scrolled.
+use crate::widget::operation::{Focusable, Outcome};
 use crate::widget::{Id, Operation};
 use crate::{Rectangle, Vector};
 
@@ -9,6 +10,12 @@ pub trait Scrollable {
 
     /// Scroll the widget to the given [`AbsoluteOffset`] along the horizontal & vertical axis.
     fn scroll_to(&mut self, offset: AbsoluteOffset);
+
+    /// Scrolls the widget by the given [`AbsoluteOffset`] along the horizontal & vertical axis,
+    /// in the direction of its translation.
+    ///
+    /// By default, it does nothing; so [`scroll_into_view`] leaves the widget as it is.
+    fn scroll_by(&mut self, _offset: AbsoluteOffset) {}
 }
 
 /// Produces an [`Operation`] that snaps the widget with the given [`Id`] to
@@ -79,6 +86,294 @@ pub fn scroll_to<T>(target: Id, offset: AbsoluteOffset) -> impl Operation<T> {
     ScrollTo { target, offset }
 }
 
+/// Produces an [`Operation`] that scrolls every [`Scrollable`] containing the
+/// widget with the given [`Id`], so that the widget becomes visible.
+///
+/// The widget is found among the containers and the widgets reporting their
+/// bounds with [`Operation::bounds`].
+pub fn scroll_into_view<T>(target: Id, anchor: Anchor) -> impl Operation<T> {
+    ScrollIntoView::new(Target::Id(target), anchor)
+}
+
+/// Produces an [`Operation`] that scrolls every [`Scrollable`] containing the
+/// focused widget, so that the widget becomes visible.
+///
+/// The bounds of the focused widget are those it reports with
+/// [`Operation::bounds`] or, otherwise, those of its innermost container.
+pub fn scroll_focused_into_view<T>(anchor: Anchor) -> impl Operation<T> {
+    ScrollIntoView::focused(anchor)
+}
+
+/// Where a widget is placed in a [`Scrollable`] when scrolled into view.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Anchor {
+    /// The widget is aligned to the start of the [`Scrollable`].
+    Start,
+    /// The widget is centered in the [`Scrollable`].
+    Center,
+    /// The widget is aligned to the end of the [`Scrollable`].
+    End,
+    /// The [`Scrollable`] is scrolled as little as possible; not at all if
+    /// the widget is already visible.
+    #[default]
+    Nearest,
+}
+
+impl Anchor {
+    /// Returns the change in translation needed to show the segment starting
+    /// at `position` with the given `length` in the given viewport.
+    fn delta(
+        self,
+        position: f32,
+        length: f32,
+        viewport: f32,
+        viewport_length: f32,
+    ) -> f32 {
+        let start = position - viewport;
+        let end = position + length - (viewport + viewport_length);
+
+        match self {
+            Anchor::Start => start,
+            Anchor::Center => (start + end) / 2.0,
+            Anchor::End => end,
+            Anchor::Nearest if start < 0.0 || length > viewport_length => start,
+            Anchor::Nearest => end.max(0.0),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Target {
+    Id(Id),
+    Focused,
+}
+
+/// A [`Scrollable`] enclosing the target of a [`ScrollIntoView`] operation.
+#[derive(Debug, Clone, Copy)]
+struct Ancestor {
+    /// The position of the [`Scrollable`] in the widget tree.
+    index: usize,
+    bounds: Rectangle,
+    translation: Vector,
+    depth: usize,
+}
+
+#[derive(Debug)]
+pub(super) struct ScrollIntoView {
+    target: Target,
+    anchor: Anchor,
+    depth: usize,
+    scrollables: usize,
+    ancestors: Vec<Ancestor>,
+    containers: Vec<Rectangle>,
+    /// The bounds reported by the last widget, if it has not been operated on
+    /// yet.
+    bounds: Option<Rectangle>,
+    found: Option<(Rectangle, Vec<Ancestor>)>,
+}
+
+impl ScrollIntoView {
+    fn new(target: Target, anchor: Anchor) -> Self {
+        Self {
+            target,
+            anchor,
+            depth: 0,
+            scrollables: 0,
+            ancestors: Vec::new(),
+            containers: Vec::new(),
+            bounds: None,
+            found: None,
+        }
+    }
+
+    pub(super) fn focused(anchor: Anchor) -> Self {
+        Self::new(Target::Focused, anchor)
+    }
+
+    /// Returns whether the widget with the given [`Id`] is the target.
+    fn is_target(&self, id: Option<&Id>) -> bool {
+        matches!(&self.target, Target::Id(target) if id == Some(target))
+    }
+
+    /// Returns the [`Ancestor`] scrollables enclosing the current widget.
+    fn enclosing(&self) -> Vec<Ancestor> {
+        self.ancestors
+            .iter()
+            .filter(|ancestor| ancestor.depth < self.depth)
+            .copied()
+            .collect()
+    }
+
+    /// Computes the offsets to scroll each [`Ancestor`] by, from the innermost
+    /// to the outermost one.
+    fn offsets(&self) -> Vec<(usize, AbsoluteOffset)> {
+        let Some((mut bounds, ancestors)) = self.found.clone() else {
+            return Vec::new();
+        };
+
+        let mut offsets = Vec::new();
+
+        for ancestor in ancestors.iter().rev() {
+            let viewport = ancestor.bounds + ancestor.translation;
+
+            let delta = Vector::new(
+                self.anchor.delta(
+                    bounds.x,
+                    bounds.width,
+                    viewport.x,
+                    viewport.width,
+                ),
+                self.anchor.delta(
+                    bounds.y,
+                    bounds.height,
+                    viewport.y,
+                    viewport.height,
+                ),
+            );
+
+            if delta != Vector::ZERO {
+                offsets.push((
+                    ancestor.index,
+                    AbsoluteOffset {
+                        x: delta.x,
+                        y: delta.y,
+                    },
+                ));
+            }
+
+            let shown = bounds - (ancestor.translation + delta);
+
+            bounds = shown.intersection(&ancestor.bounds).unwrap_or(shown);
+        }
+
+        offsets
+    }
+}
+
+impl<T> Operation<T> for ScrollIntoView {
+    fn container(
+        &mut self,
+        id: Option<&Id>,
+        bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        if self.found.is_some() {
+            return;
+        }
+
+        if self.is_target(id) {
+            self.found = Some((bounds, self.enclosing()));
+            return;
+        }
+
+        self.bounds = None;
+        self.depth += 1;
+        self.containers.push(bounds);
+
+        operate_on_children(self);
+
+        let _ = self.containers.pop();
+        self.depth -= 1;
+
+        match self.ancestors.last() {
+            Some(ancestor) if ancestor.depth == self.depth => {
+                let _ = self.ancestors.pop();
+            }
+            _ => {}
+        }
+    }
+
+    fn scrollable(
+        &mut self,
+        _state: &mut dyn Scrollable,
+        _id: Option<&Id>,
+        bounds: Rectangle,
+        translation: Vector,
+    ) {
+        self.bounds = None;
+        self.ancestors.push(Ancestor {
+            index: self.scrollables,
+            bounds,
+            translation,
+            depth: self.depth,
+        });
+
+        self.scrollables += 1;
+    }
+
+    fn focusable(&mut self, state: &mut dyn Focusable, _id: Option<&Id>) {
+        let bounds = self.bounds.take().or(self.containers.last().copied());
+
+        if let Target::Focused = self.target {
+            if self.found.is_none() && state.is_focused() {
+                if let Some(bounds) = bounds {
+                    self.found = Some((bounds, self.enclosing()));
+                }
+            }
+        }
+    }
+
+    fn bounds(&mut self, id: Option<&Id>, bounds: Rectangle) {
+        if self.found.is_some() {
+            return;
+        }
+
+        if self.is_target(id) {
+            self.found = Some((bounds, self.enclosing()));
+        }
+
+        self.bounds = Some(bounds);
+    }
+
+    fn finish(&self) -> Outcome<T> {
+        let offsets = self.offsets();
+
+        if offsets.is_empty() {
+            return Outcome::None;
+        }
+
+        Outcome::Chain(Box::new(ScrollBy {
+            offsets,
+            scrollables: 0,
+        }))
+    }
+}
+
+/// Scrolls the scrollables at the given positions in the widget tree.
+struct ScrollBy {
+    offsets: Vec<(usize, AbsoluteOffset)>,
+    scrollables: usize,
+}
+
+impl<T> Operation<T> for ScrollBy {
+    fn container(
+        &mut self,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        operate_on_children(self);
+    }
+
+    fn scrollable(
+        &mut self,
+        state: &mut dyn Scrollable,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+        _translation: Vector,
+    ) {
+        if let Some((_, offset)) = self
+            .offsets
+            .iter()
+            .find(|(index, _)| *index == self.scrollables)
+        {
+            state.scroll_by(*offset);
+        }
+
+        self.scrollables += 1;
+    }
+}
+
 /// The amount of absolute offset in each direction of a [`Scrollable`].
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
 pub struct AbsoluteOffset {
@@ -106,3 +401,55 @@ impl RelativeOffset {
     /// A relative offset that points to the bottom-right of a [`Scrollable`].
     pub const END: Self = Self { x: 1.0, y: 1.0 };
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Point, Size};
+
+    fn ancestor(index: usize, y: f32, translation: f32) -> Ancestor {
+        Ancestor {
+            index,
+            bounds: Rectangle::new(Point::new(0.0, y), Size::new(100.0, 100.0)),
+            translation: Vector::new(0.0, translation),
+            depth: index,
+        }
+    }
+
+    fn offsets(anchor: Anchor, y: f32, ancestors: Vec<Ancestor>) -> Vec<f32> {
+        let mut operation =
+            ScrollIntoView::new(Target::Id(Id::new("target")), anchor);
+
+        operation.found = Some((
+            Rectangle::new(Point::new(0.0, y), Size::new(100.0, 20.0)),
+            ancestors,
+        ));
+
+        operation
+            .offsets()
+            .into_iter()
+            .map(|(_, offset)| offset.y)
+            .collect()
+    }
+
+    #[test]
+    fn anchors_align_the_target() {
+        let ancestors = vec![ancestor(0, 0.0, 50.0)];
+
+        assert_eq!(offsets(Anchor::Start, 300.0, ancestors.clone()), [250.0]);
+        assert_eq!(offsets(Anchor::Center, 300.0, ancestors.clone()), [210.0]);
+        assert_eq!(offsets(Anchor::End, 300.0, ancestors.clone()), [170.0]);
+        assert_eq!(offsets(Anchor::Nearest, 300.0, ancestors.clone()), [170.0]);
+        assert_eq!(offsets(Anchor::Nearest, 10.0, ancestors.clone()), [-40.0]);
+        assert!(offsets(Anchor::Nearest, 100.0, ancestors).is_empty());
+    }
+
+    #[test]
+    fn nested_scrollables_are_scrolled_from_the_inside_out() {
+        let ancestors = vec![ancestor(0, 0.0, 0.0), ancestor(1, 200.0, 0.0)];
+
+        // The inner scrollable shows the target at its bottom, at y = 280;
+        // then the outer scrollable shows the inner one down to that point.
+        assert_eq!(offsets(Anchor::Nearest, 500.0, ancestors), [220.0, 200.0]);
+    }
+}
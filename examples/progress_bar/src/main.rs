@@ -1,5 +1,5 @@
-use iced::widget::{column, progress_bar, slider};
-use iced::{Element, Sandbox, Settings};
+use iced::widget::{column, linear_progress, progress_bar, row, slider};
+use iced::{Element, Length, Sandbox, Settings};
 
 pub fn main() -> iced::Result {
     Progress::run(Settings::default())
@@ -33,10 +33,21 @@ impl Sandbox for Progress {
     }
 
     fn view(&self) -> Element<Message> {
-        column![
-            progress_bar(0.0..=100.0, self.value),
-            slider(0.0..=100.0, self.value, Message::SliderChanged).step(0.01)
+        let label = format!("{:.0}%", self.value);
+
+        row![
+            column![
+                progress_bar(0.0..=100.0, self.value).label(label.clone()),
+                slider(0.0..=100.0, self.value, Message::SliderChanged)
+                    .step(0.01),
+                linear_progress().width(Length::Fill),
+            ]
+            .spacing(20),
+            progress_bar(0.0..=100.0, self.value)
+                .vertical(true)
+                .label(label),
         ]
+        .spacing(20)
         .padding(20)
         .into()
     }
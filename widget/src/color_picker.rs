@@ -0,0 +1,1436 @@
+//! Pick a color from a saturation/value square, sliders, or text.
+use crate::core::event::{self, Event};
+use crate::core::gradient;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::text;
+use crate::core::touch;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::{self, operation, Operation};
+use crate::core::{
+    Background, Border, Clipboard, Color, Element, Layout, Length, Pixels,
+    Point, Radians, Rectangle, Shell, Size, Theme, Widget,
+};
+use crate::text_input::{self, TextInput};
+
+use std::f32::consts::{FRAC_PI_2, PI};
+
+/// A widget to pick a [`Color`].
+///
+/// A [`ColorPicker`] shows a square to pick the saturation and value of the
+/// color, sliders for its hue and alpha, and text inputs for its hex, RGB,
+/// and HSL representations. It can also show a palette of swatches to pick
+/// from.
+///
+/// Apps can implement an eyedropper by handling the message set with
+/// [`ColorPicker::on_eyedropper`]: the [`ColorPicker`] only shows a button
+/// that produces it, and the picked color is expected to be fed back as the
+/// color of the [`ColorPicker`].
+#[allow(missing_debug_implementations)]
+pub struct ColorPicker<
+    'a,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> where
+    Renderer: text::Renderer,
+{
+    color: Color,
+    on_change: Box<dyn Fn(Color) -> Message + 'a>,
+    on_eyedropper: Option<Message>,
+    swatches: Vec<Color>,
+    width: Length,
+    text_size: Option<Pixels>,
+    font: Option<Renderer::Font>,
+    style: Style<Theme>,
+    input_style: text_input::Style<Theme>,
+}
+
+impl<'a, Message, Theme, Renderer> ColorPicker<'a, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    /// The default width of a [`ColorPicker`].
+    pub const DEFAULT_WIDTH: f32 = 256.0;
+
+    /// Creates a new [`ColorPicker`] with the given [`Color`] and the message
+    /// to produce when it changes.
+    pub fn new(color: Color, on_change: impl Fn(Color) -> Message + 'a) -> Self
+    where
+        Theme: DefaultStyle + text_input::DefaultStyle,
+    {
+        Self {
+            color,
+            on_change: Box::new(on_change),
+            on_eyedropper: None,
+            swatches: Vec::new(),
+            width: Length::Fixed(Self::DEFAULT_WIDTH),
+            text_size: None,
+            font: None,
+            style: <Theme as DefaultStyle>::default_style(),
+            input_style: <Theme as text_input::DefaultStyle>::default_style(),
+        }
+    }
+
+    /// Sets the palette of swatches of the [`ColorPicker`].
+    pub fn swatches(
+        mut self,
+        swatches: impl IntoIterator<Item = Color>,
+    ) -> Self {
+        self.swatches = swatches.into_iter().collect();
+        self
+    }
+
+    /// Sets the message produced when the eyedropper button of the
+    /// [`ColorPicker`] is pressed.
+    ///
+    /// Unless this method is called, the button is not shown.
+    pub fn on_eyedropper(mut self, message: Message) -> Self {
+        self.on_eyedropper = Some(message);
+        self
+    }
+
+    /// Sets the width of the [`ColorPicker`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the text size of the text inputs of the [`ColorPicker`].
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(size.into());
+        self
+    }
+
+    /// Sets the font of the text inputs of the [`ColorPicker`].
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the style of the [`ColorPicker`].
+    pub fn style(mut self, style: fn(&Theme) -> Appearance) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Sets the style of the text inputs of the [`ColorPicker`].
+    pub fn input_style(
+        mut self,
+        style: fn(&Theme, text_input::Status) -> text_input::Appearance,
+    ) -> Self {
+        self.input_style = style;
+        self
+    }
+
+    /// Builds the text inputs of the [`ColorPicker`].
+    ///
+    /// Their labels are only shown if a `font` is provided.
+    fn inputs(
+        &self,
+        state: &State,
+        font: Option<Renderer::Font>,
+    ) -> Vec<Element<'_, Input, Theme, Renderer>> {
+        let hsv = state.hsv(self.color);
+
+        Channel::ALL
+            .into_iter()
+            .map(|channel| {
+                let value = match &state.editing {
+                    Some((editing, value)) if *editing == channel => {
+                        value.clone()
+                    }
+                    _ => channel.format(self.color, hsv),
+                };
+
+                let mut input =
+                    TextInput::with_style("", &value, self.input_style)
+                        .on_input(move |value| Input::Changed(channel, value))
+                        .on_submit(Input::Submitted);
+
+                if let Some(size) = self.text_size {
+                    input = input.size(size);
+                }
+
+                if let Some(font) = self.font {
+                    input = input.font(font);
+                }
+
+                if let Some(font) = font {
+                    input = input.icon(text_input::Icon {
+                        font,
+                        code_point: channel.label(),
+                        size: self.text_size.map(|size| size * 0.8),
+                        spacing: 4.0,
+                        side: text_input::Side::Left,
+                    });
+                }
+
+                Element::from(input)
+            })
+            .collect()
+    }
+
+    fn publish(
+        &self,
+        state: &mut State,
+        color: Color,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        state.color = Some(color);
+
+        if color != self.color {
+            shell.publish((self.on_change)(color));
+        }
+    }
+}
+
+/// The space between the parts of a [`ColorPicker`].
+const SPACING: f32 = 8.0;
+
+/// The height of the hue and alpha sliders.
+const SLIDER_HEIGHT: f32 = 12.0;
+
+/// The size of a swatch.
+const SWATCH_SIZE: f32 = 20.0;
+
+/// The space between swatches.
+const SWATCH_SPACING: f32 = 4.0;
+
+/// The size of a checker of the transparency pattern.
+const CHECKER_SIZE: f32 = 6.0;
+
+/// The index of the first text input in the layout of a [`ColorPicker`].
+const INPUTS: usize = 6;
+
+/// A text input of a [`ColorPicker`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Channel {
+    Hex,
+    Red,
+    Green,
+    Blue,
+    Hue,
+    Saturation,
+    Lightness,
+}
+
+impl Channel {
+    const ALL: [Self; 7] = [
+        Self::Hex,
+        Self::Red,
+        Self::Green,
+        Self::Blue,
+        Self::Hue,
+        Self::Saturation,
+        Self::Lightness,
+    ];
+
+    fn label(self) -> char {
+        match self {
+            Self::Hex => '#',
+            Self::Red => 'R',
+            Self::Green => 'G',
+            Self::Blue => 'B',
+            Self::Hue => 'H',
+            Self::Saturation => 'S',
+            Self::Lightness => 'L',
+        }
+    }
+
+    fn format(self, color: Color, hsv: Hsv) -> String {
+        let [r, g, b, a] = color.into_rgba8();
+        let (_, saturation, lightness) = to_hsl(color);
+
+        match self {
+            Self::Hex if a == u8::MAX => format!("{r:02X}{g:02X}{b:02X}"),
+            Self::Hex => format!("{r:02X}{g:02X}{b:02X}{a:02X}"),
+            Self::Red => r.to_string(),
+            Self::Green => g.to_string(),
+            Self::Blue => b.to_string(),
+            Self::Hue => format!("{:.0}", hsv.hue),
+            Self::Saturation => format!("{:.0}", saturation * 100.0),
+            Self::Lightness => format!("{:.0}", lightness * 100.0),
+        }
+    }
+
+    /// Parses the given value of the [`Channel`], returning the resulting
+    /// [`Color`] and hue, if valid.
+    fn parse(
+        self,
+        value: &str,
+        color: Color,
+        hsv: Hsv,
+    ) -> Option<(Color, f32)> {
+        let value = value.trim();
+        let (_, saturation, lightness) = to_hsl(color);
+
+        let color = match self {
+            Self::Hex => parse_hex(value)?,
+            Self::Red | Self::Green | Self::Blue => {
+                let component = f32::from(value.parse::<u8>().ok()?) / 255.0;
+
+                match self {
+                    Self::Red => Color {
+                        r: component,
+                        ..color
+                    },
+                    Self::Green => Color {
+                        g: component,
+                        ..color
+                    },
+                    _ => Color {
+                        b: component,
+                        ..color
+                    },
+                }
+            }
+            Self::Hue => {
+                let hue = value.parse::<f32>().ok()?;
+
+                if !(0.0..=360.0).contains(&hue) {
+                    return None;
+                }
+
+                return Some((
+                    from_hsl(hue, saturation, lightness, color.a),
+                    hue % 360.0,
+                ));
+            }
+            Self::Saturation | Self::Lightness => {
+                let percent = value.parse::<f32>().ok()?;
+
+                if !(0.0..=100.0).contains(&percent) {
+                    return None;
+                }
+
+                if self == Self::Saturation {
+                    from_hsl(hsv.hue, percent / 100.0, lightness, color.a)
+                } else {
+                    from_hsl(hsv.hue, saturation, percent / 100.0, color.a)
+                }
+            }
+        };
+
+        Some((color, Hsv::from_color(color, hsv.hue).hue))
+    }
+}
+
+/// A message of the text inputs of a [`ColorPicker`].
+#[derive(Debug, Clone)]
+enum Input {
+    Changed(Channel, String),
+    Submitted,
+}
+
+/// A part of a [`ColorPicker`] being dragged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Drag {
+    SaturationValue,
+    Hue,
+    Alpha,
+}
+
+/// A color in the HSV color model.
+///
+/// The hue is kept separately from the [`Color`] of a [`ColorPicker`], since
+/// it cannot be recovered from colors without saturation.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+struct Hsv {
+    hue: f32,
+    saturation: f32,
+    value: f32,
+}
+
+impl Hsv {
+    fn from_color(color: Color, hue: f32) -> Self {
+        let max = color.r.max(color.g).max(color.b);
+        let min = color.r.min(color.g).min(color.b);
+        let delta = max - min;
+
+        Self {
+            hue: if delta > 0.0 {
+                hue_of(color, max, delta)
+            } else {
+                hue
+            },
+            saturation: if max > 0.0 { delta / max } else { 0.0 },
+            value: max,
+        }
+    }
+
+    fn to_color(self, alpha: f32) -> Color {
+        let chroma = self.value * self.saturation;
+
+        from_chroma(self.hue, chroma, self.value - chroma, alpha)
+    }
+}
+
+/// The local state of a [`ColorPicker`].
+#[derive(Debug, Clone, Default)]
+struct State {
+    hsv: Hsv,
+    /// The color the `hsv` was computed from.
+    color: Option<Color>,
+    drag: Option<Drag>,
+    /// The text input being edited, and its current value.
+    editing: Option<(Channel, String)>,
+}
+
+impl State {
+    fn hsv(&self, color: Color) -> Hsv {
+        if self.color == Some(color) {
+            self.hsv
+        } else {
+            Hsv::from_color(color, self.hsv.hue)
+        }
+    }
+
+    fn sync(&mut self, color: Color) {
+        if self.color != Some(color) {
+            // The color was changed by the application
+            self.editing = None;
+        }
+
+        self.hsv = self.hsv(color);
+        self.color = Some(color);
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for ColorPicker<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        let mut state = State::default();
+        state.sync(self.color);
+
+        tree::State::new(state)
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.inputs(&State::default(), None)
+            .iter()
+            .map(Tree::new)
+            .collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State>();
+        state.sync(self.color);
+
+        let inputs = self.inputs(state, None);
+        tree.diff_children(&inputs);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let width = limits
+            .resolve(
+                self.width,
+                Length::Shrink,
+                Size::new(Self::DEFAULT_WIDTH, 0.0),
+            )
+            .width;
+
+        let state = tree.state.downcast_ref::<State>();
+        let inputs = self.inputs(state, Some(renderer.default_font()));
+
+        let layout_input =
+            |tree: &mut Tree, input: &Element<'_, _, _, _>, width| {
+                input.as_widget().layout(
+                    tree,
+                    renderer,
+                    &layout::Limits::new(
+                        Size::ZERO,
+                        Size::new(width, f32::INFINITY),
+                    ),
+                )
+            };
+
+        let third = (width - 2.0 * SPACING) / 3.0;
+
+        let mut nodes: Vec<_> = inputs
+            .iter()
+            .zip(&mut tree.children)
+            .skip(1)
+            .map(|(input, tree)| layout_input(tree, input, third))
+            .collect();
+
+        let row = nodes[0].size().height;
+        let buttons = if self.on_eyedropper.is_some() {
+            2.0
+        } else {
+            1.0
+        };
+
+        nodes.insert(
+            0,
+            layout_input(
+                &mut tree.children[0],
+                &inputs[0],
+                (width - buttons * (row + SPACING)).max(0.0),
+            ),
+        );
+
+        let mut y = 0.0;
+        let mut next = |height: f32| {
+            let top = y;
+            y += height + SPACING;
+            top
+        };
+
+        let square = layout::Node::new(Size::new(width, width))
+            .move_to(Point::new(0.0, next(width)));
+        let hue = layout::Node::new(Size::new(width, SLIDER_HEIGHT))
+            .move_to(Point::new(0.0, next(SLIDER_HEIGHT)));
+        let alpha = layout::Node::new(Size::new(width, SLIDER_HEIGHT))
+            .move_to(Point::new(0.0, next(SLIDER_HEIGHT)));
+
+        let top = next(row);
+        let preview = layout::Node::new(Size::new(row, row))
+            .move_to(Point::new(0.0, top));
+        nodes[0].move_to_mut(Point::new(row + SPACING, top));
+
+        let eyedropper = if self.on_eyedropper.is_some() {
+            layout::Node::new(Size::new(row, row))
+                .move_to(Point::new(width - row, top))
+        } else {
+            layout::Node::default()
+        };
+
+        for line in nodes[1..].chunks_mut(3) {
+            let top = next(row);
+
+            for (i, node) in line.iter_mut().enumerate() {
+                node.move_to_mut(Point::new(i as f32 * (third + SPACING), top));
+            }
+        }
+
+        let per_row = (((width + SWATCH_SPACING)
+            / (SWATCH_SIZE + SWATCH_SPACING)) as usize)
+            .max(1);
+        let swatch_rows = self.swatches.len().div_ceil(per_row);
+
+        let swatches = if swatch_rows > 0 {
+            let height = swatch_rows as f32 * (SWATCH_SIZE + SWATCH_SPACING)
+                - SWATCH_SPACING;
+
+            layout::Node::new(Size::new(width, height))
+                .move_to(Point::new(0.0, next(height)))
+        } else {
+            layout::Node::default()
+        };
+
+        let height = (y - SPACING).max(0.0);
+
+        layout::Node::with_children(
+            Size::new(width, height),
+            [square, hue, alpha, preview, eyedropper, swatches]
+                .into_iter()
+                .chain(nodes)
+                .collect(),
+        )
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let inputs = self.inputs(state, Some(renderer.default_font()));
+
+        operation.container(None, layout.bounds(), &mut |operation| {
+            for ((input, tree), layout) in inputs
+                .iter()
+                .zip(&mut tree.children)
+                .zip(layout.children().skip(INPUTS))
+            {
+                input.as_widget().operate(
+                    tree,
+                    layout,
+                    renderer,
+                    &mut MapOperation { operation },
+                );
+            }
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let mut messages = Vec::new();
+        let mut status = event::Status::Ignored;
+
+        {
+            let state = tree.state.downcast_mut::<State>();
+            state.sync(self.color);
+
+            let mut inputs = self.inputs(state, Some(renderer.default_font()));
+            let mut local_shell = Shell::new(&mut messages);
+
+            for ((input, tree), layout) in inputs
+                .iter_mut()
+                .zip(&mut tree.children)
+                .zip(layout.children().skip(INPUTS))
+            {
+                status = status.merge(input.as_widget_mut().on_event(
+                    tree,
+                    event.clone(),
+                    layout,
+                    cursor,
+                    renderer,
+                    clipboard,
+                    &mut local_shell,
+                    viewport,
+                ));
+            }
+
+            local_shell.revalidate_layout(|| shell.invalidate_layout());
+
+            if let Some(redraw_request) = local_shell.redraw_request() {
+                shell.request_redraw(redraw_request);
+            }
+        }
+
+        let state = tree.state.downcast_mut::<State>();
+
+        for message in messages {
+            match message {
+                Input::Changed(channel, value) => {
+                    let hsv = state.hsv(self.color);
+
+                    if let Some((color, hue)) =
+                        channel.parse(&value, self.color, hsv)
+                    {
+                        state.hsv = Hsv::from_color(color, hue);
+                        self.publish(state, color, shell);
+                    }
+
+                    state.editing = Some((channel, value));
+                }
+                Input::Submitted => {
+                    state.editing = None;
+                }
+            }
+
+            shell.invalidate_layout();
+        }
+
+        if status == event::Status::Captured {
+            return status;
+        }
+
+        let mut children = layout.children();
+        let square = children.next().unwrap().bounds();
+        let hue = children.next().unwrap().bounds();
+        let alpha = children.next().unwrap().bounds();
+        let _preview = children.next();
+        let eyedropper = children.next().unwrap().bounds();
+        let swatches = children.next().unwrap().bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(position) = cursor.position() else {
+                    return event::Status::Ignored;
+                };
+
+                state.drag = if square.contains(position) {
+                    Some(Drag::SaturationValue)
+                } else if hue.contains(position) {
+                    Some(Drag::Hue)
+                } else if alpha.contains(position) {
+                    Some(Drag::Alpha)
+                } else {
+                    None
+                };
+
+                if state.drag.is_some() {
+                    state.editing = None;
+                } else if let Some(on_eyedropper) = self
+                    .on_eyedropper
+                    .clone()
+                    .filter(|_| eyedropper.contains(position))
+                {
+                    shell.publish(on_eyedropper);
+
+                    return event::Status::Captured;
+                } else if let Some(color) =
+                    swatch_bounds(swatches, self.swatches.len())
+                        .zip(&self.swatches)
+                        .find(|(bounds, _)| bounds.contains(position))
+                        .map(|(_, color)| *color)
+                {
+                    state.editing = None;
+                    state.hsv = Hsv::from_color(color, state.hsv.hue);
+                    self.publish(state, color, shell);
+
+                    return event::Status::Captured;
+                } else {
+                    return event::Status::Ignored;
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. })
+                if state.drag.is_some() => {}
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. })
+                if state.drag.is_some() =>
+            {
+                state.drag = None;
+
+                return event::Status::Captured;
+            }
+            _ => return event::Status::Ignored,
+        }
+
+        let (Some(drag), Some(position)) = (state.drag, cursor.position())
+        else {
+            return event::Status::Ignored;
+        };
+
+        let ratio = |value: f32, start: f32, size: f32| {
+            if size > 0.0 {
+                ((value - start) / size).clamp(0.0, 1.0)
+            } else {
+                0.0
+            }
+        };
+
+        let mut alpha_value = self.color.a;
+
+        match drag {
+            Drag::SaturationValue => {
+                state.hsv.saturation =
+                    ratio(position.x, square.x, square.width);
+                state.hsv.value =
+                    1.0 - ratio(position.y, square.y, square.height);
+            }
+            Drag::Hue => {
+                state.hsv.hue = ratio(position.x, hue.x, hue.width) * 360.0;
+            }
+            Drag::Alpha => {
+                alpha_value = ratio(position.x, alpha.x, alpha.width);
+            }
+        }
+
+        let color = state.hsv.to_color(alpha_value);
+        self.publish(state, color, shell);
+        shell.invalidate_layout();
+
+        event::Status::Captured
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        if state.drag.is_some() {
+            return mouse::Interaction::Grabbing;
+        }
+
+        let mut children = layout.children();
+        let square = children.next().unwrap().bounds();
+
+        if cursor.is_over(square) {
+            return mouse::Interaction::Crosshair;
+        }
+
+        let is_over_control =
+            children.by_ref().take(INPUTS - 1).enumerate().any(
+                |(i, layout)| {
+                    let bounds = layout.bounds();
+
+                    match i {
+                        // The preview is not interactive
+                        2 => false,
+                        4 => swatch_bounds(bounds, self.swatches.len())
+                            .any(|bounds| cursor.is_over(bounds)),
+                        _ => cursor.is_over(bounds),
+                    }
+                },
+            );
+
+        if is_over_control {
+            return mouse::Interaction::Pointer;
+        }
+
+        let inputs = self.inputs(state, Some(renderer.default_font()));
+
+        inputs
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children().skip(INPUTS))
+            .map(|((input, tree), layout)| {
+                input
+                    .as_widget()
+                    .mouse_interaction(tree, layout, cursor, viewport, renderer)
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let appearance = (self.style)(theme);
+        let hsv = state.hsv(self.color);
+        let opaque = Color {
+            a: 1.0,
+            ..self.color
+        };
+
+        let mut children = layout.children();
+        let square = children.next().unwrap().bounds();
+        let hue = children.next().unwrap().bounds();
+        let alpha = children.next().unwrap().bounds();
+        let preview = children.next().unwrap().bounds();
+        let eyedropper = children.next().unwrap().bounds();
+        let swatches = children.next().unwrap().bounds();
+
+        let border = Border::rounded(appearance.border_radius);
+
+        // Saturation and value
+        let pure = Hsv {
+            hue: hsv.hue,
+            saturation: 1.0,
+            value: 1.0,
+        }
+        .to_color(1.0);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: square,
+                border,
+                ..renderer::Quad::default()
+            },
+            gradient::Linear::new(Radians(FRAC_PI_2))
+                .add_stop(0.0, Color::WHITE)
+                .add_stop(1.0, pure),
+        );
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: square,
+                border,
+                ..renderer::Quad::default()
+            },
+            gradient::Linear::new(Radians(PI))
+                .add_stop(0.0, Color::TRANSPARENT)
+                .add_stop(1.0, Color::BLACK),
+        );
+
+        draw_handle(
+            renderer,
+            &appearance,
+            Point::new(
+                square.x + hsv.saturation * square.width,
+                square.y + (1.0 - hsv.value) * square.height,
+            ),
+            SLIDER_HEIGHT,
+            SLIDER_HEIGHT,
+        );
+
+        // Hue
+        let mut hues = gradient::Linear::new(Radians(FRAC_PI_2));
+
+        for i in 0..=6 {
+            let hue = i as f32 / 6.0;
+
+            hues = hues.add_stop(
+                hue,
+                Hsv {
+                    hue: hue * 360.0,
+                    saturation: 1.0,
+                    value: 1.0,
+                }
+                .to_color(1.0),
+            );
+        }
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: hue,
+                border,
+                ..renderer::Quad::default()
+            },
+            hues,
+        );
+
+        draw_handle(
+            renderer,
+            &appearance,
+            Point::new(hue.x + hsv.hue / 360.0 * hue.width, hue.center_y()),
+            SLIDER_HEIGHT / 2.0,
+            SLIDER_HEIGHT + 4.0,
+        );
+
+        // Alpha
+        draw_checkerboard(renderer, &appearance, alpha);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: alpha,
+                border,
+                ..renderer::Quad::default()
+            },
+            gradient::Linear::new(Radians(FRAC_PI_2))
+                .add_stop(0.0, Color::TRANSPARENT)
+                .add_stop(1.0, opaque),
+        );
+
+        draw_handle(
+            renderer,
+            &appearance,
+            Point::new(alpha.x + self.color.a * alpha.width, alpha.center_y()),
+            SLIDER_HEIGHT / 2.0,
+            SLIDER_HEIGHT + 4.0,
+        );
+
+        // Preview
+        draw_checkerboard(renderer, &appearance, preview);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: preview,
+                border: appearance.swatch_border,
+                ..renderer::Quad::default()
+            },
+            self.color,
+        );
+
+        // Eyedropper
+        if self.on_eyedropper.is_some() {
+            draw_eyedropper(
+                renderer,
+                &appearance,
+                eyedropper,
+                cursor.is_over(eyedropper),
+            );
+        }
+
+        // Swatches
+        for (bounds, color) in
+            swatch_bounds(swatches, self.swatches.len()).zip(&self.swatches)
+        {
+            draw_checkerboard(renderer, &appearance, bounds);
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border: if cursor.is_over(bounds) || *color == self.color {
+                        Border {
+                            color: appearance.handle_color,
+                            width: 2.0,
+                            ..appearance.swatch_border
+                        }
+                    } else {
+                        appearance.swatch_border
+                    },
+                    ..renderer::Quad::default()
+                },
+                *color,
+            );
+        }
+
+        // Text inputs
+        let inputs = self.inputs(state, Some(renderer.default_font()));
+
+        for ((input, tree), layout) in inputs
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children().skip(INPUTS))
+        {
+            input
+                .as_widget()
+                .draw(tree, renderer, theme, style, layout, cursor, viewport);
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer>
+    From<ColorPicker<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(color_picker: ColorPicker<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(color_picker)
+    }
+}
+
+/// An [`Operation`] on the text inputs of a [`ColorPicker`], which produce
+/// a different type of message.
+struct MapOperation<'a, B> {
+    operation: &'a mut dyn Operation<B>,
+}
+
+impl<'a, T, B> Operation<T> for MapOperation<'a, B> {
+    fn container(
+        &mut self,
+        id: Option<&widget::Id>,
+        bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        self.operation.container(id, bounds, &mut |operation| {
+            operate_on_children(&mut MapOperation { operation });
+        });
+    }
+
+    fn focusable(
+        &mut self,
+        state: &mut dyn operation::Focusable,
+        id: Option<&widget::Id>,
+    ) {
+        self.operation.focusable(state, id);
+    }
+
+    fn text_input(
+        &mut self,
+        state: &mut dyn operation::TextInput,
+        id: Option<&widget::Id>,
+    ) {
+        self.operation.text_input(state, id);
+    }
+
+    fn custom(
+        &mut self,
+        state: &mut dyn std::any::Any,
+        id: Option<&widget::Id>,
+    ) {
+        self.operation.custom(state, id);
+    }
+
+    fn bounds(&mut self, id: Option<&widget::Id>, bounds: Rectangle) {
+        self.operation.bounds(id, bounds);
+    }
+}
+
+/// Returns the bounds of the given amount of swatches in the given area.
+fn swatch_bounds(
+    bounds: Rectangle,
+    count: usize,
+) -> impl Iterator<Item = Rectangle> {
+    let per_row = (((bounds.width + SWATCH_SPACING)
+        / (SWATCH_SIZE + SWATCH_SPACING)) as usize)
+        .max(1);
+
+    (0..count).map(move |i| Rectangle {
+        x: bounds.x + (i % per_row) as f32 * (SWATCH_SIZE + SWATCH_SPACING),
+        y: bounds.y + (i / per_row) as f32 * (SWATCH_SIZE + SWATCH_SPACING),
+        width: SWATCH_SIZE,
+        height: SWATCH_SIZE,
+    })
+}
+
+fn draw_handle<Renderer>(
+    renderer: &mut Renderer,
+    appearance: &Appearance,
+    center: Point,
+    width: f32,
+    height: f32,
+) where
+    Renderer: renderer::Renderer,
+{
+    let bounds = Rectangle {
+        x: center.x - width / 2.0,
+        y: center.y - height / 2.0,
+        width,
+        height,
+    };
+
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds: bounds.expand(1.0),
+            border: Border {
+                color: appearance.handle_border_color,
+                width: 1.0,
+                radius: (width.min(height) / 2.0 + 1.0).into(),
+            },
+            ..renderer::Quad::default()
+        },
+        Color::TRANSPARENT,
+    );
+
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds,
+            border: Border {
+                color: appearance.handle_color,
+                width: 2.0,
+                radius: (width.min(height) / 2.0).into(),
+            },
+            ..renderer::Quad::default()
+        },
+        Color::TRANSPARENT,
+    );
+}
+
+/// Draws the pattern used to show the transparency of colors.
+fn draw_checkerboard<Renderer>(
+    renderer: &mut Renderer,
+    appearance: &Appearance,
+    bounds: Rectangle,
+) where
+    Renderer: renderer::Renderer,
+{
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds,
+            border: Border::rounded(appearance.border_radius),
+            ..renderer::Quad::default()
+        },
+        appearance.checkerboard[0],
+    );
+
+    let columns = (bounds.width / CHECKER_SIZE).ceil() as usize;
+    let rows = (bounds.height / CHECKER_SIZE).ceil() as usize;
+
+    renderer.with_layer(bounds, |renderer| {
+        for row in 0..rows {
+            for column in (row % 2..columns).step_by(2) {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: bounds.x + column as f32 * CHECKER_SIZE,
+                            y: bounds.y + row as f32 * CHECKER_SIZE,
+                            width: CHECKER_SIZE,
+                            height: CHECKER_SIZE,
+                        },
+                        ..renderer::Quad::default()
+                    },
+                    appearance.checkerboard[1],
+                );
+            }
+        }
+    });
+}
+
+fn draw_eyedropper<Renderer>(
+    renderer: &mut Renderer,
+    appearance: &Appearance,
+    bounds: Rectangle,
+    is_hovered: bool,
+) where
+    Renderer: renderer::Renderer,
+{
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds,
+            border: Border::rounded(appearance.border_radius),
+            ..renderer::Quad::default()
+        },
+        if is_hovered {
+            appearance.button_hovered_background
+        } else {
+            appearance.button_background
+        },
+    );
+
+    // A crosshair
+    let center = bounds.center();
+    let size = bounds.width.min(bounds.height) * 0.5;
+
+    for (width, height) in [(size, 2.0), (2.0, size)] {
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: center.x - width / 2.0,
+                    y: center.y - height / 2.0,
+                    width,
+                    height,
+                },
+                ..renderer::Quad::default()
+            },
+            appearance.button_icon_color,
+        );
+    }
+
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds: Rectangle {
+                x: center.x - size / 4.0,
+                y: center.y - size / 4.0,
+                width: size / 2.0,
+                height: size / 2.0,
+            },
+            border: Border {
+                color: appearance.button_icon_color,
+                width: 2.0,
+                radius: (size / 4.0).into(),
+            },
+            ..renderer::Quad::default()
+        },
+        Color::TRANSPARENT,
+    );
+}
+
+/// Returns the hue of a color with the given maximum component and chroma.
+fn hue_of(color: Color, max: f32, delta: f32) -> f32 {
+    let hue = if max == color.r {
+        ((color.g - color.b) / delta).rem_euclid(6.0)
+    } else if max == color.g {
+        (color.b - color.r) / delta + 2.0
+    } else {
+        (color.r - color.g) / delta + 4.0
+    };
+
+    (hue * 60.0).rem_euclid(360.0)
+}
+
+fn from_chroma(hue: f32, chroma: f32, min: f32, alpha: f32) -> Color {
+    let sector = (hue.rem_euclid(360.0)) / 60.0;
+    let x = chroma * (1.0 - (sector % 2.0 - 1.0).abs());
+
+    let (r, g, b) = match sector as u8 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+
+    Color::from_rgba(r + min, g + min, b + min, alpha)
+}
+
+/// Returns the hue, saturation, and lightness of a [`Color`].
+fn to_hsl(color: Color) -> (f32, f32, f32) {
+    let max = color.r.max(color.g).max(color.b);
+    let min = color.r.min(color.g).min(color.b);
+    let delta = max - min;
+    let lightness = (max + min) / 2.0;
+
+    if delta <= 0.0 {
+        return (0.0, 0.0, lightness);
+    }
+
+    (
+        hue_of(color, max, delta),
+        delta / (1.0 - (2.0 * lightness - 1.0).abs()),
+        lightness,
+    )
+}
+
+fn from_hsl(hue: f32, saturation: f32, lightness: f32, alpha: f32) -> Color {
+    let chroma = (1.0 - (2.0 * lightness - 1.0).abs()) * saturation;
+
+    from_chroma(hue, chroma, lightness - chroma / 2.0, alpha)
+}
+
+/// Parses a hex color with an optional `#` prefix, in the `RGB`, `RGBA`,
+/// `RRGGBB`, or `RRGGBBAA` formats.
+fn parse_hex(hex: &str) -> Option<Color> {
+    let hex = hex.strip_prefix('#').unwrap_or(hex);
+
+    if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    let digit = |i: usize, width: usize| {
+        let value =
+            u8::from_str_radix(&hex[i * width..(i + 1) * width], 16).ok()?;
+
+        Some(if width == 1 { value * 17 } else { value })
+    };
+
+    let width = match hex.len() {
+        3 | 4 => 1,
+        6 | 8 => 2,
+        _ => return None,
+    };
+
+    let alpha = if hex.len() / width == 4 {
+        f32::from(digit(3, width)?) / 255.0
+    } else {
+        1.0
+    };
+
+    Some(Color::from_rgba8(
+        digit(0, width)?,
+        digit(1, width)?,
+        digit(2, width)?,
+        alpha,
+    ))
+}
+
+/// The appearance of a [`ColorPicker`].
+#[derive(Debug, Clone, Copy)]
+pub struct Appearance {
+    /// The [`Color`] of the handles of the square and the sliders.
+    pub handle_color: Color,
+    /// The [`Color`] of the outline of the handles.
+    pub handle_border_color: Color,
+    /// The [`Border`] of the preview and the swatches.
+    pub swatch_border: Border,
+    /// The colors of the pattern shown behind transparent colors.
+    pub checkerboard: [Color; 2],
+    /// The [`Background`] of the eyedropper button.
+    pub button_background: Background,
+    /// The [`Background`] of the hovered eyedropper button.
+    pub button_hovered_background: Background,
+    /// The [`Color`] of the icon of the eyedropper button.
+    pub button_icon_color: Color,
+    /// The border radius of the square, the sliders, and the buttons.
+    pub border_radius: f32,
+}
+
+/// The style of a [`ColorPicker`].
+pub type Style<Theme> = fn(&Theme) -> Appearance;
+
+/// The default style of a [`ColorPicker`].
+pub trait DefaultStyle {
+    /// Returns the default style of a [`ColorPicker`].
+    fn default_style() -> Style<Self>;
+}
+
+impl DefaultStyle for Theme {
+    fn default_style() -> Style<Self> {
+        default
+    }
+}
+
+impl DefaultStyle for Appearance {
+    fn default_style() -> Style<Self> {
+        |appearance| *appearance
+    }
+}
+
+/// The default style of a [`ColorPicker`].
+pub fn default(theme: &Theme) -> Appearance {
+    let palette = theme.extended_palette();
+
+    Appearance {
+        handle_color: Color::WHITE,
+        handle_border_color: Color::BLACK.scale_alpha(0.5),
+        swatch_border: Border {
+            color: palette.background.strong.color,
+            width: 1.0,
+            radius: 2.0.into(),
+        },
+        checkerboard: [Color::WHITE, Color::from_rgb8(0xCC, 0xCC, 0xCC)],
+        button_background: palette.secondary.base.color.into(),
+        button_hovered_background: palette.secondary.strong.color.into(),
+        button_icon_color: palette.secondary.base.text,
+        border_radius: 2.0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(a: Color, b: Color) {
+        let a = a.into_rgba8();
+        let b = b.into_rgba8();
+
+        assert!(
+            a.iter().zip(&b).all(|(a, b)| a.abs_diff(*b) <= 1),
+            "{a:?} != {b:?}"
+        );
+    }
+
+    #[test]
+    fn parses_hex() {
+        assert_eq!(parse_hex("#FF8000"), Some(Color::from_rgb8(255, 128, 0)));
+        assert_eq!(parse_hex("ff8000"), Some(Color::from_rgb8(255, 128, 0)));
+        assert_eq!(parse_hex("#F80"), Some(Color::from_rgb8(255, 136, 0)));
+        assert_eq!(
+            parse_hex("#FF800080"),
+            Some(Color::from_rgba8(255, 128, 0, 128.0 / 255.0))
+        );
+        assert_eq!(
+            parse_hex("#FF80"),
+            Some(Color::from_rgba8(255, 255, 136, 0.0))
+        );
+        assert_eq!(parse_hex("#FF80G0"), None);
+        assert_eq!(parse_hex("#FF80000"), None);
+        assert_eq!(parse_hex(""), None);
+    }
+
+    #[test]
+    fn converts_hsv() {
+        for color in [
+            Color::from_rgb8(255, 0, 0),
+            Color::from_rgb8(12, 200, 99),
+            Color::from_rgb8(80, 80, 240),
+            Color::from_rgb8(128, 128, 128),
+            Color::BLACK,
+            Color::WHITE,
+        ] {
+            assert_close(Hsv::from_color(color, 0.0).to_color(1.0), color);
+        }
+
+        let hsv = Hsv::from_color(Color::from_rgb8(0, 0, 255), 0.0);
+        assert!((hsv.hue - 240.0).abs() < 0.01);
+
+        // The hue is kept for colors without saturation
+        assert_eq!(Hsv::from_color(Color::BLACK, 120.0).hue, 120.0);
+    }
+
+    #[test]
+    fn converts_hsl() {
+        for color in [
+            Color::from_rgb8(255, 0, 0),
+            Color::from_rgb8(12, 200, 99),
+            Color::from_rgb8(80, 80, 240),
+            Color::from_rgb8(128, 128, 128),
+        ] {
+            let (hue, saturation, lightness) = to_hsl(color);
+
+            assert_close(from_hsl(hue, saturation, lightness, 1.0), color);
+        }
+
+        let (hue, saturation, lightness) = to_hsl(Color::from_rgb8(0, 255, 0));
+        assert!((hue - 120.0).abs() < 0.01);
+        assert!((saturation - 1.0).abs() < 0.01);
+        assert!((lightness - 0.5).abs() < 0.01);
+    }
+}
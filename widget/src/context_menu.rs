@@ -0,0 +1,277 @@
+//! Show a menu when right-clicking some content.
+use crate::core::event::{self, Event};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text;
+use crate::core::widget::{tree, Operation, Tree};
+use crate::core::{
+    Clipboard, Element, Layout, Length, Padding, Pixels, Point, Rectangle,
+    Shell, Size, Vector, Widget,
+};
+use crate::menu_bar::cascade::{self, Cascade};
+use crate::menu_bar::{self, Appearance, DefaultStyle, Item, Style};
+
+/// A container that shows a menu at the position of the cursor when its
+/// content is right-clicked.
+///
+/// The menu supports the same items as the menus of a
+/// [`MenuBar`](crate::MenuBar), including submenus and keyboard navigation.
+#[allow(missing_debug_implementations)]
+pub struct ContextMenu<
+    'a,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> where
+    Renderer: text::Renderer,
+{
+    content: Element<'a, Message, Theme, Renderer>,
+    items: Vec<Item<Message>>,
+    padding: Padding,
+    text_size: Option<Pixels>,
+    font: Option<Renderer::Font>,
+    style: Style<Theme>,
+}
+
+impl<'a, Message, Theme, Renderer> ContextMenu<'a, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    /// Creates a new [`ContextMenu`] for the given content with the given
+    /// items.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        items: impl IntoIterator<Item = Item<Message>>,
+    ) -> Self
+    where
+        Theme: DefaultStyle,
+    {
+        Self {
+            content: content.into(),
+            items: items.into_iter().collect(),
+            padding: menu_bar::DEFAULT_PADDING,
+            text_size: None,
+            font: None,
+            style: Theme::default_style(),
+        }
+    }
+
+    /// Sets the [`Padding`] of the items of the [`ContextMenu`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the [`ContextMenu`].
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(size.into());
+        self
+    }
+
+    /// Sets the font of the [`ContextMenu`].
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the style of the [`ContextMenu`].
+    pub fn style(mut self, style: fn(&Theme) -> Appearance) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+/// Local state of the [`ContextMenu`].
+#[derive(Debug, Clone, Default)]
+struct State {
+    menu: cascade::State,
+    position: Point,
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for ContextMenu<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        if let event::Status::Captured = self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event.clone(),
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        ) {
+            return event::Status::Captured;
+        }
+
+        let state = tree.state.downcast_mut::<State>();
+
+        // A `MouseArea` cannot be used here, since it publishes a `Message`
+        // on right press; while the menu needs to open on its own, at the
+        // position of the cursor
+        if let Event::Mouse(mouse::Event::ButtonPressed(button)) = event {
+            state.menu.close();
+
+            if button == mouse::Button::Right && !self.items.is_empty() {
+                if let Some(position) = cursor.position_over(layout.bounds()) {
+                    state.position = position;
+                    state.menu.open(Vec::new());
+
+                    return event::Status::Captured;
+                }
+            }
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        renderer_style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            renderer_style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = tree.state.downcast_mut::<State>();
+
+        let content = self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            translation,
+        );
+
+        let menu = state.menu.is_open.then(|| {
+            overlay::Element::new(Box::new(Cascade {
+                state: &mut state.menu,
+                items: &self.items,
+                position: state.position + translation,
+                target_height: 0.0,
+                padding: self.padding,
+                text_size: self.text_size,
+                font: self.font,
+                style: self.style,
+            }))
+        });
+
+        match (content, menu) {
+            (Some(content), Some(menu)) => Some(
+                overlay::Group::with_children(vec![content, menu]).overlay(),
+            ),
+            (content, menu) => content.or(menu),
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer>
+    From<ContextMenu<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(context_menu: ContextMenu<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(context_menu)
+    }
+}
@@ -0,0 +1,1545 @@
+//! Pick a date, or a range of dates, from a calendar.
+use crate::core::alignment;
+use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text::{self, Paragraph as _, Text};
+use crate::core::touch;
+use crate::core::widget::operation;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::Operation;
+use crate::core::{
+    Background, Border, Clipboard, Color, Element, Layout, Length, Padding,
+    Pixels, Point, Rectangle, Shell, Size, Theme, Vector, Widget,
+};
+
+use std::fmt;
+use std::ops::RangeInclusive;
+
+/// An input-like field that opens a calendar to pick a [`Date`].
+///
+/// A [`DatePicker`] can pick either a single date or, if created with
+/// [`DatePicker::range`], a range of dates. Dates outside the bounds set with
+/// [`DatePicker::min`] and [`DatePicker::max`], or rejected by
+/// [`DatePicker::disabled`], cannot be picked.
+///
+/// The calendar can be navigated with the keyboard: the arrow keys move
+/// between days, Page Up and Page Down change the month (or the year, while
+/// holding Shift), Home and End move to the start and end of the week, Enter
+/// picks a day, and Escape closes the calendar.
+#[allow(missing_debug_implementations)]
+pub struct DatePicker<
+    'a,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> where
+    Renderer: text::Renderer,
+{
+    mode: Mode<'a, Message>,
+    min: Option<Date>,
+    max: Option<Date>,
+    is_disabled: Option<Box<dyn Fn(Date) -> bool + 'a>>,
+    today: Option<Date>,
+    first_weekday: Weekday,
+    placeholder: Option<String>,
+    width: Length,
+    padding: Padding,
+    text_size: Option<Pixels>,
+    font: Option<Renderer::Font>,
+    style: Style<Theme>,
+}
+
+enum Mode<'a, Message> {
+    Single {
+        selected: Option<Date>,
+        on_select: Box<dyn Fn(Date) -> Message + 'a>,
+    },
+    Range {
+        selected: Option<RangeInclusive<Date>>,
+        on_select: Box<dyn Fn(RangeInclusive<Date>) -> Message + 'a>,
+    },
+}
+
+impl<'a, Message, Theme, Renderer> DatePicker<'a, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    /// The default [`Padding`] of a [`DatePicker`].
+    pub const DEFAULT_PADDING: Padding = Padding::new(5.0);
+
+    /// Creates a new [`DatePicker`] with the selected [`Date`], if any, and
+    /// the message to produce when a date is picked.
+    pub fn new(
+        selected: Option<Date>,
+        on_select: impl Fn(Date) -> Message + 'a,
+    ) -> Self
+    where
+        Theme: DefaultStyle,
+    {
+        Self::with_mode(Mode::Single {
+            selected,
+            on_select: Box::new(on_select),
+        })
+    }
+
+    /// Creates a new [`DatePicker`] that picks a range of dates, with the
+    /// selected range, if any, and the message to produce when a range is
+    /// picked.
+    ///
+    /// The first picked day starts the range and the second one ends it.
+    pub fn range(
+        selected: Option<RangeInclusive<Date>>,
+        on_select: impl Fn(RangeInclusive<Date>) -> Message + 'a,
+    ) -> Self
+    where
+        Theme: DefaultStyle,
+    {
+        Self::with_mode(Mode::Range {
+            selected,
+            on_select: Box::new(on_select),
+        })
+    }
+
+    fn with_mode(mode: Mode<'a, Message>) -> Self
+    where
+        Theme: DefaultStyle,
+    {
+        Self {
+            mode,
+            min: None,
+            max: None,
+            is_disabled: None,
+            today: None,
+            first_weekday: Weekday::Monday,
+            placeholder: None,
+            width: Length::Shrink,
+            padding: Self::DEFAULT_PADDING,
+            text_size: None,
+            font: None,
+            style: Theme::default_style(),
+        }
+    }
+
+    /// Sets the earliest [`Date`] that can be picked.
+    pub fn min(mut self, date: Date) -> Self {
+        self.min = Some(date);
+        self
+    }
+
+    /// Sets the latest [`Date`] that can be picked.
+    pub fn max(mut self, date: Date) -> Self {
+        self.max = Some(date);
+        self
+    }
+
+    /// Sets the function that decides whether a [`Date`] is disabled and,
+    /// therefore, cannot be picked.
+    pub fn disabled(mut self, is_disabled: impl Fn(Date) -> bool + 'a) -> Self {
+        self.is_disabled = Some(Box::new(is_disabled));
+        self
+    }
+
+    /// Sets the current [`Date`], which is highlighted in the calendar and
+    /// shown when opening it without a selection.
+    pub fn today(mut self, date: Date) -> Self {
+        self.today = Some(date);
+        self
+    }
+
+    /// Sets the first [`Weekday`] of the weeks of the calendar.
+    pub fn first_weekday(mut self, weekday: Weekday) -> Self {
+        self.first_weekday = weekday;
+        self
+    }
+
+    /// Sets the placeholder of the [`DatePicker`].
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    /// Sets the width of the [`DatePicker`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`DatePicker`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the [`DatePicker`].
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(size.into());
+        self
+    }
+
+    /// Sets the font of the [`DatePicker`].
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the style of the [`DatePicker`].
+    pub fn style(mut self, style: fn(&Theme, Status) -> Appearance) -> Self {
+        self.style = style;
+        self
+    }
+
+    fn is_enabled(&self, date: Date) -> bool {
+        self.bounds().contains(&date)
+            && !self.is_disabled.as_ref().is_some_and(|f| f(date))
+    }
+
+    /// Returns whether any day of the month of the given [`Date`] is within
+    /// the bounds of the [`DatePicker`].
+    fn is_month_enabled(&self, date: Date) -> bool {
+        let first = date.with_day(1);
+        let last = date.with_day(date.days_in_month());
+
+        let bounds = self.bounds();
+
+        last >= *bounds.start() && first <= *bounds.end()
+    }
+
+    /// Returns the range of dates that can be picked, ignoring disabled ones.
+    fn bounds(&self) -> RangeInclusive<Date> {
+        self.min.unwrap_or(Date::MIN)..=self.max.unwrap_or(Date::MAX)
+    }
+
+    fn label(&self) -> Option<String> {
+        match &self.mode {
+            Mode::Single { selected, .. } => {
+                selected.map(|date| date.to_string())
+            }
+            Mode::Range { selected, .. } => selected
+                .as_ref()
+                .map(|range| format!("{} – {}", range.start(), range.end())),
+        }
+    }
+
+    fn is_selected(&self, date: Date) -> bool {
+        match &self.mode {
+            Mode::Single { selected, .. } => *selected == Some(date),
+            Mode::Range { selected, .. } => {
+                selected.as_ref().is_some_and(|range| {
+                    *range.start() == date || *range.end() == date
+                })
+            }
+        }
+    }
+
+    /// Picks the given [`Date`], returning whether the picker is done.
+    fn pick(
+        &self,
+        state: &mut State,
+        date: Date,
+        shell: &mut Shell<'_, Message>,
+    ) -> bool {
+        if !self.is_enabled(date) {
+            return false;
+        }
+
+        match &self.mode {
+            Mode::Single { on_select, .. } => {
+                shell.publish(on_select(date));
+
+                true
+            }
+            Mode::Range { on_select, .. } => {
+                if let Some(anchor) = state.anchor.take() {
+                    shell.publish(on_select(
+                        anchor.min(date)..=anchor.max(date),
+                    ));
+
+                    true
+                } else {
+                    state.anchor = Some(date);
+
+                    false
+                }
+            }
+        }
+    }
+
+    /// Returns the [`Date`] to show when opening the calendar.
+    fn initial_date(&self) -> Date {
+        let selected = match &self.mode {
+            Mode::Single { selected, .. } => *selected,
+            Mode::Range { selected, .. } => {
+                selected.as_ref().map(|range| *range.start())
+            }
+        };
+
+        self.clamp(
+            selected
+                .or(self.today)
+                .or(self.min)
+                .or(self.max)
+                .unwrap_or_default(),
+        )
+    }
+
+    fn clamp(&self, date: Date) -> Date {
+        let bounds = self.bounds();
+
+        date.max(*bounds.start()).min(*bounds.end())
+    }
+}
+
+/// The state of the field of a picker.
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct Field {
+    pub(crate) is_open: bool,
+    pub(crate) is_focused: bool,
+}
+
+impl Field {
+    /// Processes an [`Event`] for the field with the given bounds, returning
+    /// its [`event::Status`] and whether the field has just been opened.
+    pub(crate) fn update(
+        &mut self,
+        event: &Event,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+    ) -> (event::Status, bool) {
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if cursor.is_over(bounds) {
+                    self.is_focused = true;
+                    self.is_open = !self.is_open;
+
+                    return (event::Status::Captured, self.is_open);
+                }
+
+                self.is_focused = false;
+                self.is_open = false;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed { key, .. })
+                if self.is_focused && !self.is_open =>
+            {
+                if let keyboard::Key::Named(
+                    key::Named::Enter
+                    | key::Named::Space
+                    | key::Named::ArrowDown,
+                ) = key.as_ref()
+                {
+                    self.is_open = true;
+
+                    return (event::Status::Captured, true);
+                }
+            }
+            _ => {}
+        }
+
+        (event::Status::Ignored, false)
+    }
+
+    pub(crate) fn status(&self, is_hovered: bool) -> Status {
+        if self.is_open {
+            Status::Opened
+        } else if self.is_focused {
+            Status::Focused
+        } else if is_hovered {
+            Status::Hovered
+        } else {
+            Status::Active
+        }
+    }
+}
+
+impl operation::Focusable for Field {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+        self.is_open = false;
+    }
+}
+
+/// The local state of a [`DatePicker`].
+#[derive(Debug, Clone, Default)]
+struct State {
+    field: Field,
+    /// The month shown in the calendar.
+    month: Date,
+    /// The day focused with the keyboard.
+    cursor: Date,
+    /// The start of the range being picked, if any.
+    anchor: Option<Date>,
+    is_keyboard: bool,
+}
+
+impl State {
+    fn move_cursor(&mut self, date: Date) {
+        self.cursor = date;
+        self.month = date.with_day(1);
+        self.is_keyboard = true;
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for DatePicker<'a, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let sample = match self.mode {
+            Mode::Single { .. } => "0000-00-00",
+            Mode::Range { .. } => "0000-00-00 – 0000-00-00",
+        };
+
+        layout_field(
+            renderer,
+            limits,
+            self.width,
+            self.padding,
+            self.text_size,
+            self.font,
+            sample,
+            self.placeholder.as_deref(),
+        )
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        operation.bounds(None, layout.bounds());
+        operation.focusable(&mut state.field, None);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        _shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        let (status, is_opened) =
+            state.field.update(&event, layout.bounds(), cursor);
+
+        if is_opened {
+            let date = self.initial_date();
+
+            state.move_cursor(date);
+            state.anchor = None;
+            state.is_keyboard = false;
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+
+        let appearance =
+            (self.style)(theme, state.field.status(cursor.is_over(bounds)));
+
+        draw_field(
+            renderer,
+            &appearance,
+            bounds,
+            self.padding,
+            self.text_size,
+            self.font,
+            self.label().as_deref(),
+            self.placeholder.as_deref(),
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = tree.state.downcast_mut::<State>();
+
+        if !state.field.is_open {
+            return None;
+        }
+
+        let bounds = layout.bounds();
+
+        Some(overlay::Element::new(Box::new(Calendar {
+            picker: self,
+            state,
+            position: bounds.position() + translation,
+            target_height: bounds.height,
+        })))
+    }
+}
+
+impl<'a, Message, Theme, Renderer>
+    From<DatePicker<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(date_picker: DatePicker<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(date_picker)
+    }
+}
+
+/// The space between the contents of a popup and its border.
+pub(crate) const POPUP_PADDING: f32 = 8.0;
+
+/// Returns the size of the side of a cell of a popup.
+pub(crate) fn cell_size(text_size: Pixels) -> f32 {
+    (text_size.0 * 2.25).round()
+}
+
+/// Positions a popup of the given size below its target, or above it if
+/// there is not enough space.
+pub(crate) fn position_popup(
+    position: Point,
+    target_height: f32,
+    size: Size,
+    bounds: Size,
+) -> Point {
+    let below = position.y + target_height;
+
+    let y = if below + size.height <= bounds.height || position.y < size.height
+    {
+        below
+    } else {
+        position.y - size.height
+    };
+
+    Point::new(position.x.min(bounds.width - size.width).max(0.0), y)
+}
+
+pub(crate) fn layout_field<Renderer>(
+    renderer: &Renderer,
+    limits: &layout::Limits,
+    width: Length,
+    padding: Padding,
+    text_size: Option<Pixels>,
+    font: Option<Renderer::Font>,
+    sample: &str,
+    placeholder: Option<&str>,
+) -> layout::Node
+where
+    Renderer: text::Renderer,
+{
+    let text_size = text_size.unwrap_or_else(|| renderer.default_size());
+    let font = font.unwrap_or_else(|| renderer.default_font());
+
+    let measure = |content| {
+        Renderer::Paragraph::with_text(Text {
+            content,
+            bounds: Size::INFINITY,
+            size: text_size,
+            line_height: text::LineHeight::default(),
+            font,
+            horizontal_alignment: alignment::Horizontal::Left,
+            vertical_alignment: alignment::Vertical::Top,
+            shaping: text::Shaping::Advanced,
+        })
+        .min_bounds()
+    };
+
+    let sample = measure(sample);
+    let text_width = placeholder
+        .map(measure)
+        .map_or(sample.width, |placeholder| {
+            placeholder.width.max(sample.width)
+        });
+
+    let size = limits.width(width).shrink(padding).resolve(
+        width,
+        Length::Shrink,
+        Size::new(
+            text_width,
+            f32::from(text::LineHeight::default().to_absolute(text_size)),
+        ),
+    );
+
+    layout::Node::new(size.expand(padding))
+}
+
+pub(crate) fn draw_field<Renderer>(
+    renderer: &mut Renderer,
+    appearance: &Appearance,
+    bounds: Rectangle,
+    padding: Padding,
+    text_size: Option<Pixels>,
+    font: Option<Renderer::Font>,
+    label: Option<&str>,
+    placeholder: Option<&str>,
+    viewport: &Rectangle,
+) where
+    Renderer: text::Renderer,
+{
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds,
+            border: appearance.border,
+            ..renderer::Quad::default()
+        },
+        appearance.background,
+    );
+
+    let (content, color) = match (label, placeholder) {
+        (Some(label), _) => (label, appearance.text_color),
+        (None, Some(placeholder)) => {
+            (placeholder, appearance.placeholder_color)
+        }
+        (None, None) => return,
+    };
+
+    let text_size = text_size.unwrap_or_else(|| renderer.default_size());
+
+    renderer.fill_text(
+        Text {
+            content,
+            bounds: Size::new(
+                bounds.width - padding.horizontal(),
+                bounds.height - padding.vertical(),
+            ),
+            size: text_size,
+            line_height: text::LineHeight::default(),
+            font: font.unwrap_or_else(|| renderer.default_font()),
+            horizontal_alignment: alignment::Horizontal::Left,
+            vertical_alignment: alignment::Vertical::Center,
+            shaping: text::Shaping::Advanced,
+        },
+        Point::new(bounds.x + padding.left, bounds.center_y()),
+        color,
+        *viewport,
+    );
+}
+
+/// Draws a cell of a popup with some centered text.
+pub(crate) fn draw_cell<Renderer>(
+    renderer: &mut Renderer,
+    bounds: Rectangle,
+    content: &str,
+    size: Pixels,
+    font: Renderer::Font,
+    color: Color,
+    background: Option<Background>,
+    border: Border,
+) where
+    Renderer: text::Renderer,
+{
+    if background.is_some() || border.width > 0.0 {
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border,
+                ..renderer::Quad::default()
+            },
+            background.unwrap_or(Background::Color(Color::TRANSPARENT)),
+        );
+    }
+
+    renderer.fill_text(
+        Text {
+            content,
+            bounds: bounds.size(),
+            size,
+            line_height: text::LineHeight::default(),
+            font,
+            horizontal_alignment: alignment::Horizontal::Center,
+            vertical_alignment: alignment::Vertical::Center,
+            shaping: text::Shaping::Advanced,
+        },
+        bounds.center(),
+        color,
+        bounds,
+    );
+}
+
+/// The calendar of an open [`DatePicker`].
+struct Calendar<'a, 'b, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    picker: &'b DatePicker<'a, Message, Theme, Renderer>,
+    state: &'b mut State,
+    position: Point,
+    target_height: f32,
+}
+
+/// A part of a [`Calendar`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Target {
+    PreviousYear,
+    PreviousMonth,
+    NextMonth,
+    NextYear,
+    Day(Date),
+}
+
+impl<'a, 'b, Message, Theme, Renderer>
+    Calendar<'a, 'b, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    /// Returns the first [`Date`] shown in the grid of days.
+    fn first_day(&self) -> Date {
+        let offset = (self.state.month.weekday().index() + 7
+            - self.picker.first_weekday.index())
+            % 7;
+
+        self.state.month.add_days(-(offset as i64))
+    }
+
+    fn cell_bounds(
+        &self,
+        origin: Point,
+        cell: f32,
+        column: usize,
+        row: f32,
+    ) -> Rectangle {
+        Rectangle {
+            x: origin.x + POPUP_PADDING + column as f32 * cell,
+            y: origin.y + POPUP_PADDING + row * cell,
+            width: cell,
+            height: cell,
+        }
+    }
+
+    fn targets(
+        &self,
+        bounds: Rectangle,
+        cell: f32,
+    ) -> impl Iterator<Item = (Target, Rectangle)> + '_ {
+        let origin = bounds.position();
+        let first_day = self.first_day();
+
+        [
+            (Target::PreviousYear, 0),
+            (Target::PreviousMonth, 1),
+            (Target::NextMonth, 5),
+            (Target::NextYear, 6),
+        ]
+        .into_iter()
+        .map(move |(target, column)| {
+            (target, self.cell_bounds(origin, cell, column, 0.0))
+        })
+        .chain((0..42).map(move |i| {
+            (
+                Target::Day(first_day.add_days(i as i64)),
+                self.cell_bounds(origin, cell, i % 7, 1.75 + (i / 7) as f32),
+            )
+        }))
+    }
+
+    fn navigate(&self, target: Target) -> Option<Date> {
+        let months = match target {
+            Target::PreviousYear => -12,
+            Target::PreviousMonth => -1,
+            Target::NextMonth => 1,
+            Target::NextYear => 12,
+            Target::Day(_) => return None,
+        };
+
+        Some(self.state.month.add_months(months))
+            .filter(|month| self.picker.is_month_enabled(*month))
+    }
+
+    fn close(&mut self) {
+        self.state.field.is_open = false;
+        self.state.anchor = None;
+    }
+}
+
+impl<'a, 'b, Message, Theme, Renderer>
+    overlay::Overlay<Message, Theme, Renderer>
+    for Calendar<'a, 'b, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> layout::Node {
+        let cell = cell_size(
+            self.picker
+                .text_size
+                .unwrap_or_else(|| renderer.default_size()),
+        );
+
+        let size = Size::new(
+            7.0 * cell + 2.0 * POPUP_PADDING,
+            7.75 * cell + 2.0 * POPUP_PADDING,
+        );
+
+        layout::Node::new(size).move_to(position_popup(
+            self.position,
+            self.target_height,
+            size,
+            bounds,
+        ))
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                self.state.is_keyboard = false;
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let cell = cell_size(
+                    self.picker
+                        .text_size
+                        .unwrap_or_else(|| renderer.default_size()),
+                );
+
+                let Some(target) = cursor.position().and_then(|position| {
+                    self.targets(bounds, cell)
+                        .find(|(_, bounds)| bounds.contains(position))
+                        .map(|(target, _)| target)
+                }) else {
+                    return if cursor.is_over(bounds) {
+                        event::Status::Captured
+                    } else {
+                        event::Status::Ignored
+                    };
+                };
+
+                if let Target::Day(date) = target {
+                    if self.picker.pick(self.state, date, shell) {
+                        self.close();
+                    } else if self.picker.is_enabled(date) {
+                        self.state.cursor = date;
+                    }
+                } else if let Some(month) = self.navigate(target) {
+                    self.state.month = month;
+                }
+
+                return event::Status::Captured;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key,
+                modifiers,
+                ..
+            }) => {
+                let cursor = self.state.cursor;
+
+                let date = match key.as_ref() {
+                    keyboard::Key::Named(key::Named::ArrowLeft) => {
+                        cursor.add_days(-1)
+                    }
+                    keyboard::Key::Named(key::Named::ArrowRight) => {
+                        cursor.add_days(1)
+                    }
+                    keyboard::Key::Named(key::Named::ArrowUp) => {
+                        cursor.add_days(-7)
+                    }
+                    keyboard::Key::Named(key::Named::ArrowDown) => {
+                        cursor.add_days(7)
+                    }
+                    keyboard::Key::Named(key::Named::PageUp) => cursor
+                        .add_months(if modifiers.shift() { -12 } else { -1 }),
+                    keyboard::Key::Named(key::Named::PageDown) => cursor
+                        .add_months(if modifiers.shift() { 12 } else { 1 }),
+                    keyboard::Key::Named(key::Named::Home) => {
+                        let offset = (cursor.weekday().index() + 7
+                            - self.picker.first_weekday.index())
+                            % 7;
+
+                        cursor.add_days(-(offset as i64))
+                    }
+                    keyboard::Key::Named(key::Named::End) => {
+                        let offset = (self.picker.first_weekday.index() + 6
+                            - cursor.weekday().index())
+                            % 7;
+
+                        cursor.add_days(offset as i64)
+                    }
+                    keyboard::Key::Named(
+                        key::Named::Enter | key::Named::Space,
+                    ) => {
+                        if self.picker.pick(self.state, cursor, shell) {
+                            self.close();
+                        }
+
+                        return event::Status::Captured;
+                    }
+                    keyboard::Key::Named(
+                        key::Named::Escape | key::Named::Tab,
+                    ) => {
+                        self.close();
+
+                        return event::Status::Captured;
+                    }
+                    _ => return event::Status::Ignored,
+                };
+
+                self.state.move_cursor(self.picker.clamp(date));
+
+                return event::Status::Captured;
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let cell = cell_size(
+            self.picker
+                .text_size
+                .unwrap_or_else(|| renderer.default_size()),
+        );
+
+        let is_enabled = self
+            .targets(layout.bounds(), cell)
+            .find(|(_, bounds)| cursor.is_over(*bounds))
+            .is_some_and(|(target, _)| match target {
+                Target::Day(date) => self.picker.is_enabled(date),
+                target => self.navigate(target).is_some(),
+            });
+
+        if is_enabled {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) {
+        let appearance = (self.picker.style)(theme, Status::Opened);
+        let bounds = layout.bounds();
+        let text_size = self
+            .picker
+            .text_size
+            .unwrap_or_else(|| renderer.default_size());
+        let font = self.picker.font.unwrap_or_else(|| renderer.default_font());
+        let cell = cell_size(text_size);
+        let radius = Border::rounded(appearance.popup_border.radius);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: appearance.popup_border,
+                ..renderer::Quad::default()
+            },
+            appearance.popup_background,
+        );
+
+        let title = self.state.month;
+
+        draw_cell(
+            renderer,
+            Rectangle {
+                x: bounds.x + POPUP_PADDING + 2.0 * cell,
+                y: bounds.y + POPUP_PADDING,
+                width: 3.0 * cell,
+                height: cell,
+            },
+            &format!(
+                "{} {}",
+                MONTHS[usize::from(title.month()) - 1],
+                title.year()
+            ),
+            text_size,
+            font,
+            appearance.text_color,
+            None,
+            Border::default(),
+        );
+
+        for (column, weekday) in (0..7)
+            .map(|i| Weekday::ALL[(self.picker.first_weekday.index() + i) % 7])
+            .enumerate()
+        {
+            draw_cell(
+                renderer,
+                Rectangle {
+                    height: cell * 0.75,
+                    ..self.cell_bounds(bounds.position(), cell, column, 1.0)
+                },
+                weekday.short_name(),
+                text_size * 0.85,
+                font,
+                appearance.header_text_color,
+                None,
+                Border::default(),
+            );
+        }
+
+        // The range being previewed, including the one being picked
+        let preview = match (&self.picker.mode, self.state.anchor) {
+            (_, Some(anchor)) => {
+                let end = cursor
+                    .position()
+                    .and_then(|position| {
+                        self.targets(bounds, cell).find_map(
+                            |(target, bounds)| match target {
+                                Target::Day(date)
+                                    if bounds.contains(position) =>
+                                {
+                                    Some(date)
+                                }
+                                _ => None,
+                            },
+                        )
+                    })
+                    .unwrap_or(self.state.cursor);
+
+                Some(anchor.min(end)..=anchor.max(end))
+            }
+            (Mode::Range { selected, .. }, None) => selected.clone(),
+            (Mode::Single { .. }, None) => None,
+        };
+
+        for (target, cell_bounds) in self.targets(bounds, cell) {
+            let is_hovered = cursor.is_over(cell_bounds);
+
+            let Target::Day(date) = target else {
+                let is_enabled = self.navigate(target).is_some();
+
+                draw_cell(
+                    renderer,
+                    cell_bounds,
+                    match target {
+                        Target::PreviousYear => "«",
+                        Target::PreviousMonth => "‹",
+                        Target::NextMonth => "›",
+                        _ => "»",
+                    },
+                    text_size,
+                    font,
+                    if is_enabled {
+                        appearance.text_color
+                    } else {
+                        appearance.disabled_text_color
+                    },
+                    (is_enabled && is_hovered)
+                        .then_some(appearance.hovered_background),
+                    radius,
+                );
+
+                continue;
+            };
+
+            if preview.as_ref().is_some_and(|range| range.contains(&date)) {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: cell_bounds,
+                        ..renderer::Quad::default()
+                    },
+                    appearance.range_background,
+                );
+            }
+
+            let is_enabled = self.picker.is_enabled(date);
+            let is_selected = self.picker.is_selected(date)
+                || self.state.anchor == Some(date);
+            let is_cursor = self.state.is_keyboard && self.state.cursor == date;
+
+            let background = if is_selected {
+                Some(appearance.selected_background)
+            } else if is_enabled && (is_hovered || is_cursor) {
+                Some(appearance.hovered_background)
+            } else {
+                None
+            };
+
+            let border = if self.picker.today == Some(date) {
+                Border {
+                    color: appearance.today_border,
+                    width: 1.0,
+                    ..radius
+                }
+            } else {
+                radius
+            };
+
+            let color = if !is_enabled {
+                appearance.disabled_text_color
+            } else if is_selected {
+                appearance.selected_text_color
+            } else if date.with_day(1) != self.state.month {
+                appearance.outside_text_color
+            } else {
+                appearance.text_color
+            };
+
+            draw_cell(
+                renderer,
+                cell_bounds,
+                &date.day().to_string(),
+                text_size,
+                font,
+                color,
+                background,
+                border,
+            );
+        }
+    }
+}
+
+const MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+/// A day of the proleptic Gregorian calendar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date {
+    year: i32,
+    month: u8,
+    day: u8,
+}
+
+impl Date {
+    /// The earliest [`Date`] supported.
+    pub const MIN: Self = Self {
+        year: -9999,
+        month: 1,
+        day: 1,
+    };
+
+    /// The latest [`Date`] supported.
+    pub const MAX: Self = Self {
+        year: 9999,
+        month: 12,
+        day: 31,
+    };
+
+    /// Creates a new [`Date`] from its year, month (starting at 1), and day
+    /// (starting at 1).
+    ///
+    /// Returns `None` if the date does not exist or is out of the supported
+    /// range.
+    pub fn from_ymd(year: i32, month: u8, day: u8) -> Option<Self> {
+        ((Self::MIN.year..=Self::MAX.year).contains(&year)
+            && (1..=12).contains(&month)
+            && day >= 1
+            && day <= days_in_month(year, month))
+        .then_some(Self { year, month, day })
+    }
+
+    /// Returns the year of the [`Date`].
+    pub fn year(self) -> i32 {
+        self.year
+    }
+
+    /// Returns the month of the [`Date`], starting at 1.
+    pub fn month(self) -> u8 {
+        self.month
+    }
+
+    /// Returns the day of the month of the [`Date`], starting at 1.
+    pub fn day(self) -> u8 {
+        self.day
+    }
+
+    /// Returns the [`Weekday`] of the [`Date`].
+    pub fn weekday(self) -> Weekday {
+        // 1970-01-01 was a Thursday
+        Weekday::ALL[(self.to_days() + 3).rem_euclid(7) as usize]
+    }
+
+    /// Returns the amount of days of the month of the [`Date`].
+    pub fn days_in_month(self) -> u8 {
+        days_in_month(self.year, self.month)
+    }
+
+    /// Returns the [`Date`] the given amount of days after this one, which
+    /// can be negative.
+    pub fn add_days(self, days: i64) -> Self {
+        Self::from_days(
+            (self.to_days() + days)
+                .clamp(Self::MIN.to_days(), Self::MAX.to_days()),
+        )
+    }
+
+    /// Returns the [`Date`] the given amount of months after this one, which
+    /// can be negative.
+    ///
+    /// The day is clamped to the days of the resulting month.
+    pub fn add_months(self, months: i32) -> Self {
+        let index = (self.year * 12 + i32::from(self.month) - 1 + months)
+            .clamp(Self::MIN.year * 12, Self::MAX.year * 12 + 11);
+
+        let year = index.div_euclid(12);
+        let month = index.rem_euclid(12) as u8 + 1;
+
+        Self {
+            year,
+            month,
+            day: self.day.min(days_in_month(year, month)),
+        }
+    }
+
+    fn with_day(self, day: u8) -> Self {
+        Self {
+            day: day.clamp(1, self.days_in_month()),
+            ..self
+        }
+    }
+
+    /// Returns the amount of days since 1970-01-01.
+    fn to_days(self) -> i64 {
+        let year = i64::from(self.year) - i64::from(self.month <= 2);
+        let era = year.div_euclid(400);
+        let year_of_era = year - era * 400;
+        let month = i64::from(self.month);
+        let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2)
+            / 5
+            + i64::from(self.day)
+            - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4
+            - year_of_era / 100
+            + day_of_year;
+
+        era * 146_097 + day_of_era - 719_468
+    }
+
+    fn from_days(days: i64) -> Self {
+        let days = days + 719_468;
+        let era = days.div_euclid(146_097);
+        let day_of_era = days - era * 146_097;
+        let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36524
+            - day_of_era / 146_096)
+            / 365;
+        let day_of_year = day_of_era
+            - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let month = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * month + 2) / 5 + 1;
+        let month = if month < 10 { month + 3 } else { month - 9 };
+        let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+        Self {
+            year: year as i32,
+            month: month as u8,
+            day: day as u8,
+        }
+    }
+}
+
+impl Default for Date {
+    fn default() -> Self {
+        Self {
+            year: 1970,
+            month: 1,
+            day: 1,
+        }
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+fn days_in_month(year: i32, month: u8) -> u8 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// A day of the week.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[allow(missing_docs)]
+pub enum Weekday {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+impl Weekday {
+    const ALL: [Self; 7] = [
+        Self::Monday,
+        Self::Tuesday,
+        Self::Wednesday,
+        Self::Thursday,
+        Self::Friday,
+        Self::Saturday,
+        Self::Sunday,
+    ];
+
+    fn index(self) -> usize {
+        self as usize
+    }
+
+    fn short_name(self) -> &'static str {
+        match self {
+            Self::Monday => "Mo",
+            Self::Tuesday => "Tu",
+            Self::Wednesday => "We",
+            Self::Thursday => "Th",
+            Self::Friday => "Fr",
+            Self::Saturday => "Sa",
+            Self::Sunday => "Su",
+        }
+    }
+}
+
+/// The possible status of a [`DatePicker`] or a
+/// [`TimePicker`](crate::TimePicker).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The picker can be interacted with.
+    Active,
+    /// The picker is being hovered.
+    Hovered,
+    /// The picker is focused.
+    Focused,
+    /// The picker is open.
+    Opened,
+}
+
+/// The appearance of a [`DatePicker`] or a [`TimePicker`](crate::TimePicker).
+#[derive(Debug, Clone, Copy)]
+pub struct Appearance {
+    /// The text [`Color`] of the field and the popup.
+    pub text_color: Color,
+    /// The placeholder [`Color`] of the field.
+    pub placeholder_color: Color,
+    /// The [`Background`] of the field.
+    pub background: Background,
+    /// The [`Border`] of the field.
+    pub border: Border,
+    /// The [`Background`] of the popup.
+    pub popup_background: Background,
+    /// The [`Border`] of the popup.
+    pub popup_border: Border,
+    /// The text [`Color`] of the headers of the popup, like weekday names.
+    pub header_text_color: Color,
+    /// The text [`Color`] of the days outside of the shown month.
+    pub outside_text_color: Color,
+    /// The text [`Color`] of the values that cannot be picked.
+    pub disabled_text_color: Color,
+    /// The [`Background`] of a hovered value.
+    pub hovered_background: Background,
+    /// The [`Background`] of a selected value.
+    pub selected_background: Background,
+    /// The text [`Color`] of a selected value.
+    pub selected_text_color: Color,
+    /// The [`Background`] of the days of a selected range.
+    pub range_background: Background,
+    /// The border [`Color`] of the current day.
+    pub today_border: Color,
+}
+
+/// The style of a [`DatePicker`] or a [`TimePicker`](crate::TimePicker).
+pub type Style<Theme> = fn(&Theme, Status) -> Appearance;
+
+/// The default style of a [`DatePicker`] or a
+/// [`TimePicker`](crate::TimePicker).
+pub trait DefaultStyle {
+    /// Returns the default style of a picker.
+    fn default_style() -> Style<Self>;
+}
+
+impl DefaultStyle for Theme {
+    fn default_style() -> Style<Self> {
+        default
+    }
+}
+
+impl DefaultStyle for Appearance {
+    fn default_style() -> Style<Self> {
+        |appearance, _status| *appearance
+    }
+}
+
+/// The default style of a [`DatePicker`] or a
+/// [`TimePicker`](crate::TimePicker).
+pub fn default(theme: &Theme, status: Status) -> Appearance {
+    let palette = theme.extended_palette();
+
+    let active = Appearance {
+        text_color: palette.background.weak.text,
+        placeholder_color: palette.background.strong.color,
+        background: palette.background.base.color.into(),
+        border: Border {
+            radius: 2.0.into(),
+            width: 1.0,
+            color: palette.background.strong.color,
+        },
+        popup_background: palette.background.base.color.into(),
+        popup_border: Border {
+            radius: 2.0.into(),
+            width: 1.0,
+            color: palette.background.strong.color,
+        },
+        header_text_color: palette.background.strong.text,
+        outside_text_color: palette.background.strong.color,
+        disabled_text_color: palette.background.weak.color,
+        hovered_background: palette.background.weak.color.into(),
+        selected_background: palette.primary.strong.color.into(),
+        selected_text_color: palette.primary.strong.text,
+        range_background: palette.primary.weak.color.into(),
+        today_border: palette.primary.base.color,
+    };
+
+    match status {
+        Status::Active => active,
+        Status::Hovered | Status::Focused | Status::Opened => Appearance {
+            border: Border {
+                color: palette.primary.strong.color,
+                ..active.border
+            },
+            ..active
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u8, day: u8) -> Date {
+        Date::from_ymd(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn validates_dates() {
+        assert!(Date::from_ymd(2024, 2, 29).is_some());
+        assert!(Date::from_ymd(2023, 2, 29).is_none());
+        assert!(Date::from_ymd(1900, 2, 29).is_none());
+        assert!(Date::from_ymd(2000, 2, 29).is_some());
+        assert!(Date::from_ymd(2024, 4, 31).is_none());
+        assert!(Date::from_ymd(2024, 13, 1).is_none());
+        assert!(Date::from_ymd(2024, 1, 0).is_none());
+    }
+
+    #[test]
+    fn converts_days() {
+        assert_eq!(Date::default().to_days(), 0);
+        assert_eq!(date(2000, 3, 1).to_days(), 11_017);
+
+        for days in [-800_000, -1, 0, 59, 11_016, 19_782, 800_000] {
+            assert_eq!(Date::from_days(days).to_days(), days);
+        }
+    }
+
+    #[test]
+    fn computes_weekdays() {
+        assert_eq!(Date::default().weekday(), Weekday::Thursday);
+        assert_eq!(date(2024, 5, 17).weekday(), Weekday::Friday);
+        assert_eq!(date(2000, 1, 1).weekday(), Weekday::Saturday);
+    }
+
+    #[test]
+    fn adds_days_and_months() {
+        assert_eq!(date(2024, 2, 28).add_days(1), date(2024, 2, 29));
+        assert_eq!(date(2024, 3, 1).add_days(-1), date(2024, 2, 29));
+        assert_eq!(date(2023, 12, 31).add_days(1), date(2024, 1, 1));
+        assert_eq!(date(2024, 1, 31).add_months(1), date(2024, 2, 29));
+        assert_eq!(date(2024, 1, 15).add_months(-1), date(2023, 12, 15));
+        assert_eq!(date(2024, 5, 17).add_months(-24), date(2022, 5, 17));
+        assert_eq!(Date::MAX.add_days(1), Date::MAX);
+    }
+}
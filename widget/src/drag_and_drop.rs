@@ -0,0 +1,156 @@
+//! Drag and drop payloads between widgets.
+//!
+//! A [`Draggable`] wraps some content that can be picked up with the mouse,
+//! carrying a payload of any type. While it is being dragged, a preview
+//! follows the cursor. A [`DropTarget`] wraps some content where the
+//! payload can be dropped, producing a message with the payload and the
+//! position of the drop.
+//!
+//! Like the rest of the state of your application, the payload being
+//! dragged is owned by you: a [`Draggable`] tells you when a drag starts
+//! and ends with a [`DragEvent`], and every [`DropTarget`] needs to be given
+//! the payload being dragged, if any.
+//!
+//! # Example
+//! ```no_run
+//! # use iced_widget::core::Point;
+//! # type Element<'a, Message> =
+//! #     iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! use iced_widget::drag_and_drop::DragEvent;
+//! use iced_widget::{column, draggable, drop_target, text};
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Dragged(DragEvent<usize>),
+//!     Dropped(usize, Point),
+//! }
+//!
+//! struct List {
+//!     items: Vec<String>,
+//!     dragging: Option<usize>,
+//! }
+//!
+//! impl List {
+//!     fn view(&self) -> Element<'_, Message> {
+//!         let items = column(self.items.iter().enumerate().map(|(i, item)| {
+//!             draggable(text(item), i).on_drag(Message::Dragged).into()
+//!         }));
+//!
+//!         drop_target(items, self.dragging)
+//!             .on_drop(Message::Dropped)
+//!             .into()
+//!     }
+//!
+//!     fn update(&mut self, message: Message) {
+//!         match message {
+//!             Message::Dragged(DragEvent::Picked { payload }) => {
+//!                 self.dragging = Some(payload);
+//!             }
+//!             Message::Dragged(_) => {
+//!                 self.dragging = None;
+//!             }
+//!             Message::Dropped(item, position) => {
+//!                 // Move the item to the given position...
+//!             }
+//!         }
+//!     }
+//! }
+//! ```
+mod draggable;
+mod drop_target;
+
+pub use draggable::Draggable;
+pub use drop_target::DropTarget;
+
+use crate::core::{Background, Border, Color, Theme};
+
+/// An event produced by a [`Draggable`] during a drag interaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DragEvent<P> {
+    /// The [`Draggable`] has been picked and is being dragged.
+    Picked {
+        /// The payload of the [`Draggable`].
+        payload: P,
+    },
+
+    /// The [`Draggable`] has been released.
+    ///
+    /// If it was released over a [`DropTarget`], the target produces its
+    /// own message as well.
+    Released {
+        /// The payload of the [`Draggable`].
+        payload: P,
+    },
+
+    /// The drag interaction has been canceled with the Escape key.
+    Canceled {
+        /// The payload of the [`Draggable`].
+        payload: P,
+    },
+}
+
+/// The possible status of a [`DropTarget`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// A payload is being dragged somewhere else.
+    Active,
+    /// A payload is being dragged over the [`DropTarget`].
+    Hovered,
+}
+
+/// The appearance of a [`DropTarget`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Appearance {
+    /// The [`Background`] drawn over the content of the [`DropTarget`].
+    pub background: Option<Background>,
+    /// The [`Border`] drawn around the content of the [`DropTarget`].
+    pub border: Border,
+}
+
+/// The style of a [`DropTarget`].
+pub type Style<Theme> = fn(&Theme, Status) -> Appearance;
+
+/// The default style of a [`DropTarget`].
+pub trait DefaultStyle {
+    /// Returns the default style of a [`DropTarget`].
+    fn default_style() -> Style<Self>;
+}
+
+impl DefaultStyle for Theme {
+    fn default_style() -> Style<Self> {
+        default
+    }
+}
+
+impl DefaultStyle for Appearance {
+    fn default_style() -> Style<Self> {
+        |appearance, _status| *appearance
+    }
+}
+
+/// The default style of a [`DropTarget`].
+pub fn default(theme: &Theme, status: Status) -> Appearance {
+    let palette = theme.extended_palette();
+
+    match status {
+        Status::Active => Appearance {
+            background: None,
+            border: Border {
+                width: 1.0,
+                color: palette.primary.weak.color,
+                radius: 0.0.into(),
+            },
+        },
+        Status::Hovered => Appearance {
+            background: Some(Background::Color(Color {
+                a: 0.5,
+                ..palette.primary.base.color
+            })),
+            border: Border {
+                width: 2.0,
+                color: palette.primary.strong.color,
+                radius: 0.0.into(),
+            },
+        },
+    }
+}
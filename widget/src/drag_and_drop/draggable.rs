@@ -0,0 +1,455 @@
+use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::touch;
+use crate::core::widget::{tree, Operation, Tree};
+use crate::core::{
+    Clipboard, Element, Layout, Length, Point, Rectangle, Shell, Size, Vector,
+    Widget,
+};
+use crate::drag_and_drop::DragEvent;
+
+const DRAG_DEADBAND_DISTANCE: f32 = 10.0;
+
+/// Some content that can be dragged with the mouse, carrying a payload.
+///
+/// Once the cursor moves far enough while pressing the [`Draggable`], a
+/// preview of its content follows the cursor until the mouse button is
+/// released or the Escape key is pressed.
+#[allow(missing_debug_implementations)]
+pub struct Draggable<
+    'a,
+    P,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> where
+    Renderer: crate::core::Renderer,
+{
+    content: Element<'a, Message, Theme, Renderer>,
+    preview: Option<Element<'a, Message, Theme, Renderer>>,
+    payload: P,
+    on_drag: Option<Box<dyn Fn(DragEvent<P>) -> Message + 'a>>,
+}
+
+impl<'a, P, Message, Theme, Renderer> Draggable<'a, P, Message, Theme, Renderer>
+where
+    Renderer: crate::core::Renderer,
+{
+    /// Creates a new [`Draggable`] with the given content and payload.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        payload: P,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            preview: None,
+            payload,
+            on_drag: None,
+        }
+    }
+
+    /// Sets the message that will be produced for each [`DragEvent`] of the
+    /// [`Draggable`].
+    ///
+    /// The [`Draggable`] cannot be dragged until this is set.
+    pub fn on_drag(
+        mut self,
+        on_drag: impl Fn(DragEvent<P>) -> Message + 'a,
+    ) -> Self {
+        self.on_drag = Some(Box::new(on_drag));
+        self
+    }
+
+    /// Sets the preview that follows the cursor while the [`Draggable`] is
+    /// being dragged.
+    ///
+    /// By default, the content of the [`Draggable`] itself is used.
+    pub fn preview(
+        mut self,
+        preview: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        self.preview = Some(preview.into());
+        self
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+enum State {
+    #[default]
+    Idle,
+    Pressed {
+        origin: Point,
+        grab: Vector,
+    },
+    Dragging {
+        position: Point,
+        grab: Vector,
+    },
+}
+
+impl<'a, P, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Draggable<'a, P, Message, Theme, Renderer>
+where
+    P: Clone,
+    Renderer: crate::core::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        std::iter::once(&self.content)
+            .chain(&self.preview)
+            .map(Tree::new)
+            .collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let children: Vec<_> = std::iter::once(&self.content)
+            .chain(&self.preview)
+            .collect();
+
+        tree.diff_children(&children);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        let Some(on_drag) = &self.on_drag else {
+            *state = State::Idle;
+
+            return self.content.as_widget_mut().on_event(
+                &mut tree.children[0],
+                event,
+                layout,
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            );
+        };
+
+        match (*state, &event) {
+            (
+                State::Dragging { grab, .. },
+                Event::Mouse(mouse::Event::CursorMoved { .. })
+                | Event::Touch(touch::Event::FingerMoved { .. }),
+            ) => {
+                if let Some(position) = cursor.position() {
+                    *state = State::Dragging { position, grab };
+
+                    shell.invalidate_layout();
+                }
+
+                return event::Status::Captured;
+            }
+            (
+                State::Dragging { .. },
+                Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                | Event::Touch(
+                    touch::Event::FingerLifted { .. }
+                    | touch::Event::FingerLost { .. },
+                ),
+            ) => {
+                *state = State::Idle;
+
+                shell.publish(on_drag(DragEvent::Released {
+                    payload: self.payload.clone(),
+                }));
+                shell.invalidate_layout();
+
+                // Drop targets need to see the release as well
+                return event::Status::Ignored;
+            }
+            (
+                State::Dragging { .. },
+                Event::Keyboard(keyboard::Event::KeyPressed {
+                    key: keyboard::Key::Named(key::Named::Escape),
+                    ..
+                }),
+            ) => {
+                *state = State::Idle;
+
+                shell.publish(on_drag(DragEvent::Canceled {
+                    payload: self.payload.clone(),
+                }));
+                shell.invalidate_layout();
+
+                return event::Status::Captured;
+            }
+            (
+                State::Pressed { origin, grab },
+                Event::Mouse(mouse::Event::CursorMoved { .. })
+                | Event::Touch(touch::Event::FingerMoved { .. }),
+            ) => {
+                if let Some(position) = cursor.position() {
+                    if position.distance(origin) > DRAG_DEADBAND_DISTANCE {
+                        *state = State::Dragging { position, grab };
+
+                        shell.publish(on_drag(DragEvent::Picked {
+                            payload: self.payload.clone(),
+                        }));
+                        shell.invalidate_layout();
+
+                        return event::Status::Captured;
+                    }
+                }
+            }
+            (
+                State::Pressed { .. },
+                Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                | Event::Touch(
+                    touch::Event::FingerLifted { .. }
+                    | touch::Event::FingerLost { .. },
+                ),
+            ) => {
+                *state = State::Idle;
+            }
+            _ => {}
+        }
+
+        if let State::Dragging { .. } = state {
+            return event::Status::Ignored;
+        }
+
+        let status = self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event.clone(),
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        if status == event::Status::Captured {
+            return status;
+        }
+
+        if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerPressed { .. }) = event
+        {
+            let bounds = layout.bounds();
+
+            if let Some(origin) = cursor.position_over(bounds) {
+                *state = State::Pressed {
+                    origin,
+                    grab: origin - bounds.position(),
+                };
+
+                return event::Status::Captured;
+            }
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        if let State::Dragging { .. } = state {
+            return mouse::Interaction::Grabbing;
+        }
+
+        let interaction = self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        );
+
+        if interaction == mouse::Interaction::Idle
+            && self.on_drag.is_some()
+            && cursor.is_over(layout.bounds())
+        {
+            mouse::Interaction::Grab
+        } else {
+            interaction
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let State::Dragging { position, grab } =
+            *tree.state.downcast_ref::<State>()
+        else {
+            return self.content.as_widget_mut().overlay(
+                &mut tree.children[0],
+                layout,
+                renderer,
+                translation,
+            );
+        };
+
+        let preview = match &self.preview {
+            Some(preview) => Preview {
+                element: preview,
+                tree: &mut tree.children[1],
+                size: None,
+                position: position + translation,
+            },
+            None => Preview {
+                element: &self.content,
+                tree: &mut tree.children[0],
+                size: Some(layout.bounds().size()),
+                position: position - grab + translation,
+            },
+        };
+
+        Some(overlay::Element::new(Box::new(preview)))
+    }
+}
+
+impl<'a, P, Message, Theme, Renderer>
+    From<Draggable<'a, P, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    P: Clone + 'a,
+    Message: 'a,
+    Theme: 'a,
+    Renderer: crate::core::Renderer + 'a,
+{
+    fn from(draggable: Draggable<'a, P, Message, Theme, Renderer>) -> Self {
+        Self::new(draggable)
+    }
+}
+
+/// The preview of a [`Draggable`] that follows the cursor.
+struct Preview<'a, 'b, Message, Theme, Renderer> {
+    element: &'b Element<'a, Message, Theme, Renderer>,
+    tree: &'b mut Tree,
+    size: Option<Size>,
+    position: Point,
+}
+
+impl<'a, 'b, Message, Theme, Renderer>
+    overlay::Overlay<Message, Theme, Renderer>
+    for Preview<'a, 'b, Message, Theme, Renderer>
+where
+    Renderer: crate::core::Renderer,
+{
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> layout::Node {
+        let limits = match self.size {
+            Some(size) => layout::Limits::new(size, size),
+            None => layout::Limits::new(Size::ZERO, bounds),
+        };
+
+        self.element
+            .as_widget()
+            .layout(self.tree, renderer, &limits)
+            .move_to(self.position)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) {
+        self.element.as_widget().draw(
+            self.tree,
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            &Rectangle::with_size(Size::INFINITY),
+        );
+    }
+
+    fn is_over(
+        &self,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+        _cursor_position: Point,
+    ) -> bool {
+        false
+    }
+}
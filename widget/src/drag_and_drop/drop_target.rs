@@ -0,0 +1,257 @@
+use crate::core::event::{self, Event};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::touch;
+use crate::core::widget::{Operation, Tree};
+use crate::core::{
+    Clipboard, Color, Element, Layout, Length, Point, Rectangle, Shell, Size,
+    Vector, Widget,
+};
+use crate::drag_and_drop::{Appearance, DefaultStyle, Status, Style};
+
+/// Some content where the payload of a [`Draggable`] can be dropped.
+///
+/// While a payload is being dragged, the [`DropTarget`] is highlighted, and
+/// even more so when the payload is dragged over it.
+///
+/// [`Draggable`]: super::Draggable
+#[allow(missing_debug_implementations)]
+pub struct DropTarget<
+    'a,
+    P,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> where
+    Renderer: crate::core::Renderer,
+{
+    content: Element<'a, Message, Theme, Renderer>,
+    dragging: Option<P>,
+    on_drop: Option<Box<dyn Fn(P, Point) -> Message + 'a>>,
+    style: Style<Theme>,
+}
+
+impl<'a, P, Message, Theme, Renderer>
+    DropTarget<'a, P, Message, Theme, Renderer>
+where
+    Renderer: crate::core::Renderer,
+{
+    /// Creates a new [`DropTarget`] with the given content and the payload
+    /// that is currently being dragged, if any.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        dragging: Option<P>,
+    ) -> Self
+    where
+        Theme: DefaultStyle,
+    {
+        Self {
+            content: content.into(),
+            dragging,
+            on_drop: None,
+            style: Theme::default_style(),
+        }
+    }
+
+    /// Sets the message that will be produced when a payload is dropped on
+    /// the [`DropTarget`].
+    ///
+    /// The [`Point`] is the position of the drop, relative to the top-left
+    /// corner of the [`DropTarget`].
+    pub fn on_drop(
+        mut self,
+        on_drop: impl Fn(P, Point) -> Message + 'a,
+    ) -> Self {
+        self.on_drop = Some(Box::new(on_drop));
+        self
+    }
+
+    /// Sets the style of the [`DropTarget`].
+    pub fn style(mut self, style: fn(&Theme, Status) -> Appearance) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+impl<'a, P, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for DropTarget<'a, P, Message, Theme, Renderer>
+where
+    P: Clone,
+    Renderer: crate::core::Renderer,
+{
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        if let event::Status::Captured = self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event.clone(),
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        ) {
+            return event::Status::Captured;
+        }
+
+        if let Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerLifted { .. }) = event
+        {
+            let bounds = layout.bounds();
+
+            if let (Some(payload), Some(on_drop), Some(position)) =
+                (&self.dragging, &self.on_drop, cursor.position_over(bounds))
+            {
+                shell.publish(on_drop(
+                    payload.clone(),
+                    Point::ORIGIN + (position - bounds.position()),
+                ));
+
+                return event::Status::Captured;
+            }
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+
+        if self.dragging.is_none() || self.on_drop.is_none() {
+            return;
+        }
+
+        let bounds = layout.bounds();
+
+        let status = if cursor.is_over(bounds) {
+            Status::Hovered
+        } else {
+            Status::Active
+        };
+
+        let appearance = (self.style)(theme, status);
+
+        renderer.with_layer(bounds, |renderer| {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border: appearance.border,
+                    ..renderer::Quad::default()
+                },
+                appearance.background.unwrap_or(Color::TRANSPARENT.into()),
+            );
+        });
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            translation,
+        )
+    }
+}
+
+impl<'a, P, Message, Theme, Renderer>
+    From<DropTarget<'a, P, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    P: Clone + 'a,
+    Message: 'a,
+    Theme: 'a,
+    Renderer: crate::core::Renderer + 'a,
+{
+    fn from(drop_target: DropTarget<'a, P, Message, Theme, Renderer>) -> Self {
+        Self::new(drop_target)
+    }
+}
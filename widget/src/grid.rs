@@ -0,0 +1,745 @@
+//! Lay out content in rows and columns that stay aligned.
+use crate::core::alignment;
+use crate::core::event::{self, Event};
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::widget::{Operation, Tree};
+use crate::core::{
+    Clipboard, Element, Length, Padding, Pixels, Point, Rectangle, Shell, Size,
+    Vector, Widget,
+};
+
+/// A container that places its contents in the cells of a grid.
+///
+/// The columns and rows of a [`Grid`] are tracks that can have a fixed size,
+/// fill a portion of the remaining space, or shrink to fit their contents.
+/// Every [`Cell`] of the [`Grid`] is placed at a row and a column, and it
+/// can span multiple of them.
+///
+/// Tracks that are not explicitly defined shrink to fit their contents.
+#[allow(missing_debug_implementations)]
+pub struct Grid<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer> {
+    columns: Vec<Length>,
+    rows: Vec<Length>,
+    column_spacing: f32,
+    row_spacing: f32,
+    padding: Padding,
+    width: Length,
+    height: Length,
+    horizontal_alignment: alignment::Horizontal,
+    vertical_alignment: alignment::Vertical,
+    placements: Vec<Placement>,
+    children: Vec<Element<'a, Message, Theme, Renderer>>,
+}
+
+impl<'a, Message, Theme, Renderer> Grid<'a, Message, Theme, Renderer>
+where
+    Renderer: crate::core::Renderer,
+{
+    /// Creates an empty [`Grid`].
+    pub fn new() -> Self {
+        Self {
+            columns: Vec::new(),
+            rows: Vec::new(),
+            column_spacing: 0.0,
+            row_spacing: 0.0,
+            padding: Padding::ZERO,
+            width: Length::Shrink,
+            height: Length::Shrink,
+            horizontal_alignment: alignment::Horizontal::Left,
+            vertical_alignment: alignment::Vertical::Top,
+            placements: Vec::new(),
+            children: Vec::new(),
+        }
+    }
+
+    /// Creates a [`Grid`] with the given cells.
+    pub fn with_cells(
+        cells: impl IntoIterator<Item = Cell<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self::new().extend(cells)
+    }
+
+    /// Sets the column tracks of the [`Grid`].
+    pub fn columns(
+        mut self,
+        columns: impl IntoIterator<Item = Length>,
+    ) -> Self {
+        self.columns = columns.into_iter().collect();
+        self.width = self
+            .columns
+            .iter()
+            .copied()
+            .fold(self.width, Length::enclose);
+        self
+    }
+
+    /// Sets the row tracks of the [`Grid`].
+    pub fn rows(mut self, rows: impl IntoIterator<Item = Length>) -> Self {
+        self.rows = rows.into_iter().collect();
+        self.height =
+            self.rows.iter().copied().fold(self.height, Length::enclose);
+        self
+    }
+
+    /// Sets both the horizontal and vertical spacing _between_ cells.
+    pub fn spacing(self, amount: impl Into<Pixels>) -> Self {
+        let amount = amount.into();
+
+        self.column_spacing(amount).row_spacing(amount)
+    }
+
+    /// Sets the horizontal spacing _between_ columns.
+    pub fn column_spacing(mut self, amount: impl Into<Pixels>) -> Self {
+        self.column_spacing = amount.into().0;
+        self
+    }
+
+    /// Sets the vertical spacing _between_ rows.
+    pub fn row_spacing(mut self, amount: impl Into<Pixels>) -> Self {
+        self.row_spacing = amount.into().0;
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`Grid`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the width of the [`Grid`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Grid`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the default horizontal alignment of the contents of the cells of
+    /// the [`Grid`].
+    pub fn align_x(mut self, alignment: alignment::Horizontal) -> Self {
+        self.horizontal_alignment = alignment;
+        self
+    }
+
+    /// Sets the default vertical alignment of the contents of the cells of
+    /// the [`Grid`].
+    pub fn align_y(mut self, alignment: alignment::Vertical) -> Self {
+        self.vertical_alignment = alignment;
+        self
+    }
+
+    /// Adds a [`Cell`] to the [`Grid`].
+    pub fn push(mut self, cell: Cell<'a, Message, Theme, Renderer>) -> Self {
+        self.placements.push(Placement {
+            row: cell.row,
+            column: cell.column,
+            row_span: cell.row_span,
+            column_span: cell.column_span,
+            horizontal_alignment: cell.horizontal_alignment,
+            vertical_alignment: cell.vertical_alignment,
+        });
+
+        self.children.push(cell.content);
+        self
+    }
+
+    /// Adds a [`Cell`] to the [`Grid`], if `Some`.
+    pub fn push_maybe(
+        self,
+        cell: Option<Cell<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        if let Some(cell) = cell {
+            self.push(cell)
+        } else {
+            self
+        }
+    }
+
+    /// Extends the [`Grid`] with the given cells.
+    pub fn extend(
+        self,
+        cells: impl IntoIterator<Item = Cell<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        cells.into_iter().fold(self, Self::push)
+    }
+}
+
+impl<'a, Message, Renderer> Default for Grid<'a, Message, Renderer>
+where
+    Renderer: crate::core::Renderer,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Some content placed in a [`Grid`].
+#[allow(missing_debug_implementations)]
+pub struct Cell<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer> {
+    content: Element<'a, Message, Theme, Renderer>,
+    row: usize,
+    column: usize,
+    row_span: usize,
+    column_span: usize,
+    horizontal_alignment: Option<alignment::Horizontal>,
+    vertical_alignment: Option<alignment::Vertical>,
+}
+
+impl<'a, Message, Theme, Renderer> Cell<'a, Message, Theme, Renderer> {
+    /// Creates a new [`Cell`] at the given row and column with the given
+    /// content.
+    pub fn new(
+        row: usize,
+        column: usize,
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            row,
+            column,
+            row_span: 1,
+            column_span: 1,
+            horizontal_alignment: None,
+            vertical_alignment: None,
+        }
+    }
+
+    /// Sets the amount of rows the [`Cell`] spans.
+    pub fn row_span(mut self, rows: usize) -> Self {
+        self.row_span = rows.max(1);
+        self
+    }
+
+    /// Sets the amount of columns the [`Cell`] spans.
+    pub fn column_span(mut self, columns: usize) -> Self {
+        self.column_span = columns.max(1);
+        self
+    }
+
+    /// Sets the horizontal alignment of the contents of the [`Cell`],
+    /// overriding the default alignment of the [`Grid`].
+    pub fn align_x(mut self, alignment: alignment::Horizontal) -> Self {
+        self.horizontal_alignment = Some(alignment);
+        self
+    }
+
+    /// Sets the vertical alignment of the contents of the [`Cell`],
+    /// overriding the default alignment of the [`Grid`].
+    pub fn align_y(mut self, alignment: alignment::Vertical) -> Self {
+        self.vertical_alignment = Some(alignment);
+        self
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Placement {
+    row: usize,
+    column: usize,
+    row_span: usize,
+    column_span: usize,
+    horizontal_alignment: Option<alignment::Horizontal>,
+    vertical_alignment: Option<alignment::Vertical>,
+}
+
+impl Placement {
+    fn columns(&self) -> std::ops::Range<usize> {
+        self.column..self.column + self.column_span
+    }
+
+    fn rows(&self) -> std::ops::Range<usize> {
+        self.row..self.row + self.row_span
+    }
+}
+
+/// Computes the sizes of a set of tracks.
+///
+/// Each requirement is the range of tracks spanned by a cell and the size
+/// of its contents. Requirements only grow the tracks that shrink to fit
+/// their contents, evenly.
+///
+/// Fill tracks share the space left in `available`; if `available` is
+/// unbounded, they shrink to fit their contents instead.
+fn resolve_tracks(
+    tracks: &[Length],
+    available: f32,
+    spacing: f32,
+    mut requirements: Vec<(std::ops::Range<usize>, f32)>,
+) -> Vec<f32> {
+    let is_fluid = available.is_finite();
+    let is_flexible = |track: &Length| match track {
+        Length::Shrink => true,
+        Length::Fill | Length::FillPortion(_) => !is_fluid,
+        Length::Fixed(_) => false,
+    };
+
+    let mut sizes: Vec<f32> = tracks
+        .iter()
+        .map(|track| match track {
+            Length::Fixed(amount) => *amount,
+            _ => 0.0,
+        })
+        .collect();
+
+    // Cells spanning fewer tracks are resolved first
+    requirements.sort_by_key(|(span, _)| span.len());
+
+    for (span, size) in requirements {
+        if is_fluid && tracks[span.clone()].iter().any(Length::is_fill) {
+            continue;
+        }
+
+        let flexible = tracks[span.clone()]
+            .iter()
+            .filter(|track| is_flexible(track));
+        let count = flexible.count();
+
+        if count == 0 {
+            continue;
+        }
+
+        let covered = sizes[span.clone()].iter().sum::<f32>()
+            + spacing * (span.len() - 1) as f32;
+
+        if size <= covered {
+            continue;
+        }
+
+        let extra = (size - covered) / count as f32;
+
+        for (track, size) in
+            tracks[span.clone()].iter().zip(&mut sizes[span.clone()])
+        {
+            if is_flexible(track) {
+                *size += extra;
+            }
+        }
+    }
+
+    if is_fluid {
+        let fill_sum: u16 = tracks.iter().map(Length::fill_factor).sum();
+
+        if fill_sum > 0 {
+            let remaining = (available
+                - sizes.iter().sum::<f32>()
+                - spacing * tracks.len().saturating_sub(1) as f32)
+                .max(0.0);
+
+            for (track, size) in tracks.iter().zip(&mut sizes) {
+                if track.is_fill() {
+                    *size = remaining * f32::from(track.fill_factor())
+                        / f32::from(fill_sum);
+                }
+            }
+        }
+    }
+
+    sizes
+}
+
+/// Returns the offsets of the given tracks, followed by the total size.
+fn offsets(sizes: &[f32], start: f32, spacing: f32) -> Vec<f32> {
+    let mut offsets = Vec::with_capacity(sizes.len() + 1);
+    let mut offset = start;
+
+    for size in sizes {
+        offsets.push(offset);
+        offset += size + spacing;
+    }
+
+    offsets.push(offset - if sizes.is_empty() { 0.0 } else { spacing });
+    offsets
+}
+
+fn span(offsets: &[f32], spacing: f32, range: std::ops::Range<usize>) -> f32 {
+    offsets[range.end]
+        - offsets[range.start]
+        - if range.end == offsets.len() - 1 {
+            0.0
+        } else {
+            spacing
+        }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Grid<'a, Message, Theme, Renderer>
+where
+    Renderer: crate::core::Renderer,
+{
+    fn children(&self) -> Vec<Tree> {
+        self.children.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&self.children);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits
+            .width(self.width)
+            .height(self.height)
+            .shrink(self.padding);
+
+        let max = limits.max();
+
+        let tracks = |defined: &[Length], count: usize| -> Vec<Length> {
+            defined
+                .iter()
+                .copied()
+                .chain(std::iter::repeat(Length::Shrink))
+                .take(count.max(defined.len()))
+                .collect()
+        };
+
+        let columns = tracks(
+            &self.columns,
+            self.placements
+                .iter()
+                .map(|placement| placement.columns().end)
+                .max()
+                .unwrap_or(0),
+        );
+
+        let rows = tracks(
+            &self.rows,
+            self.placements
+                .iter()
+                .map(|placement| placement.rows().end)
+                .max()
+                .unwrap_or(0),
+        );
+
+        let widths = {
+            let requirements = self
+                .children
+                .iter()
+                .zip(&mut tree.children)
+                .zip(&self.placements)
+                .filter(|((child, _), placement)| {
+                    !child.as_widget().size().width.is_fill()
+                        && columns[placement.columns()]
+                            .iter()
+                            .any(|column| !matches!(column, Length::Fixed(_)))
+                })
+                .map(|((child, tree), placement)| {
+                    let node = child.as_widget().layout(
+                        tree,
+                        renderer,
+                        &layout::Limits::new(Size::ZERO, max),
+                    );
+
+                    (placement.columns(), node.size().width)
+                })
+                .collect();
+
+            resolve_tracks(
+                &columns,
+                if self.width == Length::Shrink {
+                    f32::INFINITY
+                } else {
+                    max.width
+                },
+                self.column_spacing,
+                requirements,
+            )
+        };
+
+        let x = offsets(&widths, self.padding.left, self.column_spacing);
+
+        let heights = {
+            let requirements = self
+                .children
+                .iter()
+                .zip(&mut tree.children)
+                .zip(&self.placements)
+                .filter(|((child, _), placement)| {
+                    !child.as_widget().size().height.is_fill()
+                        && rows[placement.rows()]
+                            .iter()
+                            .any(|row| !matches!(row, Length::Fixed(_)))
+                })
+                .map(|((child, tree), placement)| {
+                    let width =
+                        span(&x, self.column_spacing, placement.columns());
+
+                    let node = child.as_widget().layout(
+                        tree,
+                        renderer,
+                        &layout::Limits::new(
+                            Size::ZERO,
+                            Size::new(width, max.height),
+                        ),
+                    );
+
+                    (placement.rows(), node.size().height)
+                })
+                .collect();
+
+            resolve_tracks(
+                &rows,
+                if self.height == Length::Shrink {
+                    f32::INFINITY
+                } else {
+                    max.height
+                },
+                self.row_spacing,
+                requirements,
+            )
+        };
+
+        let y = offsets(&heights, self.padding.top, self.row_spacing);
+
+        let nodes = self
+            .children
+            .iter()
+            .zip(&mut tree.children)
+            .zip(&self.placements)
+            .map(|((child, tree), placement)| {
+                let cell = Size::new(
+                    span(&x, self.column_spacing, placement.columns()),
+                    span(&y, self.row_spacing, placement.rows()),
+                );
+
+                let mut node = child.as_widget().layout(
+                    tree,
+                    renderer,
+                    &layout::Limits::new(Size::ZERO, cell),
+                );
+
+                node.move_to_mut(Point::new(
+                    x[placement.column],
+                    y[placement.row],
+                ));
+
+                node.align_mut(
+                    placement
+                        .horizontal_alignment
+                        .unwrap_or(self.horizontal_alignment)
+                        .into(),
+                    placement
+                        .vertical_alignment
+                        .unwrap_or(self.vertical_alignment)
+                        .into(),
+                    cell,
+                );
+
+                node
+            })
+            .collect();
+
+        let intrinsic_size = Size::new(
+            x[widths.len()] - self.padding.left,
+            y[heights.len()] - self.padding.top,
+        );
+
+        let size = limits.resolve(self.width, self.height, intrinsic_size);
+
+        layout::Node::with_children(size.expand(self.padding), nodes)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        operation.container(None, layout.bounds(), &mut |operation| {
+            self.children
+                .iter()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .for_each(|((child, state), layout)| {
+                    child
+                        .as_widget()
+                        .operate(state, layout, renderer, operation);
+                });
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        self.children
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .map(|((child, state), layout)| {
+                child.as_widget_mut().on_event(
+                    state,
+                    event.clone(),
+                    layout,
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge)
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.children
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .map(|((child, state), layout)| {
+                child.as_widget().mouse_interaction(
+                    state, layout, cursor, viewport, renderer,
+                )
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        if layout.bounds().intersects(viewport) {
+            for ((child, state), layout) in self
+                .children
+                .iter()
+                .zip(&tree.children)
+                .zip(layout.children())
+            {
+                child.as_widget().draw(
+                    state, renderer, theme, style, layout, cursor, viewport,
+                );
+            }
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        overlay::from_children(
+            &mut self.children,
+            tree,
+            layout,
+            renderer,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Grid<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: crate::core::Renderer + 'a,
+{
+    fn from(grid: Grid<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(grid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mixed_tracks_are_resolved() {
+        let tracks = [
+            Length::Fixed(50.0),
+            Length::FillPortion(1),
+            Length::Shrink,
+            Length::FillPortion(3),
+        ];
+
+        // Requirements do not grow fill tracks when space is bounded
+        let sizes = resolve_tracks(
+            &tracks,
+            400.0,
+            10.0,
+            vec![(2..3, 30.0), (1..2, 1000.0)],
+        );
+
+        assert_eq!(sizes, vec![50.0, 72.5, 30.0, 217.5]);
+    }
+
+    #[test]
+    fn fill_tracks_shrink_when_unbounded() {
+        let tracks = [Length::Fill, Length::Fixed(20.0), Length::Shrink];
+
+        let sizes = resolve_tracks(
+            &tracks,
+            f32::INFINITY,
+            10.0,
+            vec![(0..1, 15.0), (1..2, 40.0), (2..3, 5.0)],
+        );
+
+        assert_eq!(sizes, vec![15.0, 20.0, 5.0]);
+    }
+
+    #[test]
+    fn spans_grow_shrink_tracks_evenly() {
+        let tracks = [Length::Fixed(40.0), Length::Shrink, Length::Shrink];
+
+        let sizes = resolve_tracks(
+            &tracks,
+            f32::INFINITY,
+            10.0,
+            vec![(0..3, 120.0), (1..2, 20.0), (0..1, 10.0)],
+        );
+
+        assert_eq!(sizes, vec![40.0, 40.0, 20.0]);
+
+        let offsets = offsets(&sizes, 5.0, 10.0);
+
+        assert_eq!(offsets, vec![5.0, 55.0, 105.0, 125.0]);
+        assert_eq!(span(&offsets, 10.0, 0..1), 40.0);
+        assert_eq!(span(&offsets, 10.0, 1..3), 70.0);
+        assert_eq!(span(&offsets, 10.0, 0..3), 120.0);
+    }
+
+    #[test]
+    fn empty_tracks_have_no_size() {
+        assert_eq!(offsets(&[], 5.0, 10.0), vec![5.0]);
+    }
+}
@@ -1,26 +1,40 @@
 //! Helper functions to create pure widgets.
 use crate::button::{self, Button};
 use crate::checkbox::{self, Checkbox};
+use crate::color_picker::{self, ColorPicker};
 use crate::combo_box::{self, ComboBox};
 use crate::container::{self, Container};
+use crate::context_menu::ContextMenu;
 use crate::core;
 use crate::core::widget::operation;
 use crate::core::{Element, Length, Pixels};
+use crate::date_picker::{self, DatePicker};
+use crate::drag_and_drop::{self, Draggable, DropTarget};
+use crate::grid::{self, Grid};
 use crate::keyed;
+use crate::menu_bar::{self, MenuBar};
+use crate::modal::{self, Modal};
+use crate::number_input::{self, NumberInput};
 use crate::pick_list::{self, PickList};
 use crate::progress_bar::{self, ProgressBar};
+use crate::progress_indicator;
 use crate::radio::{self, Radio};
+use crate::range_slider::{self, RangeSlider};
 use crate::rule::{self, Rule};
 use crate::runtime::Command;
 use crate::scrollable::{self, Scrollable};
 use crate::slider::{self, Slider};
+use crate::table::{self, Table};
+use crate::tabs::{self, Tabs};
 use crate::text::Text;
 use crate::text_editor::{self, TextEditor};
 use crate::text_input::{self, TextInput};
+use crate::time_picker::{self, TimePicker};
 use crate::toggler::{self, Toggler};
 use crate::tooltip::{self, Tooltip};
+use crate::tree_view::{self, TreeView};
 use crate::vertical_slider::{self, VerticalSlider};
-use crate::{Column, MouseArea, Row, Space, Themer};
+use crate::{Column, List, MouseArea, Row, Space, Stack, Themer, Wrap};
 
 use std::borrow::Borrow;
 use std::ops::RangeInclusive;
@@ -51,6 +65,32 @@ macro_rules! row {
     );
 }
 
+/// Creates a [`Stack`] with the given children.
+///
+/// [`Stack`]: crate::Stack
+#[macro_export]
+macro_rules! stack {
+    () => (
+        $crate::Stack::new()
+    );
+    ($($x:expr),+ $(,)?) => (
+        $crate::Stack::with_children([$($crate::core::Element::from($x)),+])
+    );
+}
+
+/// Creates a [`Wrap`] with the given children.
+///
+/// [`Wrap`]: crate::Wrap
+#[macro_export]
+macro_rules! wrap {
+    () => (
+        $crate::Wrap::new()
+    );
+    ($($x:expr),+ $(,)?) => (
+        $crate::Wrap::with_children([$($crate::core::Element::from($x)),+])
+    );
+}
+
 /// Creates a new [`Container`] with the provided content.
 ///
 /// [`Container`]: crate::Container
@@ -85,6 +125,20 @@ where
     keyed::Column::with_children(children)
 }
 
+/// Creates a new [`List`] with the given amount of items and a closure
+/// that produces the item for a given index.
+///
+/// [`List`]: crate::List
+pub fn list<'a, Message, Theme, Renderer>(
+    count: usize,
+    view: impl Fn(usize) -> Element<'a, Message, Theme, Renderer> + 'a,
+) -> List<'a, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    List::new(count, view)
+}
+
 /// Creates a new [`Row`] with the given children.
 ///
 /// [`Row`]: crate::Row
@@ -97,6 +151,42 @@ where
     Row::with_children(children)
 }
 
+/// Creates a new [`Grid`] with the given cells.
+///
+/// [`Grid`]: crate::Grid
+pub fn grid<'a, Message, Theme, Renderer>(
+    cells: impl IntoIterator<Item = grid::Cell<'a, Message, Theme, Renderer>>,
+) -> Grid<'a, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    Grid::with_cells(cells)
+}
+
+/// Creates a new [`Stack`] with the given children.
+///
+/// [`Stack`]: crate::Stack
+pub fn stack<'a, Message, Theme, Renderer>(
+    children: impl IntoIterator<Item = Element<'a, Message, Theme, Renderer>>,
+) -> Stack<'a, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    Stack::with_children(children)
+}
+
+/// Creates a new [`Wrap`] with the given children.
+///
+/// [`Wrap`]: crate::Wrap
+pub fn wrap<'a, Message, Theme, Renderer>(
+    children: impl IntoIterator<Item = Element<'a, Message, Theme, Renderer>>,
+) -> Wrap<'a, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    Wrap::with_children(children)
+}
+
 /// Creates a new [`Scrollable`] with the provided content.
 ///
 /// [`Scrollable`]: crate::Scrollable
@@ -110,6 +200,54 @@ where
     Scrollable::new(content)
 }
 
+/// Creates a new [`Sticky`] section with the given header and content, to be
+/// placed inside the content of a [`Scrollable`].
+///
+/// [`Sticky`]: crate::scrollable::Sticky
+/// [`Scrollable`]: crate::Scrollable
+pub fn sticky<'a, Message, Theme, Renderer>(
+    header: impl Into<Element<'a, Message, Theme, Renderer>>,
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> scrollable::Sticky<'a, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    scrollable::Sticky::new(header, content)
+}
+
+/// Creates a new [`Table`] with the given columns, amount of rows, and a
+/// closure that produces the cell for a given row and column index.
+///
+/// [`Table`]: crate::Table
+pub fn table<'a, Message, Theme, Renderer>(
+    columns: impl IntoIterator<Item = table::Column<'a, Message, Theme, Renderer>>,
+    rows: usize,
+    cell: impl Fn(usize, usize) -> Element<'a, Message, Theme, Renderer> + 'a,
+) -> Table<'a, Message, Theme, Renderer>
+where
+    Theme: table::DefaultStyle,
+    Renderer: core::text::Renderer,
+{
+    Table::new(columns, rows, cell)
+}
+
+/// Creates a new [`Tabs`] widget with the given tabs, the key of the active
+/// tab, and its contents.
+///
+/// [`Tabs`]: crate::Tabs
+pub fn tabs<'a, Key, Message, Theme, Renderer>(
+    tabs: impl IntoIterator<Item = tabs::Tab<'a, Key, Message, Theme, Renderer>>,
+    active: Key,
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Tabs<'a, Key, Message, Theme, Renderer>
+where
+    Key: Clone + PartialEq,
+    Theme: tabs::DefaultStyle,
+    Renderer: core::text::Renderer,
+{
+    Tabs::new(tabs, active, content)
+}
+
 /// Creates a new [`Button`] with the provided content.
 ///
 /// [`Button`]: crate::Button
@@ -140,6 +278,22 @@ where
     Tooltip::new(content, tooltip, position)
 }
 
+/// Creates a new [`TreeView`] with the given root nodes.
+///
+/// [`TreeView`]: crate::TreeView
+pub fn tree_view<'a, Key, Message, Theme, Renderer>(
+    roots: impl IntoIterator<
+        Item = tree_view::Node<'a, Key, Message, Theme, Renderer>,
+    >,
+) -> TreeView<'a, Key, Message, Theme, Renderer>
+where
+    Key: Clone + PartialEq,
+    Theme: tree_view::DefaultStyle,
+    Renderer: core::text::Renderer,
+{
+    TreeView::new(roots)
+}
+
 /// Creates a new [`Text`] widget with the provided content.
 ///
 /// [`Text`]: core::widget::Text
@@ -214,6 +368,22 @@ where
     TextInput::new(placeholder, value)
 }
 
+/// Creates a new [`NumberInput`] with the given value and the message to
+/// produce when it changes.
+///
+/// [`NumberInput`]: crate::NumberInput
+pub fn number_input<'a, T, Message, Theme, Renderer>(
+    value: T,
+    on_change: impl Fn(T) -> Message + 'a,
+) -> NumberInput<'a, T, Message, Theme, Renderer>
+where
+    T: Copy + From<u8> + PartialOrd + std::fmt::Display + std::str::FromStr,
+    Theme: number_input::DefaultStyle,
+    Renderer: core::text::Renderer,
+{
+    NumberInput::new(value, on_change)
+}
+
 /// Creates a new [`TextEditor`].
 ///
 /// [`TextEditor`]: crate::TextEditor
@@ -260,6 +430,22 @@ where
     VerticalSlider::new(range, value, on_change)
 }
 
+/// Creates a new [`RangeSlider`].
+///
+/// [`RangeSlider`]: crate::RangeSlider
+pub fn range_slider<'a, T, Message, Theme>(
+    range: std::ops::RangeInclusive<T>,
+    values: (T, T),
+    on_change: impl Fn((T, T)) -> Message + 'a,
+) -> RangeSlider<'a, T, Message, Theme>
+where
+    T: Copy + From<u8> + std::cmp::PartialOrd,
+    Message: Clone,
+    Theme: range_slider::DefaultStyle,
+{
+    RangeSlider::new(range, values, on_change)
+}
+
 /// Creates a new [`PickList`].
 ///
 /// [`PickList`]: crate::PickList
@@ -349,6 +535,27 @@ where
     ProgressBar::new(range, value)
 }
 
+/// Creates a new [`Linear`] progress indicator.
+///
+/// [`Linear`]: crate::progress_indicator::Linear
+pub fn linear_progress<Theme>() -> progress_indicator::Linear<Theme>
+where
+    Theme: progress_indicator::DefaultStyle,
+{
+    progress_indicator::Linear::new()
+}
+
+/// Creates a new [`Circular`] progress indicator.
+///
+/// [`Circular`]: crate::progress_indicator::Circular
+#[cfg(feature = "canvas")]
+pub fn circular_progress<Theme>() -> progress_indicator::Circular<Theme>
+where
+    Theme: progress_indicator::DefaultStyle,
+{
+    progress_indicator::Circular::new()
+}
+
 /// Creates a new [`Image`].
 ///
 /// [`Image`]: crate::Image
@@ -422,6 +629,28 @@ where
     Command::widget(operation::focusable::focus_next())
 }
 
+/// Focuses the previous focusable widget and scrolls it into view with the
+/// given [`Anchor`](scrollable::Anchor).
+pub fn focus_previous_into_view<Message>(
+    anchor: scrollable::Anchor,
+) -> Command<Message>
+where
+    Message: 'static,
+{
+    Command::widget(operation::focusable::focus_previous_into_view(anchor))
+}
+
+/// Focuses the next focusable widget and scrolls it into view with the given
+/// [`Anchor`](scrollable::Anchor).
+pub fn focus_next_into_view<Message>(
+    anchor: scrollable::Anchor,
+) -> Command<Message>
+where
+    Message: 'static,
+{
+    Command::widget(operation::focusable::focus_next_into_view(anchor))
+}
+
 /// A container intercepting mouse events.
 pub fn mouse_area<'a, Message, Theme, Renderer>(
     widget: impl Into<Element<'a, Message, Theme, Renderer>>,
@@ -432,6 +661,123 @@ where
     MouseArea::new(widget)
 }
 
+/// Creates a new [`DatePicker`] with the selected [`Date`], if any, and the
+/// message to produce when a date is picked.
+///
+/// [`DatePicker`]: crate::DatePicker
+/// [`Date`]: crate::date_picker::Date
+pub fn date_picker<'a, Message, Theme, Renderer>(
+    selected: Option<date_picker::Date>,
+    on_select: impl Fn(date_picker::Date) -> Message + 'a,
+) -> DatePicker<'a, Message, Theme, Renderer>
+where
+    Theme: date_picker::DefaultStyle,
+    Renderer: core::text::Renderer,
+{
+    DatePicker::new(selected, on_select)
+}
+
+/// Creates a new [`TimePicker`] with the selected [`Time`], if any, and the
+/// message to produce when a time is picked.
+///
+/// [`TimePicker`]: crate::TimePicker
+/// [`Time`]: crate::time_picker::Time
+pub fn time_picker<'a, Message, Theme, Renderer>(
+    selected: Option<time_picker::Time>,
+    on_select: impl Fn(time_picker::Time) -> Message + 'a,
+) -> TimePicker<'a, Message, Theme, Renderer>
+where
+    Theme: time_picker::DefaultStyle,
+    Renderer: core::text::Renderer,
+{
+    TimePicker::new(selected, on_select)
+}
+
+/// Creates a new [`ColorPicker`] with the given [`Color`] and the message to
+/// produce when it changes.
+///
+/// [`ColorPicker`]: crate::ColorPicker
+/// [`Color`]: crate::core::Color
+pub fn color_picker<'a, Message, Theme, Renderer>(
+    color: core::Color,
+    on_change: impl Fn(core::Color) -> Message + 'a,
+) -> ColorPicker<'a, Message, Theme, Renderer>
+where
+    Theme: color_picker::DefaultStyle + text_input::DefaultStyle,
+    Renderer: core::text::Renderer,
+{
+    ColorPicker::new(color, on_change)
+}
+
+/// Creates a new [`Draggable`] with the given content and payload.
+///
+/// [`Draggable`]: crate::Draggable
+pub fn draggable<'a, P, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    payload: P,
+) -> Draggable<'a, P, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    Draggable::new(content, payload)
+}
+
+/// Creates a new [`DropTarget`] with the given content and the payload that
+/// is currently being dragged, if any.
+///
+/// [`DropTarget`]: crate::DropTarget
+pub fn drop_target<'a, P, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    dragging: Option<P>,
+) -> DropTarget<'a, P, Message, Theme, Renderer>
+where
+    Theme: drag_and_drop::DefaultStyle,
+    Renderer: core::Renderer,
+{
+    DropTarget::new(content, dragging)
+}
+
+/// Creates a new [`MenuBar`] with the given menus.
+///
+/// [`MenuBar`]: crate::MenuBar
+pub fn menu_bar<Message, Theme, Renderer>(
+    menus: impl IntoIterator<Item = menu_bar::Menu<Message>>,
+) -> MenuBar<Message, Theme, Renderer>
+where
+    Theme: menu_bar::DefaultStyle,
+    Renderer: core::text::Renderer,
+{
+    MenuBar::new(menus)
+}
+
+/// Creates a new [`Modal`] with the given base content.
+///
+/// [`Modal`]: crate::Modal
+pub fn modal<'a, Message, Theme, Renderer>(
+    base: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Modal<'a, Message, Theme, Renderer>
+where
+    Theme: modal::DefaultStyle,
+    Renderer: core::Renderer,
+{
+    Modal::new(base)
+}
+
+/// Creates a new [`ContextMenu`] that shows the given items when the
+/// provided content is right-clicked.
+///
+/// [`ContextMenu`]: crate::ContextMenu
+pub fn context_menu<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    items: impl IntoIterator<Item = menu_bar::Item<Message>>,
+) -> ContextMenu<'a, Message, Theme, Renderer>
+where
+    Theme: menu_bar::DefaultStyle,
+    Renderer: core::text::Renderer,
+{
+    ContextMenu::new(content, items)
+}
+
 /// A widget that applies any `Theme` to its contents.
 pub fn themer<'a, Message, OldTheme, NewTheme, Renderer>(
     new_theme: NewTheme,
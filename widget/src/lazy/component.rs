@@ -413,6 +413,10 @@ where
             ) {
                 self.operation.custom(state, id);
             }
+
+            fn bounds(&mut self, id: Option<&widget::Id>, bounds: Rectangle) {
+                self.operation.bounds(id, bounds);
+            }
         }
 
         let tree = tree.state.downcast_mut::<Rc<RefCell<Option<Tree>>>>();
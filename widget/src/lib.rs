@@ -19,26 +19,44 @@ mod column;
 mod mouse_area;
 mod row;
 mod space;
+mod stack;
 mod themer;
+mod wrap;
 
 pub mod button;
 pub mod checkbox;
+pub mod color_picker;
 pub mod combo_box;
 pub mod container;
+pub mod context_menu;
+pub mod date_picker;
+pub mod drag_and_drop;
+pub mod grid;
 pub mod keyed;
+pub mod list;
+pub mod menu_bar;
+pub mod modal;
+pub mod number_input;
 pub mod overlay;
 pub mod pane_grid;
 pub mod pick_list;
 pub mod progress_bar;
+pub mod progress_indicator;
 pub mod radio;
+pub mod range_slider;
 pub mod rule;
 pub mod scrollable;
 pub mod slider;
+pub mod table;
+pub mod tabs;
 pub mod text;
 pub mod text_editor;
 pub mod text_input;
+pub mod time_picker;
+pub mod toast;
 pub mod toggler;
 pub mod tooltip;
+pub mod tree_view;
 pub mod vertical_slider;
 
 mod helpers;
@@ -59,14 +77,32 @@ pub use button::Button;
 #[doc(no_inline)]
 pub use checkbox::Checkbox;
 #[doc(no_inline)]
+pub use color_picker::ColorPicker;
+#[doc(no_inline)]
 pub use column::Column;
 #[doc(no_inline)]
 pub use combo_box::ComboBox;
 #[doc(no_inline)]
 pub use container::Container;
 #[doc(no_inline)]
+pub use context_menu::ContextMenu;
+#[doc(no_inline)]
+pub use date_picker::DatePicker;
+#[doc(no_inline)]
+pub use drag_and_drop::{Draggable, DropTarget};
+#[doc(no_inline)]
+pub use grid::Grid;
+#[doc(no_inline)]
+pub use list::List;
+#[doc(no_inline)]
+pub use menu_bar::MenuBar;
+#[doc(no_inline)]
+pub use modal::Modal;
+#[doc(no_inline)]
 pub use mouse_area::MouseArea;
 #[doc(no_inline)]
+pub use number_input::NumberInput;
+#[doc(no_inline)]
 pub use pane_grid::PaneGrid;
 #[doc(no_inline)]
 pub use pick_list::PickList;
@@ -75,6 +111,8 @@ pub use progress_bar::ProgressBar;
 #[doc(no_inline)]
 pub use radio::Radio;
 #[doc(no_inline)]
+pub use range_slider::RangeSlider;
+#[doc(no_inline)]
 pub use row::Row;
 #[doc(no_inline)]
 pub use rule::Rule;
@@ -85,6 +123,12 @@ pub use slider::Slider;
 #[doc(no_inline)]
 pub use space::Space;
 #[doc(no_inline)]
+pub use stack::Stack;
+#[doc(no_inline)]
+pub use table::Table;
+#[doc(no_inline)]
+pub use tabs::Tabs;
+#[doc(no_inline)]
 pub use text::Text;
 #[doc(no_inline)]
 pub use text_editor::TextEditor;
@@ -93,11 +137,17 @@ pub use text_input::TextInput;
 #[doc(no_inline)]
 pub use themer::Themer;
 #[doc(no_inline)]
+pub use time_picker::TimePicker;
+#[doc(no_inline)]
 pub use toggler::Toggler;
 #[doc(no_inline)]
 pub use tooltip::Tooltip;
 #[doc(no_inline)]
+pub use tree_view::TreeView;
+#[doc(no_inline)]
 pub use vertical_slider::VerticalSlider;
+#[doc(no_inline)]
+pub use wrap::Wrap;
 
 #[cfg(feature = "wgpu")]
 pub mod shader;
@@ -0,0 +1,767 @@
+//! Display a huge amount of items by only building the visible ones.
+use crate::core::event::{self, Event};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::widget::operation::{self, Operation};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::Id;
+use crate::core::{
+    Clipboard, Element, Layout, Length, Pixels, Point, Rectangle, Shell, Size,
+    Vector, Widget,
+};
+use crate::runtime::Command;
+use crate::scrollable::{self, AbsoluteOffset};
+
+use std::cell::{RefCell, RefMut};
+use std::collections::HashMap;
+use std::ops::Range;
+
+/// A vertical list of items that are built lazily, only when visible.
+///
+/// A [`List`] is meant to be placed inside a vertical [`Scrollable`]. It
+/// uses the viewport of the enclosing [`Scrollable`] to decide which items
+/// need to be built, laid out, and diffed; while the rest of the items only
+/// contribute to its total height.
+///
+/// The internal state of any item is dropped when the item leaves the visible
+/// area (plus the overscan).
+///
+/// [`Scrollable`]: crate::Scrollable
+#[allow(missing_debug_implementations)]
+pub struct List<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer> {
+    count: usize,
+    view: Box<dyn Fn(usize) -> Element<'a, Message, Theme, Renderer> + 'a>,
+    width: Length,
+    item_height: ItemHeight,
+    spacing: f32,
+    overscan: usize,
+    visible: RefCell<Visible<'a, Message, Theme, Renderer>>,
+}
+
+impl<'a, Message, Theme, Renderer> List<'a, Message, Theme, Renderer>
+where
+    Renderer: crate::core::Renderer,
+{
+    /// The default estimated height of an item of a [`List`].
+    pub const DEFAULT_ESTIMATED_HEIGHT: f32 = 30.0;
+
+    /// The default amount of items built outside of the visible area of a
+    /// [`List`].
+    pub const DEFAULT_OVERSCAN: usize = 3;
+
+    /// Creates a new [`List`] with the given amount of items and a closure
+    /// that produces the item for a given index.
+    ///
+    /// By default, items are measured as they become visible. You can
+    /// use [`List::item_height`] if all of the items share the same height.
+    pub fn new(
+        count: usize,
+        view: impl Fn(usize) -> Element<'a, Message, Theme, Renderer> + 'a,
+    ) -> Self {
+        Self {
+            count,
+            view: Box::new(view),
+            width: Length::Fill,
+            item_height: ItemHeight::Measured(Self::DEFAULT_ESTIMATED_HEIGHT),
+            spacing: 0.0,
+            overscan: Self::DEFAULT_OVERSCAN,
+            visible: RefCell::new(Visible {
+                range: 0..0,
+                width: 0.0,
+                items: Vec::new(),
+            }),
+        }
+    }
+
+    /// Sets the width of the [`List`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets a fixed height for all of the items of the [`List`].
+    pub fn item_height(mut self, height: impl Into<Pixels>) -> Self {
+        self.item_height = ItemHeight::Fixed(height.into().0);
+        self
+    }
+
+    /// Sets the estimated height of the items of the [`List`], which will be
+    /// measured as they become visible.
+    pub fn estimated_item_height(mut self, height: impl Into<Pixels>) -> Self {
+        self.item_height = ItemHeight::Measured(height.into().0);
+        self
+    }
+
+    /// Sets the vertical spacing _between_ items.
+    pub fn spacing(mut self, amount: impl Into<Pixels>) -> Self {
+        self.spacing = amount.into().0;
+        self
+    }
+
+    /// Sets the amount of items that will be built before and after the
+    /// visible area of the [`List`].
+    pub fn overscan(mut self, overscan: usize) -> Self {
+        self.overscan = overscan;
+        self
+    }
+
+    /// Builds and lays out the items that are visible in the last viewport
+    /// of the [`List`].
+    ///
+    /// Measuring new items may change the height of the [`List`], so this is
+    /// only called where the layout can be invalidated afterwards: in
+    /// [`Widget::layout`] and [`Widget::on_event`].
+    fn resolve(
+        &self,
+        internal: &mut Internal,
+        renderer: &Renderer,
+        width: f32,
+        relayout: bool,
+    ) -> RefMut<'_, Visible<'a, Message, Theme, Renderer>> {
+        let mut visible = self.visible.borrow_mut();
+
+        let range = if self.count == 0 {
+            0..0
+        } else {
+            let (top, height) = internal.viewport;
+
+            let start = internal.index_at(top).saturating_sub(self.overscan);
+            let end = (internal.index_at(top + height) + 1 + self.overscan)
+                .min(self.count);
+
+            start..end
+        };
+
+        if relayout || visible.range != range || visible.width != width {
+            let limits = match self.item_height {
+                ItemHeight::Fixed(height) => layout::Limits::new(
+                    Size::new(width, height),
+                    Size::new(width, height),
+                ),
+                ItemHeight::Measured(_) => layout::Limits::new(
+                    Size::new(width, 0.0),
+                    Size::new(width, f32::INFINITY),
+                ),
+            };
+
+            let is_stale = relayout || visible.width != width;
+
+            let mut previous: HashMap<_, _> = visible
+                .items
+                .drain(..)
+                .map(|item| (item.index, item))
+                .collect();
+
+            let items = range
+                .clone()
+                .map(|index| {
+                    let mut item = match previous.remove(&index) {
+                        Some(item) if !is_stale => return item,
+                        Some(item) => item,
+                        None => {
+                            let element = (self.view)(index);
+
+                            let _ = internal
+                                .trees
+                                .entry(index)
+                                .and_modify(|tree| tree.diff(&element))
+                                .or_insert_with(|| Tree::new(&element));
+
+                            Item {
+                                index,
+                                element,
+                                node: layout::Node::default(),
+                            }
+                        }
+                    };
+
+                    let tree = internal
+                        .trees
+                        .get_mut(&index)
+                        .expect("Visible item must have a tree");
+
+                    item.node = item
+                        .element
+                        .as_widget()
+                        .layout(tree, renderer, &limits);
+
+                    if let ItemHeight::Measured(_) = self.item_height {
+                        internal.measure(index, item.node.size().height);
+                    }
+
+                    item
+                })
+                .collect();
+
+            internal.trees.retain(|index, _| range.contains(index));
+
+            visible.items = items;
+            visible.range = range;
+            visible.width = width;
+        }
+
+        for item in &mut visible.items {
+            item.node.move_to_mut((0.0, internal.offset(item.index)));
+        }
+
+        visible
+    }
+}
+
+/// The height strategy of the items of a [`List`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ItemHeight {
+    /// All of the items have the same fixed height.
+    Fixed(f32),
+    /// Items are measured as they become visible, using the given estimated
+    /// height until then.
+    Measured(f32),
+}
+
+struct Visible<'a, Message, Theme, Renderer> {
+    range: Range<usize>,
+    width: f32,
+    items: Vec<Item<'a, Message, Theme, Renderer>>,
+}
+
+struct Item<'a, Message, Theme, Renderer> {
+    index: usize,
+    element: Element<'a, Message, Theme, Renderer>,
+    node: layout::Node,
+}
+
+struct State {
+    internal: RefCell<Internal>,
+}
+
+struct Internal {
+    trees: HashMap<usize, Tree>,
+    heights: Vec<Option<f32>>,
+    offsets: Vec<f32>,
+    item_height: ItemHeight,
+    spacing: f32,
+    viewport: (f32, f32),
+    bounds: Rectangle,
+    is_dirty: bool,
+    needs_layout: bool,
+}
+
+impl Internal {
+    fn sync(&mut self, count: usize, item_height: ItemHeight, spacing: f32) {
+        if self.heights.len() != count
+            || self.item_height != item_height
+            || self.spacing != spacing
+        {
+            self.heights.resize(count, None);
+            self.trees.retain(|index, _| *index < count);
+            self.item_height = item_height;
+            self.spacing = spacing;
+            self.is_dirty = true;
+        }
+    }
+
+    fn measure(&mut self, index: usize, height: f32) {
+        if self.heights[index] != Some(height) {
+            self.heights[index] = Some(height);
+            self.is_dirty = true;
+            self.needs_layout = true;
+        }
+    }
+
+    fn update_offsets(&mut self) {
+        let ItemHeight::Measured(estimate) = self.item_height else {
+            return;
+        };
+
+        if !self.is_dirty {
+            return;
+        }
+
+        let mut offset = 0.0;
+
+        self.offsets.clear();
+        self.offsets.push(offset);
+
+        for height in &self.heights {
+            offset += height.unwrap_or(estimate) + self.spacing;
+            self.offsets.push(offset);
+        }
+
+        self.is_dirty = false;
+    }
+
+    fn offset(&mut self, index: usize) -> f32 {
+        match self.item_height {
+            ItemHeight::Fixed(height) => index as f32 * (height + self.spacing),
+            ItemHeight::Measured(_) => {
+                self.update_offsets();
+                self.offsets[index]
+            }
+        }
+    }
+
+    fn total_height(&mut self) -> f32 {
+        let count = self.heights.len();
+
+        if count == 0 {
+            0.0
+        } else {
+            self.offset(count) - self.spacing
+        }
+    }
+
+    fn index_at(&mut self, y: f32) -> usize {
+        let last = self.heights.len().saturating_sub(1);
+
+        let index = match self.item_height {
+            ItemHeight::Fixed(height) => {
+                (y / (height + self.spacing)).max(0.0) as usize
+            }
+            ItemHeight::Measured(_) => {
+                self.update_offsets();
+
+                self.offsets
+                    .partition_point(|offset| *offset <= y)
+                    .saturating_sub(1)
+            }
+        };
+
+        index.min(last)
+    }
+
+    fn set_viewport(&mut self, bounds: Rectangle, viewport: &Rectangle) {
+        self.viewport = (viewport.y - bounds.y, viewport.height);
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for List<'a, Message, Theme, Renderer>
+where
+    Renderer: crate::core::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            internal: RefCell::new(Internal {
+                trees: HashMap::new(),
+                heights: Vec::new(),
+                offsets: Vec::new(),
+                item_height: self.item_height,
+                spacing: self.spacing,
+                viewport: (0.0, 0.0),
+                bounds: Rectangle::default(),
+                is_dirty: true,
+                needs_layout: false,
+            }),
+        })
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let internal = tree.state.downcast_mut::<State>().internal.get_mut();
+
+        internal.sync(self.count, self.item_height, self.spacing);
+
+        let width =
+            limits.resolve(self.width, Length::Shrink, Size::ZERO).width;
+
+        let _ = self.resolve(internal, renderer, width, true);
+
+        internal.needs_layout = false;
+
+        layout::Node::new(limits.resolve(
+            self.width,
+            Length::Shrink,
+            Size::new(width, internal.total_height()),
+        ))
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+
+        state.internal.get_mut().bounds = bounds;
+        operation.custom(state, None);
+
+        let internal = state.internal.get_mut();
+        let visible = self.visible.borrow();
+
+        operation.container(None, bounds, &mut |operation| {
+            for item in &visible.items {
+                if let Some(tree) = internal.trees.get_mut(&item.index) {
+                    item.element.as_widget().operate(
+                        tree,
+                        Layout::with_offset(
+                            bounds.position() - Point::ORIGIN,
+                            &item.node,
+                        ),
+                        renderer,
+                        operation,
+                    );
+                }
+            }
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let internal = tree.state.downcast_mut::<State>().internal.get_mut();
+        let bounds = layout.bounds();
+
+        // Any event, including `RedrawRequested` after scrolling, may bring
+        // new items into view; which must be measured before being drawn
+        internal.set_viewport(bounds, viewport);
+
+        let _ = self.resolve(internal, renderer, bounds.width, false);
+        let visible = self.visible.get_mut();
+
+        let status = visible
+            .items
+            .iter_mut()
+            .filter_map(|item| {
+                let tree = internal.trees.get_mut(&item.index)?;
+
+                Some(item.element.as_widget_mut().on_event(
+                    tree,
+                    event.clone(),
+                    Layout::with_offset(
+                        bounds.position() - Point::ORIGIN,
+                        &item.node,
+                    ),
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                ))
+            })
+            .fold(event::Status::Ignored, event::Status::merge);
+
+        if internal.needs_layout {
+            internal.needs_layout = false;
+            shell.invalidate_layout();
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let internal = tree.state.downcast_ref::<State>().internal.borrow();
+        let bounds = layout.bounds();
+        let visible = self.visible.borrow();
+
+        visible
+            .items
+            .iter()
+            .filter_map(|item| {
+                let tree = internal.trees.get(&item.index)?;
+
+                Some(item.element.as_widget().mouse_interaction(
+                    tree,
+                    Layout::with_offset(
+                        bounds.position() - Point::ORIGIN,
+                        &item.node,
+                    ),
+                    cursor,
+                    viewport,
+                    renderer,
+                ))
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let internal = tree.state.downcast_ref::<State>().internal.borrow();
+        let bounds = layout.bounds();
+        let visible = self.visible.borrow();
+
+        for item in &visible.items {
+            let layout = Layout::with_offset(
+                bounds.position() - Point::ORIGIN,
+                &item.node,
+            );
+
+            if !layout.bounds().intersects(viewport) {
+                continue;
+            }
+
+            if let Some(tree) = internal.trees.get(&item.index) {
+                item.element.as_widget().draw(
+                    tree, renderer, theme, style, layout, cursor, viewport,
+                );
+            }
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let internal = tree.state.downcast_mut::<State>().internal.get_mut();
+        let bounds = layout.bounds();
+
+        let mut trees: Vec<_> = internal.trees.iter_mut().collect();
+        trees.sort_unstable_by_key(|(index, _)| **index);
+
+        let children = self
+            .visible
+            .get_mut()
+            .items
+            .iter_mut()
+            .zip(trees)
+            .filter_map(|(item, (_, tree))| {
+                let Item { element, node, .. } = item;
+
+                element.as_widget_mut().overlay(
+                    tree,
+                    Layout::with_offset(
+                        bounds.position() - Point::ORIGIN,
+                        node,
+                    ),
+                    renderer,
+                    translation,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        (!children.is_empty())
+            .then(|| overlay::Group::with_children(children).overlay())
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<List<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: crate::core::Renderer + 'a,
+{
+    fn from(list: List<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(list)
+    }
+}
+
+/// Produces a [`Command`] that scrolls the [`Scrollable`] with the given
+/// [`scrollable::Id`] until the item with the given index of its first
+/// [`List`] is at the top of its viewport.
+///
+/// [`Scrollable`]: crate::Scrollable
+pub fn scroll_to<Message: 'static>(
+    scrollable: scrollable::Id,
+    index: usize,
+) -> Command<Message> {
+    struct ScrollTo {
+        target: Id,
+        index: usize,
+        scrollable: Option<(Rectangle, Vector)>,
+        is_inside: bool,
+        offset: Option<AbsoluteOffset>,
+    }
+
+    impl<T> Operation<T> for ScrollTo
+    where
+        T: 'static,
+    {
+        fn container(
+            &mut self,
+            id: Option<&Id>,
+            _bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+        ) {
+            if self.offset.is_some() {
+                return;
+            }
+
+            if !self.is_inside
+                && self.scrollable.is_some()
+                && id == Some(&self.target)
+            {
+                self.is_inside = true;
+                operate_on_children(self);
+                self.is_inside = false;
+            } else {
+                operate_on_children(self);
+            }
+        }
+
+        fn scrollable(
+            &mut self,
+            _state: &mut dyn operation::Scrollable,
+            id: Option<&Id>,
+            bounds: Rectangle,
+            translation: Vector,
+        ) {
+            if self.scrollable.is_none() && id == Some(&self.target) {
+                self.scrollable = Some((bounds, translation));
+            }
+        }
+
+        fn custom(&mut self, state: &mut dyn std::any::Any, _id: Option<&Id>) {
+            let (Some((bounds, translation)), true, None) =
+                (self.scrollable, self.is_inside, self.offset)
+            else {
+                return;
+            };
+
+            let Some(state) = state.downcast_mut::<State>() else {
+                return;
+            };
+
+            let internal = state.internal.get_mut();
+
+            if internal.heights.is_empty() {
+                return;
+            }
+
+            let index = self.index.min(internal.heights.len() - 1);
+
+            self.offset = Some(AbsoluteOffset {
+                x: translation.x,
+                y: internal.bounds.y - bounds.y + internal.offset(index),
+            });
+        }
+
+        fn finish(&self) -> operation::Outcome<T> {
+            match self.offset {
+                Some(offset) => operation::Outcome::Chain(Box::new(
+                    operation::scrollable::scroll_to(
+                        self.target.clone(),
+                        offset,
+                    ),
+                )),
+                None => operation::Outcome::None,
+            }
+        }
+    }
+
+    Command::widget(ScrollTo {
+        target: scrollable.into(),
+        index,
+        scrollable: None,
+        is_inside: false,
+        offset: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn internal(
+        item_height: ItemHeight,
+        spacing: f32,
+        count: usize,
+    ) -> Internal {
+        let mut internal = Internal {
+            trees: HashMap::new(),
+            heights: Vec::new(),
+            offsets: Vec::new(),
+            item_height,
+            spacing,
+            viewport: (0.0, 0.0),
+            bounds: Rectangle::default(),
+            is_dirty: true,
+            needs_layout: false,
+        };
+
+        internal.sync(count, item_height, spacing);
+        internal
+    }
+
+    #[test]
+    fn fixed_heights_are_spaced() {
+        let mut internal = internal(ItemHeight::Fixed(20.0), 5.0, 10);
+
+        assert_eq!(internal.offset(0), 0.0);
+        assert_eq!(internal.offset(3), 75.0);
+        assert_eq!(internal.total_height(), 245.0);
+
+        assert_eq!(internal.index_at(-10.0), 0);
+        assert_eq!(internal.index_at(24.0), 0);
+        assert_eq!(internal.index_at(25.0), 1);
+        assert_eq!(internal.index_at(10_000.0), 9);
+    }
+
+    #[test]
+    fn measured_heights_replace_estimates() {
+        let mut internal = internal(ItemHeight::Measured(10.0), 2.0, 4);
+
+        assert_eq!(internal.offset(2), 24.0);
+        assert_eq!(internal.total_height(), 46.0);
+
+        internal.measure(1, 30.0);
+        assert!(internal.needs_layout);
+
+        assert_eq!(internal.offset(1), 12.0);
+        assert_eq!(internal.offset(2), 44.0);
+        assert_eq!(internal.total_height(), 66.0);
+
+        assert_eq!(internal.index_at(11.9), 0);
+        assert_eq!(internal.index_at(12.0), 1);
+        assert_eq!(internal.index_at(43.9), 1);
+        assert_eq!(internal.index_at(44.0), 2);
+        assert_eq!(internal.index_at(10_000.0), 3);
+    }
+
+    #[test]
+    fn empty_lists_have_no_height() {
+        let mut fixed = internal(ItemHeight::Fixed(20.0), 5.0, 0);
+        let mut measured = internal(ItemHeight::Measured(20.0), 5.0, 0);
+
+        assert_eq!(fixed.total_height(), 0.0);
+        assert_eq!(measured.total_height(), 0.0);
+        assert_eq!(fixed.index_at(50.0), 0);
+        assert_eq!(measured.index_at(50.0), 0);
+    }
+}
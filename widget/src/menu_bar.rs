@@ -0,0 +1,673 @@
+//! Show application menus in a bar, with cascading submenus.
+pub(crate) mod cascade;
+
+use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text;
+use crate::core::touch;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::{
+    Background, Border, Clipboard, Color, Element, Layout, Length, Padding,
+    Pixels, Point, Rectangle, Shell, Size, Theme, Vector, Widget,
+};
+
+use cascade::Cascade;
+
+/// The default [`Padding`] of the titles and items of a menu.
+pub(crate) const DEFAULT_PADDING: Padding = Padding {
+    top: 4.0,
+    bottom: 4.0,
+    right: 10.0,
+    left: 10.0,
+};
+
+/// A horizontal bar of menus, like the ones found at the top of desktop
+/// applications.
+///
+/// Clicking the title of a [`Menu`] opens it, and hovering other titles
+/// while a menu is open switches between them. Menus can also be opened with
+/// the mnemonics of their titles by holding Alt (e.g. Alt+F for `"&File"`).
+///
+/// Once open, the items of a menu can be navigated with the arrow keys,
+/// triggered with Enter or their mnemonic, and closed with Escape.
+#[allow(missing_debug_implementations)]
+pub struct MenuBar<Message, Theme = crate::Theme, Renderer = crate::Renderer>
+where
+    Renderer: text::Renderer,
+{
+    menus: Vec<Menu<Message>>,
+    width: Length,
+    padding: Padding,
+    text_size: Option<Pixels>,
+    font: Option<Renderer::Font>,
+    style: Style<Theme>,
+}
+
+impl<Message, Theme, Renderer> MenuBar<Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    /// The default [`Padding`] of the titles and items of a [`MenuBar`].
+    pub const DEFAULT_PADDING: Padding = DEFAULT_PADDING;
+
+    /// Creates a new [`MenuBar`] with the given menus.
+    pub fn new(menus: impl IntoIterator<Item = Menu<Message>>) -> Self
+    where
+        Theme: DefaultStyle,
+    {
+        Self {
+            menus: menus.into_iter().collect(),
+            width: Length::Fill,
+            padding: Self::DEFAULT_PADDING,
+            text_size: None,
+            font: None,
+            style: Theme::default_style(),
+        }
+    }
+
+    /// Sets the width of the [`MenuBar`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the [`Padding`] of the titles and items of the [`MenuBar`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the [`MenuBar`].
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(size.into());
+        self
+    }
+
+    /// Sets the font of the [`MenuBar`].
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the style of the [`MenuBar`].
+    pub fn style(mut self, style: fn(&Theme) -> Appearance) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+/// The local state of a [`MenuBar`].
+#[derive(Debug, Clone, Default)]
+struct State {
+    menu: cascade::State,
+    active: usize,
+}
+
+impl State {
+    fn open<Message>(
+        &mut self,
+        index: usize,
+        menus: &[Menu<Message>],
+        keyboard: bool,
+    ) {
+        self.active = index;
+        self.menu.open(
+            keyboard
+                .then(|| {
+                    cascade::next_selectable(&menus[index].items, None, true)
+                })
+                .flatten()
+                .into_iter()
+                .collect(),
+        );
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for MenuBar<Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let text_size =
+            self.text_size.unwrap_or_else(|| renderer.default_size());
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+        let height =
+            f32::from(text::LineHeight::default().to_absolute(text_size))
+                + self.padding.vertical();
+
+        let mut x = 0.0;
+
+        let titles = self
+            .menus
+            .iter()
+            .map(|menu| {
+                let width = cascade::measure_label::<Renderer>(
+                    &menu.label,
+                    text_size,
+                    font,
+                ) + self.padding.horizontal();
+
+                let node = layout::Node::new(Size::new(width, height))
+                    .move_to(Point::new(x, 0.0));
+
+                x += width;
+
+                node
+            })
+            .collect();
+
+        let size =
+            limits.resolve(self.width, Length::Shrink, Size::new(x, height));
+
+        layout::Node::with_children(size, titles)
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        _shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let hovered = layout
+            .children()
+            .position(|title| cursor.is_over(title.bounds()));
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if let Some(index) = hovered {
+                    if state.menu.is_open && state.active == index {
+                        state.menu.close();
+                    } else {
+                        state.open(index, &self.menus, false);
+                    }
+
+                    return event::Status::Captured;
+                }
+
+                state.menu.close();
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(_)) => {
+                state.menu.close();
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                if let Some(index) = hovered {
+                    if state.menu.is_open && state.active != index {
+                        state.open(index, &self.menus, false);
+                    }
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key,
+                modifiers,
+                ..
+            }) => match key.as_ref() {
+                keyboard::Key::Character(c) if modifiers.alt() => {
+                    if let Some(index) =
+                        self.menus.iter().position(|menu| menu.label.matches(c))
+                    {
+                        state.open(index, &self.menus, true);
+
+                        return event::Status::Captured;
+                    }
+                }
+                keyboard::Key::Named(
+                    named @ (key::Named::ArrowLeft | key::Named::ArrowRight),
+                ) if state.menu.is_open && !self.menus.is_empty() => {
+                    let count = self.menus.len();
+                    let index = if named == key::Named::ArrowRight {
+                        (state.active + 1) % count
+                    } else {
+                        (state.active + count - 1) % count
+                    };
+
+                    state.open(index, &self.menus, true);
+
+                    return event::Status::Captured;
+                }
+                _ => {}
+            },
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if layout
+            .children()
+            .any(|title| cursor.is_over(title.bounds()))
+        {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let appearance = (self.style)(theme);
+        let text_size =
+            self.text_size.unwrap_or_else(|| renderer.default_size());
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+
+        if let Some(background) = appearance.bar_background {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: layout.bounds(),
+                    ..renderer::Quad::default()
+                },
+                background,
+            );
+        }
+
+        for (index, (menu, title)) in
+            self.menus.iter().zip(layout.children()).enumerate()
+        {
+            let bounds = title.bounds();
+            let is_active = state.menu.is_open && state.active == index;
+
+            if is_active || cursor.is_over(bounds) {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border: Border::rounded(appearance.border.radius),
+                        ..renderer::Quad::default()
+                    },
+                    appearance.selected_background,
+                );
+            }
+
+            cascade::draw_label(
+                renderer,
+                &menu.label,
+                Point::new(bounds.x + self.padding.left, bounds.center_y()),
+                text_size,
+                font,
+                if is_active {
+                    appearance.selected_text_color
+                } else {
+                    appearance.text_color
+                },
+                bounds,
+            );
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = tree.state.downcast_mut::<State>();
+
+        if !state.menu.is_open {
+            return None;
+        }
+
+        let menu = self.menus.get(state.active)?;
+        let title = layout.children().nth(state.active)?.bounds();
+
+        Some(overlay::Element::new(Box::new(Cascade {
+            state: &mut state.menu,
+            items: &menu.items,
+            position: title.position() + translation,
+            target_height: title.height,
+            padding: self.padding,
+            text_size: self.text_size,
+            font: self.font,
+            style: self.style,
+        })))
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<MenuBar<Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(menu_bar: MenuBar<Message, Theme, Renderer>) -> Self {
+        Self::new(menu_bar)
+    }
+}
+
+/// A menu of a [`MenuBar`].
+#[derive(Debug, Clone)]
+pub struct Menu<Message> {
+    label: Label,
+    items: Vec<Item<Message>>,
+}
+
+impl<Message> Menu<Message> {
+    /// Creates a new [`Menu`] with the given title and items.
+    ///
+    /// An ampersand in the title marks the next character as its mnemonic
+    /// (e.g. `"&File"`). Use `"&&"` for a literal ampersand.
+    pub fn new(
+        label: impl AsRef<str>,
+        items: impl IntoIterator<Item = Item<Message>>,
+    ) -> Self {
+        Self {
+            label: Label::parse(label.as_ref()),
+            items: items.into_iter().collect(),
+        }
+    }
+}
+
+/// An item of a menu.
+#[derive(Debug, Clone)]
+pub struct Item<Message> {
+    kind: Kind<Message>,
+}
+
+#[derive(Debug, Clone)]
+enum Kind<Message> {
+    Action {
+        label: Label,
+        shortcut: Option<String>,
+        checked: Option<bool>,
+        on_select: Option<Message>,
+    },
+    Submenu {
+        label: Label,
+        items: Vec<Item<Message>>,
+    },
+    Separator,
+}
+
+impl<Message> Item<Message> {
+    /// Creates a new [`Item`] with the given label.
+    ///
+    /// An ampersand in the label marks the next character as its mnemonic
+    /// (e.g. `"&Save"`). Use `"&&"` for a literal ampersand.
+    ///
+    /// Unless [`Item::on_select`] is called, the [`Item`] will be disabled.
+    pub fn new(label: impl AsRef<str>) -> Self {
+        Self {
+            kind: Kind::Action {
+                label: Label::parse(label.as_ref()),
+                shortcut: None,
+                checked: None,
+                on_select: None,
+            },
+        }
+    }
+
+    /// Creates a new [`Item`] that opens a submenu with the given items.
+    ///
+    /// A submenu without items is disabled.
+    pub fn submenu(
+        label: impl AsRef<str>,
+        items: impl IntoIterator<Item = Item<Message>>,
+    ) -> Self {
+        Self {
+            kind: Kind::Submenu {
+                label: Label::parse(label.as_ref()),
+                items: items.into_iter().collect(),
+            },
+        }
+    }
+
+    /// Creates a new separator between groups of items.
+    pub fn separator() -> Self {
+        Self {
+            kind: Kind::Separator,
+        }
+    }
+
+    /// Sets the message that will be produced when the [`Item`] is selected.
+    pub fn on_select(mut self, message: Message) -> Self {
+        if let Kind::Action { on_select, .. } = &mut self.kind {
+            *on_select = Some(message);
+        }
+
+        self
+    }
+
+    /// Sets the message that will be produced when the [`Item`] is selected,
+    /// if `Some`.
+    pub fn on_select_maybe(self, message: Option<Message>) -> Self {
+        if let Some(message) = message {
+            self.on_select(message)
+        } else {
+            self
+        }
+    }
+
+    /// Sets the keyboard shortcut displayed next to the [`Item`]
+    /// (e.g. `"Ctrl+S"`).
+    ///
+    /// The shortcut is only displayed; handling it is up to the application.
+    pub fn shortcut(mut self, shortcut: impl Into<String>) -> Self {
+        if let Kind::Action {
+            shortcut: current, ..
+        } = &mut self.kind
+        {
+            *current = Some(shortcut.into());
+        }
+
+        self
+    }
+
+    /// Makes the [`Item`] checkable and sets whether it is checked.
+    pub fn checked(mut self, is_checked: bool) -> Self {
+        if let Kind::Action { checked, .. } = &mut self.kind {
+            *checked = Some(is_checked);
+        }
+
+        self
+    }
+
+    fn kind(&self) -> &Kind<Message> {
+        &self.kind
+    }
+
+    fn label(&self) -> Option<&Label> {
+        match &self.kind {
+            Kind::Action { label, .. } | Kind::Submenu { label, .. } => {
+                Some(label)
+            }
+            Kind::Separator => None,
+        }
+    }
+
+    fn is_enabled(&self) -> bool {
+        match &self.kind {
+            Kind::Action { on_select, .. } => on_select.is_some(),
+            Kind::Submenu { items, .. } => !items.is_empty(),
+            Kind::Separator => false,
+        }
+    }
+}
+
+/// The text of a title or item, with an optional mnemonic.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Label {
+    text: String,
+    /// The index of the character of the mnemonic in `text`.
+    mnemonic: Option<usize>,
+}
+
+impl Label {
+    fn parse(label: &str) -> Self {
+        let mut text = String::with_capacity(label.len());
+        let mut mnemonic = None;
+        let mut chars = label.chars();
+
+        while let Some(c) = chars.next() {
+            if c != '&' {
+                text.push(c);
+                continue;
+            }
+
+            match chars.next() {
+                Some('&') => text.push('&'),
+                Some(c) => {
+                    if mnemonic.is_none() {
+                        mnemonic = Some(text.chars().count());
+                    }
+
+                    text.push(c);
+                }
+                None => {}
+            }
+        }
+
+        Self { text, mnemonic }
+    }
+
+    /// Returns whether the mnemonic of the [`Label`] is the given character,
+    /// ignoring case.
+    fn matches(&self, c: &str) -> bool {
+        self.mnemonic
+            .and_then(|index| self.text.chars().nth(index))
+            .is_some_and(|mnemonic| {
+                mnemonic
+                    .to_lowercase()
+                    .eq(c.chars().flat_map(char::to_lowercase))
+            })
+    }
+}
+
+/// The appearance of a [`MenuBar`] and its menus.
+#[derive(Debug, Clone, Copy)]
+pub struct Appearance {
+    /// The [`Background`] of the bar.
+    pub bar_background: Option<Background>,
+    /// The [`Background`] of a menu.
+    pub background: Background,
+    /// The [`Border`] of a menu.
+    pub border: Border,
+    /// The text [`Color`] of a title or item.
+    pub text_color: Color,
+    /// The text [`Color`] of an open title or highlighted item.
+    pub selected_text_color: Color,
+    /// The [`Background`] of an open title or highlighted item.
+    pub selected_background: Background,
+    /// The text [`Color`] of a disabled item.
+    pub disabled_text_color: Color,
+    /// The [`Color`] of a separator.
+    pub separator: Color,
+}
+
+/// The style of a [`MenuBar`] and its menus.
+pub type Style<Theme> = fn(&Theme) -> Appearance;
+
+/// The default style of a [`MenuBar`] and its menus.
+pub trait DefaultStyle {
+    /// Returns the default style of a [`MenuBar`] and its menus.
+    fn default_style() -> Style<Self>;
+}
+
+impl DefaultStyle for Theme {
+    fn default_style() -> Style<Self> {
+        default
+    }
+}
+
+impl DefaultStyle for Appearance {
+    fn default_style() -> Style<Self> {
+        |appearance| *appearance
+    }
+}
+
+/// The default style of a [`MenuBar`] and its menus.
+pub fn default(theme: &Theme) -> Appearance {
+    let palette = theme.extended_palette();
+
+    Appearance {
+        bar_background: Some(palette.background.weak.color.into()),
+        background: palette.background.base.color.into(),
+        border: Border {
+            width: 1.0,
+            radius: 2.0.into(),
+            color: palette.background.strong.color,
+        },
+        text_color: palette.background.base.text,
+        selected_text_color: palette.primary.strong.text,
+        selected_background: palette.primary.strong.color.into(),
+        disabled_text_color: palette.background.strong.color,
+        separator: palette.background.strong.color,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_mnemonics() {
+        let label = Label::parse("&File");
+        assert_eq!(label.text, "File");
+        assert_eq!(label.mnemonic, Some(0));
+        assert!(label.matches("f"));
+        assert!(label.matches("F"));
+
+        let label = Label::parse("Save &As...");
+        assert_eq!(label.text, "Save As...");
+        assert_eq!(label.mnemonic, Some(5));
+        assert!(label.matches("a"));
+
+        let label = Label::parse("Find && &Replace");
+        assert_eq!(label.text, "Find & Replace");
+        assert_eq!(label.mnemonic, Some(7));
+
+        let label = Label::parse("Plain");
+        assert_eq!(label.mnemonic, None);
+        assert!(!label.matches("p"));
+    }
+}
@@ -0,0 +1,746 @@
+//! Show the items of a menu and its submenus in a cascade of levels.
+use crate::core::alignment;
+use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text::{self, Paragraph as _, Text};
+use crate::core::touch;
+use crate::core::{
+    Border, Clipboard, Color, Padding, Pixels, Point, Rectangle, Shell, Size,
+};
+use crate::menu_bar::{Appearance, Item, Kind, Label};
+
+/// The space between the items of a level and its border.
+const LEVEL_PADDING: f32 = 4.0;
+
+/// The height of a separator.
+const SEPARATOR_HEIGHT: f32 = 9.0;
+
+/// The space between the label of an item and its shortcut.
+const SHORTCUT_SPACING: f32 = 24.0;
+
+/// The state of a cascade of menus.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct State {
+    pub(crate) is_open: bool,
+    /// The index of the highlighted item of each open level.
+    pub(crate) path: Vec<usize>,
+}
+
+impl State {
+    pub(crate) fn open(&mut self, path: Vec<usize>) {
+        self.is_open = true;
+        self.path = path;
+    }
+
+    pub(crate) fn close(&mut self) {
+        self.is_open = false;
+        self.path.clear();
+    }
+}
+
+/// A cascade of menu levels, opened from some anchor point.
+pub(crate) struct Cascade<'a, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    pub(crate) state: &'a mut State,
+    pub(crate) items: &'a [Item<Message>],
+    pub(crate) position: Point,
+    pub(crate) target_height: f32,
+    pub(crate) padding: Padding,
+    pub(crate) text_size: Option<Pixels>,
+    pub(crate) font: Option<Renderer::Font>,
+    pub(crate) style: fn(&Theme) -> Appearance,
+}
+
+impl<'a, Message, Theme, Renderer> Cascade<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: text::Renderer,
+{
+    /// Triggers the item at the given level and index.
+    ///
+    /// Submenus are opened, highlighting their first item if `from_keyboard`.
+    fn activate(
+        &mut self,
+        depth: usize,
+        index: usize,
+        from_keyboard: bool,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let levels = levels(self.items, &self.state.path);
+
+        let Some(item) = levels.get(depth).and_then(|items| items.get(index))
+        else {
+            return;
+        };
+
+        match &item.kind {
+            Kind::Submenu { items, .. } => {
+                self.state.path.truncate(depth);
+                self.state.path.push(index);
+
+                if from_keyboard {
+                    if let Some(first) = next_selectable(items, None, true) {
+                        self.state.path.push(first);
+                    }
+                }
+
+                shell.invalidate_layout();
+            }
+            Kind::Action {
+                on_select: Some(on_select),
+                ..
+            } => {
+                shell.publish(on_select.clone());
+                self.state.close();
+            }
+            Kind::Action { .. } | Kind::Separator => {}
+        }
+    }
+
+    fn highlight(
+        &mut self,
+        depth: usize,
+        index: usize,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        if self.state.path.len() != depth + 1 || self.state.path[depth] != index
+        {
+            self.state.path.truncate(depth);
+            self.state.path.push(index);
+
+            shell.invalidate_layout();
+        }
+    }
+
+    fn on_key_pressed(
+        &mut self,
+        key: &keyboard::Key,
+        modifiers: keyboard::Modifiers,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let levels = levels(self.items, &self.state.path);
+        let depth = self
+            .state
+            .path
+            .len()
+            .saturating_sub(1)
+            .min(levels.len() - 1);
+        let items = levels[depth];
+        let current = self.state.path.get(depth).copied();
+
+        match key.as_ref() {
+            keyboard::Key::Named(
+                named @ (key::Named::ArrowDown | key::Named::ArrowUp),
+            ) => {
+                let forward = named == key::Named::ArrowDown;
+
+                if let Some(next) = next_selectable(items, current, forward) {
+                    self.highlight(depth, next, shell);
+                }
+            }
+            keyboard::Key::Named(key::Named::ArrowRight) => {
+                let Some(current) = current.filter(|index| {
+                    matches!(
+                        items.get(*index).map(Item::kind),
+                        Some(Kind::Submenu { .. })
+                    )
+                }) else {
+                    return event::Status::Ignored;
+                };
+
+                self.activate(depth, current, true, shell);
+            }
+            keyboard::Key::Named(key::Named::ArrowLeft) => {
+                if depth == 0 {
+                    return event::Status::Ignored;
+                }
+
+                self.state.path.truncate(depth);
+                shell.invalidate_layout();
+            }
+            keyboard::Key::Named(key::Named::Enter | key::Named::Space) => {
+                if let Some(current) = current {
+                    self.activate(depth, current, true, shell);
+                }
+            }
+            keyboard::Key::Named(key::Named::Escape) => {
+                if depth == 0 {
+                    self.state.close();
+                } else {
+                    self.state.path.truncate(depth);
+                }
+
+                shell.invalidate_layout();
+            }
+            keyboard::Key::Character(c)
+                if !modifiers.alt() && !modifiers.command() =>
+            {
+                let Some(index) = items.iter().position(|item| {
+                    item.label().is_some_and(|label| label.matches(c))
+                }) else {
+                    return event::Status::Ignored;
+                };
+
+                self.highlight(depth, index, shell);
+                self.activate(depth, index, true, shell);
+            }
+            _ => return event::Status::Ignored,
+        }
+
+        event::Status::Captured
+    }
+}
+
+impl<'a, Message, Theme, Renderer> overlay::Overlay<Message, Theme, Renderer>
+    for Cascade<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: text::Renderer,
+{
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> layout::Node {
+        let text_size =
+            self.text_size.unwrap_or_else(|| renderer.default_size());
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+        let item_height =
+            f32::from(text::LineHeight::default().to_absolute(text_size))
+                + self.padding.vertical();
+
+        let mut parent: Option<Rectangle> = None;
+        let mut nodes = Vec::new();
+
+        for (depth, items) in
+            levels(self.items, &self.state.path).into_iter().enumerate()
+        {
+            let (labels, shortcuts) = items.iter().fold(
+                (0.0f32, 0.0f32),
+                |(labels, shortcuts), item| match &item.kind {
+                    Kind::Action {
+                        label, shortcut, ..
+                    } => (
+                        labels.max(measure::<Renderer>(
+                            &label.text,
+                            text_size,
+                            font,
+                        )),
+                        shortcut.as_deref().map_or(shortcuts, |shortcut| {
+                            shortcuts.max(measure::<Renderer>(
+                                shortcut, text_size, font,
+                            ))
+                        }),
+                    ),
+                    Kind::Submenu { label, .. } => (
+                        labels.max(measure::<Renderer>(
+                            &label.text,
+                            text_size,
+                            font,
+                        )),
+                        shortcuts,
+                    ),
+                    Kind::Separator => (labels, shortcuts),
+                },
+            );
+
+            let width = self.padding.horizontal()
+                + 2.0 * text_size.0
+                + labels
+                + if shortcuts > 0.0 {
+                    SHORTCUT_SPACING + shortcuts
+                } else {
+                    0.0
+                };
+
+            let mut y = LEVEL_PADDING;
+
+            let children: Vec<_> = items
+                .iter()
+                .map(|item| {
+                    let height = if let Kind::Separator = item.kind {
+                        SEPARATOR_HEIGHT
+                    } else {
+                        item_height
+                    };
+
+                    let node = layout::Node::new(Size::new(width, height))
+                        .move_to(Point::new(0.0, y));
+
+                    y += height;
+
+                    node
+                })
+                .collect();
+
+            let size = Size::new(width, y + LEVEL_PADDING);
+
+            let position = match parent {
+                None => {
+                    let x =
+                        self.position.x.min(bounds.width - size.width).max(0.0);
+
+                    let below = self.position.y + self.target_height;
+
+                    let y = if below + size.height <= bounds.height {
+                        below
+                    } else if self.position.y >= size.height {
+                        self.position.y - size.height
+                    } else {
+                        (bounds.height - size.height).max(0.0)
+                    };
+
+                    Point::new(x, y)
+                }
+                Some(parent) => {
+                    let x = if parent.x + parent.width + size.width
+                        <= bounds.width
+                    {
+                        parent.x + parent.width
+                    } else {
+                        (parent.x - size.width).max(0.0)
+                    };
+
+                    let y = (parent.y - LEVEL_PADDING)
+                        .min(bounds.height - size.height)
+                        .max(0.0);
+
+                    Point::new(x, y)
+                }
+            };
+
+            parent = self
+                .state
+                .path
+                .get(depth)
+                .and_then(|index| children.get(*index))
+                .map(|item| Rectangle {
+                    x: position.x,
+                    y: position.y + item.bounds().y,
+                    width: size.width,
+                    height: item.bounds().height,
+                });
+
+            nodes.push(
+                layout::Node::with_children(size, children).move_to(position),
+            );
+        }
+
+        layout::Node::with_children(bounds, nodes)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                if let Some((depth, index)) = find(layout, cursor) {
+                    let levels = levels(self.items, &self.state.path);
+
+                    if levels[depth]
+                        .get(index)
+                        .is_some_and(|item| item.label().is_some())
+                    {
+                        self.highlight(depth, index, shell);
+                    }
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if let Some((depth, index)) = find(layout, cursor) {
+                    self.activate(depth, index, false, shell);
+
+                    return event::Status::Captured;
+                }
+
+                if layout
+                    .children()
+                    .any(|level| cursor.is_over(level.bounds()))
+                {
+                    return event::Status::Captured;
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key,
+                modifiers,
+                ..
+            }) => {
+                return self.on_key_pressed(&key, modifiers, shell);
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let levels = levels(self.items, &self.state.path);
+
+        match find(layout, cursor) {
+            Some((depth, index))
+                if levels[depth].get(index).is_some_and(Item::is_enabled) =>
+            {
+                mouse::Interaction::Pointer
+            }
+            _ => mouse::Interaction::default(),
+        }
+    }
+
+    fn is_over(
+        &self,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        cursor_position: Point,
+    ) -> bool {
+        layout
+            .children()
+            .any(|level| level.bounds().contains(cursor_position))
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+    ) {
+        let appearance = (self.style)(theme);
+        let text_size =
+            self.text_size.unwrap_or_else(|| renderer.default_size());
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+
+        for (depth, (items, level)) in levels(self.items, &self.state.path)
+            .into_iter()
+            .zip(layout.children())
+            .enumerate()
+        {
+            let bounds = level.bounds();
+            let highlighted = self.state.path.get(depth).copied();
+
+            // Every level is drawn in its own layer, so that submenus are
+            // drawn on top of their parents.
+            renderer.with_layer(bounds, |renderer| {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border: appearance.border,
+                        ..renderer::Quad::default()
+                    },
+                    appearance.background,
+                );
+
+                for (index, (item, layout)) in
+                    items.iter().zip(level.children()).enumerate()
+                {
+                    self.draw_item(
+                        renderer,
+                        &appearance,
+                        item,
+                        layout.bounds(),
+                        highlighted == Some(index),
+                        text_size,
+                        font,
+                    );
+                }
+            });
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Cascade<'a, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    fn draw_item(
+        &self,
+        renderer: &mut Renderer,
+        appearance: &Appearance,
+        item: &Item<Message>,
+        bounds: Rectangle,
+        is_highlighted: bool,
+        text_size: Pixels,
+        font: Renderer::Font,
+    ) {
+        let label = match &item.kind {
+            Kind::Separator => {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: bounds.x + self.padding.left,
+                            y: bounds.center_y().round(),
+                            width: bounds.width - self.padding.horizontal(),
+                            height: 1.0,
+                        },
+                        ..renderer::Quad::default()
+                    },
+                    appearance.separator,
+                );
+
+                return;
+            }
+            Kind::Action { label, .. } | Kind::Submenu { label, .. } => label,
+        };
+
+        if is_highlighted {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: bounds.x + appearance.border.width,
+                        width: bounds.width - appearance.border.width * 2.0,
+                        ..bounds
+                    },
+                    border: Border::rounded(appearance.border.radius),
+                    ..renderer::Quad::default()
+                },
+                appearance.selected_background,
+            );
+        }
+
+        let color = if !item.is_enabled() {
+            appearance.disabled_text_color
+        } else if is_highlighted {
+            appearance.selected_text_color
+        } else {
+            appearance.text_color
+        };
+
+        let x = bounds.x + self.padding.left;
+        let y = bounds.center_y();
+        let end = bounds.x + bounds.width - self.padding.right;
+
+        draw_label(
+            renderer,
+            label,
+            Point::new(x + text_size.0, y),
+            text_size,
+            font,
+            color,
+            bounds,
+        );
+
+        let checkmark = Renderer::CHECKMARK_ICON.to_string();
+
+        let text = |content, font, size, horizontal_alignment, shaping| Text {
+            content,
+            bounds: Size::new(f32::INFINITY, bounds.height),
+            size,
+            line_height: text::LineHeight::default(),
+            font,
+            horizontal_alignment,
+            vertical_alignment: alignment::Vertical::Center,
+            shaping,
+        };
+
+        match &item.kind {
+            Kind::Action {
+                shortcut, checked, ..
+            } => {
+                if *checked == Some(true) {
+                    renderer.fill_text(
+                        text(
+                            &checkmark,
+                            Renderer::ICON_FONT,
+                            text_size * 0.8,
+                            alignment::Horizontal::Left,
+                            text::Shaping::Basic,
+                        ),
+                        Point::new(x, y),
+                        color,
+                        bounds,
+                    );
+                }
+
+                if let Some(shortcut) = shortcut {
+                    renderer.fill_text(
+                        text(
+                            shortcut,
+                            font,
+                            text_size,
+                            alignment::Horizontal::Right,
+                            text::Shaping::Advanced,
+                        ),
+                        Point::new(end - text_size.0, y),
+                        Color {
+                            a: color.a * 0.7,
+                            ..color
+                        },
+                        bounds,
+                    );
+                }
+            }
+            Kind::Submenu { .. } => {
+                renderer.fill_text(
+                    text(
+                        "▶",
+                        font,
+                        text_size * 0.6,
+                        alignment::Horizontal::Right,
+                        text::Shaping::Advanced,
+                    ),
+                    Point::new(end, y),
+                    color,
+                    bounds,
+                );
+            }
+            Kind::Separator => {}
+        }
+    }
+}
+
+/// Returns the items of every open level, given the highlighted path.
+fn levels<'a, Message>(
+    items: &'a [Item<Message>],
+    path: &[usize],
+) -> Vec<&'a [Item<Message>]> {
+    let mut levels = vec![items];
+
+    for index in path {
+        match levels.last().and_then(|items| items.get(*index)) {
+            Some(Item {
+                kind: Kind::Submenu { items, .. },
+            }) => levels.push(items),
+            _ => break,
+        }
+    }
+
+    levels
+}
+
+/// Finds the level and index of the item under the cursor, if any.
+fn find(layout: Layout<'_>, cursor: mouse::Cursor) -> Option<(usize, usize)> {
+    let position = cursor.position()?;
+
+    layout
+        .children()
+        .enumerate()
+        .rev()
+        .find_map(|(depth, level)| {
+            level
+                .children()
+                .position(|item| item.bounds().contains(position))
+                .map(|index| (depth, index))
+        })
+}
+
+/// Returns the index of the next item that is not a separator, wrapping
+/// around the given items.
+pub(crate) fn next_selectable<Message>(
+    items: &[Item<Message>],
+    current: Option<usize>,
+    forward: bool,
+) -> Option<usize> {
+    let count = items.len();
+
+    (1..=count)
+        .map(|step| match (current, forward) {
+            (Some(current), true) => (current + step) % count,
+            (Some(current), false) => (current + count * 2 - step) % count,
+            (None, true) => step - 1,
+            (None, false) => count - step,
+        })
+        .find(|index| items[*index].label().is_some())
+}
+
+fn measure<Renderer>(content: &str, size: Pixels, font: Renderer::Font) -> f32
+where
+    Renderer: text::Renderer,
+{
+    Renderer::Paragraph::with_text(Text {
+        content,
+        bounds: Size::INFINITY,
+        size,
+        line_height: text::LineHeight::default(),
+        font,
+        horizontal_alignment: alignment::Horizontal::Left,
+        vertical_alignment: alignment::Vertical::Top,
+        shaping: text::Shaping::Advanced,
+    })
+    .min_width()
+}
+
+/// Measures the width of the given [`Label`].
+pub(super) fn measure_label<Renderer>(
+    label: &Label,
+    size: Pixels,
+    font: Renderer::Font,
+) -> f32
+where
+    Renderer: text::Renderer,
+{
+    measure::<Renderer>(&label.text, size, font)
+}
+
+/// Draws a [`Label`] vertically centered at the given position, underlining
+/// its mnemonic.
+pub(super) fn draw_label<Renderer>(
+    renderer: &mut Renderer,
+    label: &Label,
+    position: Point,
+    size: Pixels,
+    font: Renderer::Font,
+    color: Color,
+    clip_bounds: Rectangle,
+) where
+    Renderer: text::Renderer,
+{
+    let text = Text {
+        content: label.text.as_str(),
+        bounds: Size::new(f32::INFINITY, clip_bounds.height),
+        size,
+        line_height: text::LineHeight::default(),
+        font,
+        horizontal_alignment: alignment::Horizontal::Left,
+        vertical_alignment: alignment::Vertical::Center,
+        shaping: text::Shaping::Advanced,
+    };
+
+    renderer.fill_text(text, position, color, clip_bounds);
+
+    let Some(index) = label.mnemonic else {
+        return;
+    };
+
+    let paragraph = Renderer::Paragraph::with_text(Text {
+        bounds: Size::INFINITY,
+        vertical_alignment: alignment::Vertical::Top,
+        ..text
+    });
+
+    let Some(start) = paragraph.grapheme_position(0, index) else {
+        return;
+    };
+
+    let end = paragraph
+        .grapheme_position(0, index + 1)
+        .map_or(paragraph.min_width(), |end| end.x);
+
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds: Rectangle {
+                x: position.x + start.x,
+                y: (position.y + size.0 * 0.45).round(),
+                width: (end - start.x).max(1.0),
+                height: 1.0,
+            },
+            ..renderer::Quad::default()
+        },
+        color,
+    );
+}
@@ -0,0 +1,854 @@
+//! Show dialogs on top of some content.
+//!
+//! A [`Modal`] wraps the content of your application and can show any
+//! number of dialogs stacked on top of it. While a dialog is shown, a
+//! backdrop blocks any interaction with everything below it and the Tab
+//! key only moves the focus between the widgets of the top-most dialog.
+//!
+//! Once all the dialogs are closed, the focus goes back to the widget that
+//! was focused before the first dialog was opened.
+//!
+//! # Example
+//! ```no_run
+//! # type Element<'a, Message> =
+//! #     iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! use iced_widget::{button, column, container, modal, text};
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Open,
+//!     Close,
+//! }
+//!
+//! fn view(is_open: bool) -> Element<'static, Message> {
+//!     let content = button(text("Open dialog")).on_press(Message::Open);
+//!
+//!     let modal = modal(content).on_close(Message::Close);
+//!
+//!     if is_open {
+//!         modal
+//!             .dialog(
+//!                 container(column![
+//!                     text("Hello!"),
+//!                     button(text("Close")).on_press(Message::Close),
+//!                 ])
+//!                 .padding(10)
+//!                 .style(container::rounded_box),
+//!             )
+//!             .into()
+//!     } else {
+//!         modal.into()
+//!     }
+//! }
+//! ```
+use crate::core::alignment::Alignment;
+use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::touch;
+use crate::core::widget::operation::{
+    self, Focusable, Operation, Scrollable, TextInput,
+};
+use crate::core::widget::{tree, Id, Tree};
+use crate::core::{
+    Background, Clipboard, Color, Element, Layout, Length, Point, Rectangle,
+    Shell, Size, Theme, Vector, Widget,
+};
+
+use std::any::Any;
+
+/// A widget that shows a stack of dialogs on top of some content.
+///
+/// Only the top-most dialog can be interacted with.
+#[allow(missing_debug_implementations)]
+pub struct Modal<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer>
+where
+    Renderer: crate::core::Renderer,
+{
+    base: Element<'a, Message, Theme, Renderer>,
+    dialogs: Vec<Element<'a, Message, Theme, Renderer>>,
+    on_close: Option<Message>,
+    close_on_escape: bool,
+    close_on_backdrop_press: bool,
+    style: Style<Theme>,
+}
+
+impl<'a, Message, Theme, Renderer> Modal<'a, Message, Theme, Renderer>
+where
+    Renderer: crate::core::Renderer,
+{
+    /// Creates a new [`Modal`] with the given base content and no dialogs.
+    pub fn new(base: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self
+    where
+        Theme: DefaultStyle,
+    {
+        Self {
+            base: base.into(),
+            dialogs: Vec::new(),
+            on_close: None,
+            close_on_escape: true,
+            close_on_backdrop_press: true,
+            style: Theme::default_style(),
+        }
+    }
+
+    /// Shows a dialog on top of the [`Modal`].
+    ///
+    /// This method can be called multiple times to stack dialogs; the last
+    /// one is on top.
+    pub fn dialog(
+        mut self,
+        dialog: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        self.dialogs.push(dialog.into());
+        self
+    }
+
+    /// Sets the message that will be produced when the top-most dialog of
+    /// the [`Modal`] is dismissed with the Escape key or by pressing its
+    /// backdrop.
+    pub fn on_close(mut self, on_close: Message) -> Self {
+        self.on_close = Some(on_close);
+        self
+    }
+
+    /// Sets whether pressing the Escape key closes the top-most dialog of
+    /// the [`Modal`].
+    ///
+    /// By default, it does.
+    pub fn close_on_escape(mut self, close_on_escape: bool) -> Self {
+        self.close_on_escape = close_on_escape;
+        self
+    }
+
+    /// Sets whether pressing the backdrop closes the top-most dialog of the
+    /// [`Modal`].
+    ///
+    /// By default, it does.
+    pub fn close_on_backdrop_press(
+        mut self,
+        close_on_backdrop_press: bool,
+    ) -> Self {
+        self.close_on_backdrop_press = close_on_backdrop_press;
+        self
+    }
+
+    /// Sets the style of the [`Modal`].
+    pub fn style(mut self, style: fn(&Theme) -> Appearance) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+/// Local state of the [`Modal`].
+///
+/// It keeps track of the focused widget of every layer covered by a dialog,
+/// so the focus can be restored once the dialog is closed.
+#[derive(Debug, Clone, Default)]
+struct State {
+    base_focus: Option<Option<usize>>,
+    dialog_focus: Vec<Option<usize>>,
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Modal<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: crate::core::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        std::iter::once(&self.base)
+            .chain(&self.dialogs)
+            .map(Tree::new)
+            .collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let children: Vec<_> =
+            std::iter::once(&self.base).chain(&self.dialogs).collect();
+
+        tree.diff_children(&children);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.base.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.base
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        if self.dialogs.is_empty() {
+            self.base.as_widget().operate(
+                &mut tree.children[0],
+                layout,
+                renderer,
+                operation,
+            );
+        } else {
+            self.base.as_widget().operate(
+                &mut tree.children[0],
+                layout,
+                renderer,
+                &mut Unfocusable(operation),
+            );
+        }
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let base = &mut tree.children[0];
+
+        if self.dialogs.is_empty() {
+            state.dialog_focus.clear();
+
+            if let Some(focused) = state.base_focus.take() {
+                if focused.is_some() {
+                    focus(&self.base, base, layout, renderer, focused);
+                }
+            }
+        } else {
+            if state.base_focus.is_none() {
+                state.base_focus =
+                    Some(find_focused(&self.base, base, layout, renderer));
+
+                focus(&self.base, base, layout, renderer, None);
+            }
+
+            // The dialogs block any user interaction with the base content
+            if !matches!(event, Event::Window(..)) {
+                return event::Status::Ignored;
+            }
+        }
+
+        self.base.as_widget_mut().on_event(
+            base, event, layout, cursor, renderer, clipboard, shell, viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.base.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.base.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let (base, dialogs) = tree.children.split_at_mut(1);
+
+        if self.dialogs.is_empty() {
+            return self.base.as_widget_mut().overlay(
+                &mut base[0],
+                layout,
+                renderer,
+                translation,
+            );
+        }
+
+        Some(overlay::Element::new(Box::new(Overlay {
+            dialogs: &mut self.dialogs,
+            trees: dialogs,
+            state: tree.state.downcast_mut::<State>(),
+            on_close: self.on_close.as_ref(),
+            close_on_escape: self.close_on_escape,
+            close_on_backdrop_press: self.close_on_backdrop_press,
+            style: self.style,
+        })))
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Modal<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: 'a,
+    Renderer: crate::core::Renderer + 'a,
+{
+    fn from(modal: Modal<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(modal)
+    }
+}
+
+/// The dialogs of a [`Modal`], centered on top of the whole window.
+struct Overlay<'a, 'b, Message, Theme, Renderer> {
+    dialogs: &'b mut [Element<'a, Message, Theme, Renderer>],
+    trees: &'b mut [Tree],
+    state: &'b mut State,
+    on_close: Option<&'b Message>,
+    close_on_escape: bool,
+    close_on_backdrop_press: bool,
+    style: Style<Theme>,
+}
+
+impl<'a, 'b, Message, Theme, Renderer> Overlay<'a, 'b, Message, Theme, Renderer>
+where
+    Renderer: crate::core::Renderer,
+{
+    /// Saves and clears the focus of the dialogs that have been covered by
+    /// a new dialog, and restores the focus of the top-most dialog if the
+    /// dialogs that were covering it have been closed.
+    fn track_focus(&mut self, layout: Layout<'_>, renderer: &Renderer) {
+        let covered = self.dialogs.len() - 1;
+
+        for (i, layout) in layout.children().enumerate() {
+            if i < self.state.dialog_focus.len() {
+                continue;
+            }
+
+            if i >= covered {
+                break;
+            }
+
+            let focused = find_focused(
+                &self.dialogs[i],
+                &mut self.trees[i],
+                layout,
+                renderer,
+            );
+
+            focus(&self.dialogs[i], &mut self.trees[i], layout, renderer, None);
+
+            self.state.dialog_focus.push(focused);
+        }
+
+        if self.state.dialog_focus.len() > covered {
+            let focused = self.state.dialog_focus[covered];
+
+            if focused.is_some() {
+                focus(
+                    &self.dialogs[covered],
+                    &mut self.trees[covered],
+                    layout.children().nth(covered).expect("Dialog layout"),
+                    renderer,
+                    focused,
+                );
+            }
+
+            self.state.dialog_focus.truncate(covered);
+        }
+    }
+}
+
+impl<'a, 'b, Message, Theme, Renderer>
+    overlay::Overlay<Message, Theme, Renderer>
+    for Overlay<'a, 'b, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: crate::core::Renderer,
+{
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> layout::Node {
+        let limits = layout::Limits::new(Size::ZERO, bounds)
+            .width(Length::Fill)
+            .height(Length::Fill);
+
+        let dialogs = self
+            .dialogs
+            .iter()
+            .zip(self.trees.iter_mut())
+            .map(|(dialog, tree)| {
+                dialog.as_widget().layout(tree, renderer, &limits).align(
+                    Alignment::Center,
+                    Alignment::Center,
+                    limits.max(),
+                )
+            })
+            .collect();
+
+        layout::Node::with_children(bounds, dialogs)
+    }
+
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let top = self.dialogs.len() - 1;
+
+        for (i, ((dialog, tree), layout)) in self
+            .dialogs
+            .iter()
+            .zip(self.trees.iter_mut())
+            .zip(layout.children())
+            .enumerate()
+        {
+            if i == top {
+                dialog
+                    .as_widget()
+                    .operate(tree, layout, renderer, operation);
+            } else {
+                dialog.as_widget().operate(
+                    tree,
+                    layout,
+                    renderer,
+                    &mut Unfocusable(operation),
+                );
+            }
+        }
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        self.track_focus(layout, renderer);
+
+        let top = self.dialogs.len() - 1;
+        let dialog_layout = layout.children().nth(top).expect("Dialog layout");
+
+        match &event {
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key::Named::Escape),
+                ..
+            }) if self.close_on_escape => {
+                if let Some(on_close) = self.on_close {
+                    shell.publish(on_close.clone());
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key::Named::Tab),
+                modifiers,
+                ..
+            }) => {
+                let (focused, count) = count_focusables(
+                    &self.dialogs[top],
+                    &mut self.trees[top],
+                    dialog_layout,
+                    renderer,
+                );
+
+                if let Some(next) = cycle(focused, count, modifiers.shift()) {
+                    focus(
+                        &self.dialogs[top],
+                        &mut self.trees[top],
+                        dialog_layout,
+                        renderer,
+                        Some(next),
+                    );
+                }
+
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(_))
+            | Event::Touch(touch::Event::FingerPressed { .. })
+                if !cursor.is_over(dialog_layout.bounds()) =>
+            {
+                if self.close_on_backdrop_press {
+                    if let Some(on_close) = self.on_close {
+                        shell.publish(on_close.clone());
+                    }
+                }
+
+                return event::Status::Captured;
+            }
+            _ => {}
+        }
+
+        let status = self.dialogs[top].as_widget_mut().on_event(
+            &mut self.trees[top],
+            event.clone(),
+            dialog_layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            &layout.bounds(),
+        );
+
+        match event {
+            Event::Mouse(_) | Event::Touch(_) => event::Status::Captured,
+            _ => status,
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) {
+        let bounds = layout.bounds();
+        let appearance = (self.style)(theme);
+        let top = self.dialogs.len() - 1;
+
+        for (i, ((dialog, tree), dialog_layout)) in self
+            .dialogs
+            .iter()
+            .zip(self.trees.iter())
+            .zip(layout.children())
+            .enumerate()
+        {
+            renderer.with_layer(bounds, |renderer| {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        ..renderer::Quad::default()
+                    },
+                    appearance.backdrop,
+                );
+
+                dialog.as_widget().draw(
+                    tree,
+                    renderer,
+                    theme,
+                    style,
+                    dialog_layout,
+                    if i == top {
+                        cursor
+                    } else {
+                        mouse::Cursor::Unavailable
+                    },
+                    &bounds,
+                );
+            });
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let top = self.dialogs.len() - 1;
+
+        self.dialogs[top].as_widget().mouse_interaction(
+            &self.trees[top],
+            layout.children().nth(top).expect("Dialog layout"),
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn is_over(
+        &self,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+        _cursor_position: Point,
+    ) -> bool {
+        true
+    }
+
+    fn overlay<'c>(
+        &'c mut self,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'c, Message, Theme, Renderer>> {
+        let top = self.dialogs.len() - 1;
+
+        self.dialogs[top].as_widget_mut().overlay(
+            &mut self.trees[top],
+            layout.children().nth(top).expect("Dialog layout"),
+            renderer,
+            Vector::ZERO,
+        )
+    }
+}
+
+/// Returns the index of the widget that should be focused when cycling
+/// through `count` focusable widgets, starting from the `focused` one.
+fn cycle(
+    focused: Option<usize>,
+    count: usize,
+    backwards: bool,
+) -> Option<usize> {
+    if count == 0 {
+        return None;
+    }
+
+    Some(match (focused, backwards) {
+        (Some(i), false) => (i + 1) % count,
+        (Some(i), true) => (i + count - 1) % count,
+        (None, false) => 0,
+        (None, true) => count - 1,
+    })
+}
+
+/// Returns the index of the focused widget of the element, if any.
+fn find_focused<Message, Theme, Renderer>(
+    element: &Element<'_, Message, Theme, Renderer>,
+    tree: &mut Tree,
+    layout: Layout<'_>,
+    renderer: &Renderer,
+) -> Option<usize>
+where
+    Renderer: crate::core::Renderer,
+{
+    count_focusables(element, tree, layout, renderer).0
+}
+
+/// Returns the index of the focused widget of the element, if any, and
+/// the amount of focusable widgets in it.
+fn count_focusables<Message, Theme, Renderer>(
+    element: &Element<'_, Message, Theme, Renderer>,
+    tree: &mut Tree,
+    layout: Layout<'_>,
+    renderer: &Renderer,
+) -> (Option<usize>, usize)
+where
+    Renderer: crate::core::Renderer,
+{
+    struct Count {
+        focused: Option<usize>,
+        count: usize,
+    }
+
+    impl<T> Operation<T> for Count {
+        fn focusable(&mut self, state: &mut dyn Focusable, _id: Option<&Id>) {
+            if state.is_focused() {
+                self.focused = Some(self.count);
+            }
+
+            self.count += 1;
+        }
+
+        fn container(
+            &mut self,
+            _id: Option<&Id>,
+            _bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+        ) {
+            operate_on_children(self);
+        }
+    }
+
+    let mut operation = Count {
+        focused: None,
+        count: 0,
+    };
+
+    element
+        .as_widget()
+        .operate(tree, layout, renderer, &mut operation);
+
+    (operation.focused, operation.count)
+}
+
+/// Focuses the focusable widget of the element with the given index and
+/// unfocuses the rest.
+fn focus<Message, Theme, Renderer>(
+    element: &Element<'_, Message, Theme, Renderer>,
+    tree: &mut Tree,
+    layout: Layout<'_>,
+    renderer: &Renderer,
+    index: Option<usize>,
+) where
+    Renderer: crate::core::Renderer,
+{
+    struct Focus {
+        target: Option<usize>,
+        current: usize,
+    }
+
+    impl<T> Operation<T> for Focus {
+        fn focusable(&mut self, state: &mut dyn Focusable, _id: Option<&Id>) {
+            if self.target == Some(self.current) {
+                state.focus();
+            } else {
+                state.unfocus();
+            }
+
+            self.current += 1;
+        }
+
+        fn container(
+            &mut self,
+            _id: Option<&Id>,
+            _bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+        ) {
+            operate_on_children(self);
+        }
+    }
+
+    element.as_widget().operate(
+        tree,
+        layout,
+        renderer,
+        &mut Focus {
+            target: index,
+            current: 0,
+        },
+    );
+}
+
+/// An [`Operation`] that hides the focusable widgets of a layer covered by
+/// a dialog, so focus cannot escape the top-most dialog.
+struct Unfocusable<'a, T>(&'a mut dyn Operation<T>);
+
+impl<'a, T> Operation<T> for Unfocusable<'a, T> {
+    fn container(
+        &mut self,
+        id: Option<&Id>,
+        bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        self.0.container(id, bounds, &mut |operation| {
+            operate_on_children(&mut Unfocusable(operation));
+        });
+    }
+
+    fn scrollable(
+        &mut self,
+        state: &mut dyn Scrollable,
+        id: Option<&Id>,
+        bounds: Rectangle,
+        translation: Vector,
+    ) {
+        self.0.scrollable(state, id, bounds, translation);
+    }
+
+    fn text_input(&mut self, state: &mut dyn TextInput, id: Option<&Id>) {
+        self.0.text_input(state, id);
+    }
+
+    fn custom(&mut self, state: &mut dyn Any, id: Option<&Id>) {
+        self.0.custom(state, id);
+    }
+
+    fn bounds(&mut self, id: Option<&Id>, bounds: Rectangle) {
+        self.0.bounds(id, bounds);
+    }
+
+    fn finish(&self) -> operation::Outcome<T> {
+        self.0.finish()
+    }
+}
+
+/// The appearance of a [`Modal`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Appearance {
+    /// The [`Background`] drawn behind every dialog, covering the content
+    /// below it.
+    pub backdrop: Background,
+}
+
+/// The style of a [`Modal`].
+pub type Style<Theme> = fn(&Theme) -> Appearance;
+
+/// The default style of a [`Modal`].
+pub trait DefaultStyle {
+    /// Returns the default style of a [`Modal`].
+    fn default_style() -> Style<Self>;
+}
+
+impl DefaultStyle for Theme {
+    fn default_style() -> Style<Self> {
+        default
+    }
+}
+
+impl DefaultStyle for Appearance {
+    fn default_style() -> Style<Self> {
+        |appearance| *appearance
+    }
+}
+
+/// The default style of a [`Modal`].
+pub fn default(_theme: &Theme) -> Appearance {
+    Appearance {
+        backdrop: Background::Color(Color {
+            a: 0.8,
+            ..Color::BLACK
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::cycle;
+
+    #[test]
+    fn focus_cycles_within_dialog() {
+        assert_eq!(cycle(None, 0, false), None);
+        assert_eq!(cycle(None, 3, false), Some(0));
+        assert_eq!(cycle(None, 3, true), Some(2));
+        assert_eq!(cycle(Some(0), 3, false), Some(1));
+        assert_eq!(cycle(Some(2), 3, false), Some(0));
+        assert_eq!(cycle(Some(0), 3, true), Some(2));
+    }
+}
@@ -0,0 +1,802 @@
+//! Enter numbers with validation, clamping, and step buttons.
+use std::ops::RangeInclusive;
+
+use crate::core::alignment;
+use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::text::{self, Text};
+use crate::core::touch;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::{self, operation, Operation};
+use crate::core::{
+    Background, Clipboard, Color, Element, Layout, Length, Padding, Pixels,
+    Rectangle, Shell, Size, Theme, Widget,
+};
+use crate::text_input::{self, TextInput};
+
+/// A field to enter a number, with buttons to increment and decrement it.
+///
+/// Only text that parses into a number within the range of the
+/// [`NumberInput`] produces messages. Any other text is kept while editing
+/// and marks the [`NumberInput`] as [`Status::Invalid`]. When editing ends,
+/// the last number typed is clamped to the range, or the text is discarded
+/// if it is not a number.
+///
+/// The number can also be stepped with the arrow keys while focused, and
+/// with the mouse wheel while hovered.
+///
+/// # Example
+/// ```no_run
+/// # type NumberInput<'a, T, Message> = iced_widget::NumberInput<'a, T, Message>;
+/// #
+/// #[derive(Clone)]
+/// pub enum Message {
+///     WeightChanged(f32),
+/// }
+///
+/// let weight = 2.5;
+///
+/// NumberInput::new(weight, Message::WeightChanged)
+///     .range(0.0..=100.0)
+///     .step(0.5)
+///     .format(|weight| format!("{weight:.1} kg"))
+///     .parse(|text| text.trim().trim_end_matches("kg").trim().parse().ok());
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct NumberInput<
+    'a,
+    T,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> where
+    Renderer: text::Renderer,
+{
+    id: Option<text_input::Id>,
+    value: T,
+    range: Option<RangeInclusive<T>>,
+    step: T,
+    on_change: Box<dyn Fn(T) -> Message + 'a>,
+    format: Box<dyn Fn(T) -> String + 'a>,
+    #[allow(clippy::type_complexity)]
+    parse: Box<dyn Fn(&str) -> Option<T> + 'a>,
+    width: Length,
+    padding: Padding,
+    size: Option<Pixels>,
+    font: Option<Renderer::Font>,
+    style: Style<Theme>,
+}
+
+impl<'a, T, Message, Theme, Renderer>
+    NumberInput<'a, T, Message, Theme, Renderer>
+where
+    T: Copy + From<u8> + PartialOrd,
+    Renderer: text::Renderer,
+{
+    /// Creates a new [`NumberInput`] with the given value and the message to
+    /// produce when it changes.
+    ///
+    /// By default, the number is formatted with its [`Display`] implementation
+    /// and parsed with its [`FromStr`] implementation.
+    ///
+    /// [`Display`]: std::fmt::Display
+    /// [`FromStr`]: std::str::FromStr
+    pub fn new<F>(value: T, on_change: F) -> Self
+    where
+        T: std::fmt::Display + std::str::FromStr,
+        F: 'a + Fn(T) -> Message,
+        Theme: DefaultStyle,
+    {
+        Self {
+            id: None,
+            value,
+            range: None,
+            step: T::from(1),
+            on_change: Box::new(on_change),
+            format: Box::new(|value| value.to_string()),
+            parse: Box::new(|text| text.trim().parse().ok()),
+            width: Length::Fill,
+            padding: text_input::DEFAULT_PADDING,
+            size: None,
+            font: None,
+            style: Theme::default_style(),
+        }
+    }
+
+    /// Sets the [`Id`] of the text input of the [`NumberInput`].
+    ///
+    /// [`Id`]: text_input::Id
+    pub fn id(mut self, id: text_input::Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Sets the range of the [`NumberInput`].
+    ///
+    /// Numbers out of the range are never produced.
+    pub fn range(mut self, range: RangeInclusive<T>) -> Self {
+        self.range = Some(range);
+        self
+    }
+
+    /// Sets the step size of the [`NumberInput`].
+    pub fn step(mut self, step: impl Into<T>) -> Self {
+        self.step = step.into();
+        self
+    }
+
+    /// Sets the function used to display the number of the [`NumberInput`];
+    /// for instance, to add units or thousands separators.
+    ///
+    /// It should be the inverse of the function set with
+    /// [`NumberInput::parse`].
+    pub fn format(mut self, format: impl Fn(T) -> String + 'a) -> Self {
+        self.format = Box::new(format);
+        self
+    }
+
+    /// Sets the function used to read a number from the text typed into the
+    /// [`NumberInput`]. It returns `None` if the text is not a valid number.
+    pub fn parse(mut self, parse: impl Fn(&str) -> Option<T> + 'a) -> Self {
+        self.parse = Box::new(parse);
+        self
+    }
+
+    /// Sets the width of the [`NumberInput`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the [`Padding`] of the text input of the [`NumberInput`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the [`NumberInput`].
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.size = Some(size.into());
+        self
+    }
+
+    /// Sets the font of the [`NumberInput`].
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the style of the [`NumberInput`].
+    pub fn style(mut self, style: fn(&Theme, Status) -> Appearance) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Builds the text input of the [`NumberInput`].
+    fn input(&self, state: &State) -> TextInput<'_, Input, Theme, Renderer> {
+        let value = state
+            .editing
+            .clone()
+            .unwrap_or_else(|| (self.format)(self.value));
+
+        // The text input is drawn with the appearance of the number input,
+        // so its own style is never used
+        let mut input = TextInput::with_style("", &value, |_, _| {
+            unreachable!("the text input of a number input is never styled")
+        })
+        .on_input(Input::Changed)
+        .on_submit(Input::Submitted)
+        .padding(self.padding);
+
+        if let Some(id) = &self.id {
+            input = input.id(id.clone());
+        }
+
+        if let Some(size) = self.size {
+            input = input.size(size);
+        }
+
+        if let Some(font) = self.font {
+            input = input.font(font);
+        }
+
+        input
+    }
+
+    /// Returns the number typed into the [`NumberInput`], if it is valid.
+    fn typed(&self, text: &str) -> Option<T> {
+        (self.parse)(text).filter(|value| {
+            self.range
+                .as_ref()
+                .is_none_or(|range| range.contains(value))
+        })
+    }
+
+    fn publish(&self, value: T, shell: &mut Shell<'_, Message>) {
+        if value != self.value {
+            shell.publish((self.on_change)(value));
+        }
+    }
+
+    /// Ends the current edit of the [`NumberInput`], if any.
+    fn commit(&self, state: &mut State, shell: &mut Shell<'_, Message>) {
+        let Some(text) = state.editing.take() else {
+            return;
+        };
+
+        if let Some(value) = (self.parse)(&text) {
+            let value = match &self.range {
+                Some(range) => clamp(value, range),
+                None => value,
+            };
+
+            self.publish(value, shell);
+        }
+
+        shell.invalidate_layout();
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer>
+    NumberInput<'a, T, Message, Theme, Renderer>
+where
+    T: Copy + From<u8> + PartialOrd + Into<f64> + num_traits::FromPrimitive,
+    Renderer: text::Renderer,
+{
+    /// Steps the number of the [`NumberInput`] the given amount of times.
+    fn step_by(
+        &self,
+        state: &mut State,
+        steps: f64,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let current = state
+            .editing
+            .take()
+            .and_then(|text| (self.parse)(&text))
+            .unwrap_or(self.value);
+
+        if let Some(value) =
+            offset(current, self.step, steps, self.range.as_ref())
+        {
+            self.publish(value, shell);
+        }
+
+        shell.invalidate_layout();
+    }
+}
+
+/// A message of the text input of a [`NumberInput`].
+#[derive(Debug, Clone)]
+enum Input {
+    Changed(String),
+    Submitted,
+}
+
+/// The local state of a [`NumberInput`].
+#[derive(Debug, Clone, Default)]
+struct State {
+    /// The text being typed, if it differs from the formatted value.
+    editing: Option<String>,
+}
+
+impl<'a, T, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for NumberInput<'a, T, Message, Theme, Renderer>
+where
+    T: Copy + From<u8> + PartialOrd + Into<f64> + num_traits::FromPrimitive,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(
+            &self.input(&State::default()) as &dyn Widget<_, _, _>
+        )]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_ref::<State>();
+        let input = self.input(state);
+
+        tree.diff_children(&[&input as &dyn Widget<_, _, _>]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let state = tree.state.downcast_ref::<State>();
+        let input = self.input(state);
+        let limits = limits.width(self.width);
+
+        let height = input
+            .layout(&mut tree.children[0], renderer, &limits, None)
+            .size()
+            .height;
+
+        // The step buttons are square
+        let button = Size::new(height, height);
+
+        let input = input.layout(
+            &mut tree.children[0],
+            renderer,
+            &limits.shrink(Size::new(button.width * 2.0, 0.0)),
+            None,
+        );
+
+        let width = input.size().width;
+
+        layout::Node::with_children(
+            Size::new(width + button.width * 2.0, height),
+            vec![
+                input,
+                layout::Node::new(button).move_to((width, 0.0)),
+                layout::Node::new(button).move_to((width + button.width, 0.0)),
+            ],
+        )
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let input = self.input(state);
+
+        input.operate(
+            &mut tree.children[0],
+            layout.children().next().unwrap(),
+            renderer,
+            &mut MapOperation { operation },
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let mut children = layout.children();
+        let input_layout = children.next().unwrap();
+        let decrement = children.next().unwrap().bounds();
+        let increment = children.next().unwrap().bounds();
+
+        let was_focused = is_focused::<Renderer>(&tree.children[0]);
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let state = tree.state.downcast_mut::<State>();
+
+                if cursor.is_over(decrement) {
+                    self.step_by(state, -1.0, shell);
+
+                    return event::Status::Captured;
+                } else if cursor.is_over(increment) {
+                    self.step_by(state, 1.0, shell);
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta })
+                if cursor.is_over(layout.bounds()) =>
+            {
+                let state = tree.state.downcast_mut::<State>();
+
+                let y = match delta {
+                    mouse::ScrollDelta::Lines { y, .. }
+                    | mouse::ScrollDelta::Pixels { y, .. } => y,
+                };
+
+                if y != 0.0 {
+                    self.step_by(state, y.signum().into(), shell);
+                }
+
+                return event::Status::Captured;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(named),
+                ..
+            }) if was_focused => {
+                let state = tree.state.downcast_mut::<State>();
+
+                match named {
+                    key::Named::ArrowUp => {
+                        self.step_by(state, 1.0, shell);
+
+                        return event::Status::Captured;
+                    }
+                    key::Named::ArrowDown => {
+                        self.step_by(state, -1.0, shell);
+
+                        return event::Status::Captured;
+                    }
+                    _ => {}
+                }
+            }
+            _ => {}
+        }
+
+        let mut messages = Vec::new();
+
+        let status = {
+            let state = tree.state.downcast_ref::<State>();
+            let mut input = self.input(state);
+            let mut local_shell = Shell::new(&mut messages);
+
+            let status = input.on_event(
+                &mut tree.children[0],
+                event,
+                input_layout,
+                cursor,
+                renderer,
+                clipboard,
+                &mut local_shell,
+                viewport,
+            );
+
+            local_shell.revalidate_layout(|| shell.invalidate_layout());
+
+            if let Some(redraw_request) = local_shell.redraw_request() {
+                shell.request_redraw(redraw_request);
+            }
+
+            status
+        };
+
+        let is_focused = is_focused::<Renderer>(&tree.children[0]);
+        let state = tree.state.downcast_mut::<State>();
+
+        for message in messages {
+            match message {
+                Input::Changed(text) => {
+                    if let Some(value) = self.typed(&text) {
+                        self.publish(value, shell);
+                    }
+
+                    state.editing = Some(text);
+                    shell.invalidate_layout();
+                }
+                Input::Submitted => {
+                    self.commit(state, shell);
+                }
+            }
+        }
+
+        if was_focused && !is_focused {
+            self.commit(state, shell);
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let mut children = layout.children();
+        let input_layout = children.next().unwrap();
+
+        if children.any(|button| cursor.is_over(button.bounds())) {
+            return mouse::Interaction::Pointer;
+        }
+
+        let state = tree.state.downcast_ref::<State>();
+
+        self.input(state).mouse_interaction(
+            &tree.children[0],
+            input_layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+
+        let mut children = layout.children();
+        let input_layout = children.next().unwrap();
+
+        let is_invalid = state
+            .editing
+            .as_ref()
+            .is_some_and(|text| self.typed(text).is_none());
+
+        let status = if is_invalid {
+            Status::Invalid
+        } else if is_focused::<Renderer>(&tree.children[0]) {
+            Status::Focused
+        } else if cursor.is_over(layout.bounds()) {
+            Status::Hovered
+        } else {
+            Status::Active
+        };
+
+        let appearance = (self.style)(theme, status);
+
+        self.input(state).draw_with_appearance(
+            &tree.children[0],
+            renderer,
+            input_layout,
+            None,
+            appearance.input,
+            viewport,
+        );
+
+        let size = self.size.unwrap_or_else(|| renderer.default_size());
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+
+        for (button, symbol) in children.zip(["-", "+"]) {
+            let bounds = button.bounds();
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border: appearance.input.border,
+                    ..renderer::Quad::default()
+                },
+                if cursor.is_over(bounds) {
+                    appearance.button_hovered
+                } else {
+                    appearance.button
+                },
+            );
+
+            renderer.fill_text(
+                Text {
+                    content: symbol,
+                    bounds: bounds.size(),
+                    size,
+                    line_height: text::LineHeight::default(),
+                    font,
+                    horizontal_alignment: alignment::Horizontal::Center,
+                    vertical_alignment: alignment::Vertical::Center,
+                    shaping: text::Shaping::Basic,
+                },
+                bounds.center(),
+                appearance.button_text,
+                *viewport,
+            );
+        }
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer>
+    From<NumberInput<'a, T, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    T: Copy
+        + From<u8>
+        + PartialOrd
+        + Into<f64>
+        + num_traits::FromPrimitive
+        + 'a,
+    Message: 'a,
+    Theme: 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(
+        number_input: NumberInput<'a, T, Message, Theme, Renderer>,
+    ) -> Self {
+        Self::new(number_input)
+    }
+}
+
+fn is_focused<Renderer: text::Renderer>(tree: &Tree) -> bool {
+    tree.state
+        .downcast_ref::<text_input::State<Renderer::Paragraph>>()
+        .is_focused()
+}
+
+fn clamp<T: Copy + PartialOrd>(value: T, range: &RangeInclusive<T>) -> T {
+    if value < *range.start() {
+        *range.start()
+    } else if value > *range.end() {
+        *range.end()
+    } else {
+        value
+    }
+}
+
+/// Offsets the value by the given amount of steps, clamping it to the range.
+///
+/// Returns `None` if the result cannot be represented by `T`.
+fn offset<T>(
+    value: T,
+    step: T,
+    steps: f64,
+    range: Option<&RangeInclusive<T>>,
+) -> Option<T>
+where
+    T: Copy + Into<f64> + num_traits::FromPrimitive,
+{
+    let mut value = value.into() + step.into() * steps;
+
+    if let Some(range) = range {
+        value = value.clamp((*range.start()).into(), (*range.end()).into());
+    }
+
+    T::from_f64(value)
+}
+
+/// An [`Operation`] on the text input of a [`NumberInput`], which produces
+/// a different type of message.
+struct MapOperation<'a, B> {
+    operation: &'a mut dyn Operation<B>,
+}
+
+impl<'a, T, B> Operation<T> for MapOperation<'a, B> {
+    fn container(
+        &mut self,
+        id: Option<&widget::Id>,
+        bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        self.operation.container(id, bounds, &mut |operation| {
+            operate_on_children(&mut MapOperation { operation });
+        });
+    }
+
+    fn focusable(
+        &mut self,
+        state: &mut dyn operation::Focusable,
+        id: Option<&widget::Id>,
+    ) {
+        self.operation.focusable(state, id);
+    }
+
+    fn text_input(
+        &mut self,
+        state: &mut dyn operation::TextInput,
+        id: Option<&widget::Id>,
+    ) {
+        self.operation.text_input(state, id);
+    }
+
+    fn custom(
+        &mut self,
+        state: &mut dyn std::any::Any,
+        id: Option<&widget::Id>,
+    ) {
+        self.operation.custom(state, id);
+    }
+
+    fn bounds(&mut self, id: Option<&widget::Id>, bounds: Rectangle) {
+        self.operation.bounds(id, bounds);
+    }
+}
+
+/// The possible status of a [`NumberInput`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The [`NumberInput`] can be interacted with.
+    Active,
+    /// The [`NumberInput`] is being hovered.
+    Hovered,
+    /// The [`NumberInput`] is focused.
+    Focused,
+    /// The text of the [`NumberInput`] is not a number within its range.
+    Invalid,
+}
+
+/// The appearance of a number input.
+#[derive(Debug, Clone, Copy)]
+pub struct Appearance {
+    /// The [`Appearance`] of the text input of the number input.
+    ///
+    /// [`Appearance`]: text_input::Appearance
+    pub input: text_input::Appearance,
+    /// The [`Background`] of the step buttons.
+    pub button: Background,
+    /// The [`Background`] of a hovered step button.
+    pub button_hovered: Background,
+    /// The [`Color`] of the symbols of the step buttons.
+    pub button_text: Color,
+}
+
+/// The style of a [`NumberInput`].
+pub type Style<Theme> = fn(&Theme, Status) -> Appearance;
+
+/// The default style of a [`NumberInput`].
+pub trait DefaultStyle {
+    /// Returns the default style of a [`NumberInput`].
+    fn default_style() -> Style<Self>;
+}
+
+impl DefaultStyle for Theme {
+    fn default_style() -> Style<Self> {
+        default
+    }
+}
+
+impl DefaultStyle for Appearance {
+    fn default_style() -> Style<Self> {
+        |appearance, _status| *appearance
+    }
+}
+
+/// The default style of a [`NumberInput`].
+pub fn default(theme: &Theme, status: Status) -> Appearance {
+    let palette = theme.extended_palette();
+
+    let input = text_input::default(
+        theme,
+        match status {
+            Status::Active => text_input::Status::Active,
+            Status::Hovered => text_input::Status::Hovered,
+            Status::Focused => text_input::Status::Focused,
+            // Editing, and thus invalid text, only lasts while focused
+            Status::Invalid => text_input::Status::Invalid { is_focused: true },
+        },
+    );
+
+    Appearance {
+        input,
+        button: Background::Color(palette.background.weak.color),
+        button_hovered: Background::Color(palette.background.strong.color),
+        button_text: palette.background.weak.text,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn steps_are_clamped() {
+        let range = 0.0..=1.0;
+
+        assert_eq!(offset(0.5, 0.25, 1.0, Some(&range)), Some(0.75));
+        assert_eq!(offset(0.5, 0.25, 4.0, Some(&range)), Some(1.0));
+        assert_eq!(offset(0.5, 0.25, -4.0, Some(&range)), Some(0.0));
+    }
+
+    #[test]
+    fn steps_out_of_bounds_are_ignored() {
+        assert_eq!(offset(0u8, 1, -1.0, None), None);
+        assert_eq!(offset(254u8, 1, 1.0, None), Some(255));
+        assert_eq!(offset(255u8, 1, 1.0, None), None);
+    }
+}
@@ -31,6 +31,7 @@ pub use state::State;
 pub use title_bar::TitleBar;
 
 use crate::core::event::{self, Event};
+use crate::core::keyboard;
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::overlay::{self, Group};
@@ -43,6 +44,8 @@ use crate::core::{
     Point, Rectangle, Shell, Size, Theme, Vector, Widget,
 };
 
+use std::collections::{BTreeMap, BTreeSet};
+
 const DRAG_DEADBAND_DISTANCE: f32 = 10.0;
 const THICKNESS_RATIO: f32 = 25.0;
 
@@ -58,9 +61,11 @@ const THICKNESS_RATIO: f32 = 25.0;
 /// A [`PaneGrid`] supports:
 ///
 /// * Vertical and horizontal splits
-/// * Tracking of the last active pane
-/// * Mouse-based resizing
+/// * Tracking of the focused pane, with directional focus
+/// * Mouse-based and keyboard-based resizing
+/// * Minimum sizes for each pane
 /// * Drag and drop to reorganize panes
+/// * Tab groups, using the [`TitleBar`] of each pane as its tab
 /// * Hotkey support
 /// * Configurable modifier keys
 /// * [`State`] API to perform actions programmatically (`split`, `swap`, `resize`, etc.)
@@ -110,6 +115,8 @@ pub struct PaneGrid<
     on_click: Option<Box<dyn Fn(Pane) -> Message + 'a>>,
     on_drag: Option<Box<dyn Fn(DragEvent) -> Message + 'a>>,
     on_resize: Option<(f32, Box<dyn Fn(ResizeEvent) -> Message + 'a>)>,
+    keyboard_resize: Option<(keyboard::Modifiers, f32)>,
+    focused: Option<Pane>,
     style: Style<Theme>,
 }
 
@@ -158,6 +165,8 @@ where
             on_click: None,
             on_drag: None,
             on_resize: None,
+            keyboard_resize: None,
+            focused: state.focused,
             style: Theme::default_style(),
         }
     }
@@ -217,6 +226,21 @@ where
         self
     }
 
+    /// Enables resizing the splits of the focused [`Pane`] of the
+    /// [`PaneGrid`] with the arrow keys while the given modifiers are pressed.
+    ///
+    /// Each key press moves the closest [`Split`] of the focused [`Pane`] in
+    /// the direction of the arrow by the given amount of pixels, producing
+    /// the messages of [`PaneGrid::on_resize`].
+    pub fn keyboard_resize(
+        mut self,
+        modifiers: keyboard::Modifiers,
+        step: impl Into<Pixels>,
+    ) -> Self {
+        self.keyboard_resize = Some((modifiers, step.into().0));
+        self
+    }
+
     /// Sets the style of the [`PaneGrid`].
     pub fn style(mut self, style: fn(&Theme) -> Appearance) -> Self {
         self.style = style;
@@ -228,6 +252,111 @@ where
             .then(|| self.on_drag.is_some())
             .unwrap_or_default()
     }
+
+    /// Returns the [`ResizeEvent`] that moves the closest [`Split`] of the
+    /// given [`Pane`] in the given [`Direction`] by `step` pixels.
+    fn keyboard_resize_event(
+        &self,
+        size: Size,
+        pane: Pane,
+        direction: Direction,
+        step: f32,
+    ) -> Option<ResizeEvent> {
+        let (axis, step) = match direction {
+            Direction::Up => (Axis::Horizontal, -step),
+            Direction::Down => (Axis::Horizontal, step),
+            Direction::Left => (Axis::Vertical, -step),
+            Direction::Right => (Axis::Vertical, step),
+        };
+
+        let split = self.contents.layout().closest_split(pane, axis)?;
+        let splits = self.contents.split_regions(self.spacing, size);
+        let (_, region, ratio) = splits.get(&split)?;
+
+        let length = match axis {
+            Axis::Horizontal => region.height,
+            Axis::Vertical => region.width,
+        };
+
+        if length <= 0.0 {
+            return None;
+        }
+
+        let ratio = self.contents.clamp_ratio(
+            split,
+            (ratio + step / length).clamp(0.1, 0.9),
+            self.spacing,
+            size,
+        );
+
+        Some(ResizeEvent { split, ratio })
+    }
+
+    /// Returns the [`Target`] where the given [`Pane`] would be dropped at
+    /// the given cursor position, alongside the bounds to highlight.
+    fn drop_target(
+        &self,
+        layout: Layout<'_>,
+        pane: Pane,
+        cursor_position: Point,
+        hidden: &BTreeSet<Pane>,
+    ) -> Option<(Target, Rectangle)> {
+        if let Some(edge) = in_edge(layout, cursor_position) {
+            return Some((Target::Edge(edge), edge_bounds(layout, edge)));
+        }
+
+        let mut hovered = None;
+
+        for ((target, content), layout) in
+            self.contents.iter().zip(layout.children())
+        {
+            let bounds = layout.bounds();
+
+            if !bounds.contains(cursor_position) {
+                continue;
+            }
+
+            // The tabs of a group are on top of its active pane
+            if hidden.contains(&target) {
+                return (target != pane)
+                    .then_some((Target::Pane(target, Region::Tabs), bounds));
+            }
+
+            hovered = Some((target, content, layout));
+        }
+
+        let (target, content, layout) = hovered?;
+
+        let (region, bounds) = match content
+            .title_bar_bounds(layout)
+            .filter(|bounds| bounds.contains(cursor_position))
+        {
+            Some(bounds) => (Region::Tabs, bounds),
+            None => {
+                let region = layout_region(layout, cursor_position)?;
+
+                (region, layout_region_bounds(layout, region))
+            }
+        };
+
+        if target != pane {
+            return Some((Target::Pane(target, region), bounds));
+        }
+
+        // The active pane of a group can be split from the rest of its tabs
+        let Region::Edge(_) = region else {
+            return None;
+        };
+
+        let sibling = self
+            .contents
+            .layout()
+            .tabs()
+            .find(|(tabs, _)| tabs.contains(&pane))
+            .and_then(|(tabs, _)| tabs.iter().find(|tab| **tab != pane))?;
+
+        Some((Target::Pane(*sibling, region), bounds))
+    }
 }
 
 impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
@@ -280,13 +409,63 @@ where
     ) -> layout::Node {
         let size = limits.resolve(self.width, self.height, Size::ZERO);
         let node = self.contents.layout();
-        let regions = node.pane_regions(self.spacing, size);
+        let regions = self.contents.pane_regions(self.spacing, size);
+
+        // The width and index of the tab, and the active pane of the group of
+        // each pane in a tab group
+        let groups: BTreeMap<Pane, (f32, usize, Pane)> =
+            node.tabs()
+                .filter_map(|(tabs, active)| {
+                    let region = *regions.get(&active)?;
+                    let width = region.width / tabs.len() as f32;
+
+                    Some(tabs.iter().enumerate().map(move |(index, tab)| {
+                        (*tab, (width, index, active))
+                    }))
+                })
+                .flatten()
+                .collect();
+
+        // The tabs of a group are as tall as its tallest title bar
+        let mut tab_heights = BTreeMap::<Pane, f32>::new();
+
+        for ((pane, content), tree) in
+            self.contents.iter().zip(tree.children.iter_mut())
+        {
+            if let Some((width, _, active)) = groups.get(&pane) {
+                let height = content.tab_height(tree, renderer, *width);
+                let max = tab_heights.entry(*active).or_default();
+
+                *max = max.max(height);
+            }
+        }
 
         let children = self
             .contents
             .iter()
             .zip(tree.children.iter_mut())
             .filter_map(|((pane, content), tree)| {
+                if let Some((width, index, active)) = groups.get(&pane) {
+                    let region = regions.get(active)?;
+
+                    let node = content.layout_tab(
+                        tree,
+                        renderer,
+                        region.size(),
+                        Rectangle {
+                            x: *index as f32 * width,
+                            y: 0.0,
+                            width: *width,
+                            height: tab_heights[active],
+                        },
+                        pane == *active,
+                    );
+
+                    return Some(
+                        node.translate(Vector::new(region.x, region.y)),
+                    );
+                }
+
                 let region = regions.get(&pane)?;
                 let size = Size::new(region.width, region.height);
 
@@ -310,11 +489,14 @@ where
         renderer: &Renderer,
         operation: &mut dyn widget::Operation<Message>,
     ) {
+        let hidden = hidden_tabs(self.contents.layout());
+
         operation.container(None, layout.bounds(), &mut |operation| {
             self.contents
                 .iter()
                 .zip(&mut tree.children)
                 .zip(layout.children())
+                .filter(|(((pane, _), _), _)| !hidden.contains(pane))
                 .for_each(|(((_pane, content), state), layout)| {
                     content.operate(state, layout, renderer, operation);
                 });
@@ -336,6 +518,7 @@ where
 
         let action = tree.state.downcast_mut::<state::Action>();
         let node = self.contents.layout();
+        let hidden = hidden_tabs(node);
 
         let on_drag = if self.drag_enabled() {
             &self.on_drag
@@ -358,7 +541,7 @@ where
                                 cursor_position.y - bounds.y,
                             );
 
-                            let splits = node.split_regions(
+                            let splits = self.contents.split_regions(
                                 self.spacing,
                                 Size::new(bounds.width, bounds.height),
                             );
@@ -381,6 +564,7 @@ where
                                     cursor_position,
                                     shell,
                                     self.contents.iter(),
+                                    &hidden,
                                     &self.on_click,
                                     on_drag,
                                 );
@@ -393,6 +577,7 @@ where
                                 cursor_position,
                                 shell,
                                 self.contents.iter(),
+                                &hidden,
                                 &self.on_click,
                                 on_drag,
                             );
@@ -409,39 +594,16 @@ where
                             if cursor_position.distance(origin)
                                 > DRAG_DEADBAND_DISTANCE
                             {
-                                let event = if let Some(edge) =
-                                    in_edge(layout, cursor_position)
-                                {
-                                    DragEvent::Dropped {
-                                        pane,
-                                        target: Target::Edge(edge),
-                                    }
-                                } else {
-                                    let dropped_region = self
-                                        .contents
-                                        .iter()
-                                        .zip(layout.children())
-                                        .find_map(|(target, layout)| {
-                                            layout_region(
-                                                layout,
-                                                cursor_position,
-                                            )
-                                            .map(|region| (target, region))
-                                        });
-
-                                    match dropped_region {
-                                        Some(((target, _), region))
-                                            if pane != target =>
-                                        {
-                                            DragEvent::Dropped {
-                                                pane,
-                                                target: Target::Pane(
-                                                    target, region,
-                                                ),
-                                            }
-                                        }
-                                        _ => DragEvent::Canceled { pane },
+                                let event = match self.drop_target(
+                                    layout,
+                                    pane,
+                                    cursor_position,
+                                    &hidden,
+                                ) {
+                                    Some((target, _)) => {
+                                        DragEvent::Dropped { pane, target }
                                     }
+                                    None => DragEvent::Canceled { pane },
                                 };
 
                                 shell.publish(on_drag(event));
@@ -462,7 +624,7 @@ where
                     if let Some((split, _)) = action.picked_split() {
                         let bounds = layout.bounds();
 
-                        let splits = node.split_regions(
+                        let splits = self.contents.split_regions(
                             self.spacing,
                             Size::new(bounds.width, bounds.height),
                         );
@@ -488,6 +650,13 @@ where
                                     }
                                 };
 
+                                let ratio = self.contents.clamp_ratio(
+                                    split,
+                                    ratio,
+                                    self.spacing,
+                                    bounds.size(),
+                                );
+
                                 shell.publish(on_resize(ResizeEvent {
                                     split,
                                     ratio,
@@ -499,6 +668,44 @@ where
                     }
                 }
             }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key),
+                modifiers,
+                ..
+            }) => {
+                let direction = match key {
+                    keyboard::key::Named::ArrowUp => Some(Direction::Up),
+                    keyboard::key::Named::ArrowDown => Some(Direction::Down),
+                    keyboard::key::Named::ArrowLeft => Some(Direction::Left),
+                    keyboard::key::Named::ArrowRight => Some(Direction::Right),
+                    _ => None,
+                };
+
+                if let (
+                    Some(direction),
+                    Some((_, on_resize)),
+                    Some((resize_modifiers, step)),
+                    Some(focused),
+                ) = (
+                    direction,
+                    &self.on_resize,
+                    self.keyboard_resize,
+                    self.focused,
+                ) {
+                    if modifiers == resize_modifiers {
+                        if let Some(event) = self.keyboard_resize_event(
+                            layout.bounds().size(),
+                            focused,
+                            direction,
+                            step,
+                        ) {
+                            shell.publish(on_resize(event));
+
+                            event_status = event::Status::Captured;
+                        }
+                    }
+                }
+            }
             _ => {}
         }
 
@@ -509,6 +716,19 @@ where
             .zip(&mut tree.children)
             .zip(layout.children())
             .map(|(((pane, content), tree), layout)| {
+                if hidden.contains(&pane) {
+                    return content.on_event_tab(
+                        tree,
+                        event.clone(),
+                        layout,
+                        cursor,
+                        renderer,
+                        clipboard,
+                        shell,
+                        viewport,
+                    );
+                }
+
                 let is_picked = picked_pane == Some(pane);
 
                 content.on_event(
@@ -549,8 +769,9 @@ where
                     let cursor_position = cursor.position()?;
                     let bounds = layout.bounds();
 
-                    let splits =
-                        node.split_regions(self.spacing, bounds.size());
+                    let splits = self
+                        .contents
+                        .split_regions(self.spacing, bounds.size());
 
                     let relative_cursor = Point::new(
                         cursor_position.x - bounds.x,
@@ -573,19 +794,32 @@ where
             };
         }
 
+        let hidden = hidden_tabs(node);
+
         self.contents
             .iter()
             .zip(&tree.children)
             .zip(layout.children())
-            .map(|(((_pane, content), tree), layout)| {
-                content.mouse_interaction(
-                    tree,
-                    layout,
-                    cursor,
-                    viewport,
-                    renderer,
-                    self.drag_enabled(),
-                )
+            .map(|(((pane, content), tree), layout)| {
+                if hidden.contains(&pane) {
+                    content.mouse_interaction_tab(
+                        tree,
+                        layout,
+                        cursor,
+                        viewport,
+                        renderer,
+                        self.drag_enabled(),
+                    )
+                } else {
+                    content.mouse_interaction(
+                        tree,
+                        layout,
+                        cursor,
+                        viewport,
+                        renderer,
+                        self.drag_enabled(),
+                    )
+                }
             })
             .max()
             .unwrap_or_default()
@@ -605,12 +839,6 @@ where
         let node = self.contents.layout();
         let resize_leeway = self.on_resize.as_ref().map(|(leeway, _)| *leeway);
 
-        let contents = self
-            .contents
-            .iter()
-            .zip(&tree.children)
-            .map(|((pane, content), tree)| (pane, (content, tree)));
-
         let picked_pane = action.picked_pane().filter(|(_, origin)| {
             cursor
                 .position()
@@ -624,7 +852,8 @@ where
             .and_then(|(split, axis)| {
                 let bounds = layout.bounds();
 
-                let splits = node.split_regions(self.spacing, bounds.size());
+                let splits =
+                    self.contents.split_regions(self.spacing, bounds.size());
 
                 let (_axis, region, ratio) = splits.get(&split)?;
 
@@ -643,8 +872,9 @@ where
                         cursor_position.y - bounds.y,
                     );
 
-                    let splits =
-                        node.split_regions(self.spacing, bounds.size());
+                    let splits = self
+                        .contents
+                        .split_regions(self.spacing, bounds.size());
 
                     let (_split, axis, region) = hovered_split(
                         splits.iter(),
@@ -667,74 +897,96 @@ where
             cursor
         };
 
-        let mut render_picked_pane = None;
-
-        let pane_in_edge = if picked_pane.is_some() {
-            cursor
-                .position()
-                .and_then(|cursor_position| in_edge(layout, cursor_position))
-        } else {
-            None
-        };
-
         let appearance = (self.style)(theme);
+        let hidden = hidden_tabs(node);
+
+        let draw_pane = |renderer: &mut Renderer,
+                         content: &Content<'a, Message, Theme, Renderer>,
+                         tree: &Tree,
+                         layout: Layout<'_>,
+                         is_tab: bool| {
+            if is_tab {
+                content.draw_tab(
+                    tree,
+                    renderer,
+                    theme,
+                    style,
+                    layout,
+                    pane_cursor,
+                    viewport,
+                );
+            } else {
+                content.draw(
+                    tree,
+                    renderer,
+                    theme,
+                    style,
+                    layout,
+                    pane_cursor,
+                    viewport,
+                );
+            }
+        };
 
-        for ((id, (content, tree)), pane_layout) in
-            contents.zip(layout.children())
-        {
-            match picked_pane {
-                Some((dragging, origin)) if id == dragging => {
-                    render_picked_pane =
-                        Some(((content, tree), origin, pane_layout));
-                }
-                Some((dragging, _)) if id != dragging => {
-                    content.draw(
-                        tree,
-                        renderer,
-                        theme,
-                        style,
-                        pane_layout,
-                        pane_cursor,
-                        viewport,
-                    );
+        let mut render_picked_pane = None;
 
-                    if picked_pane.is_some() && pane_in_edge.is_none() {
-                        if let Some(region) =
-                            cursor.position().and_then(|cursor_position| {
-                                layout_region(pane_layout, cursor_position)
-                            })
-                        {
-                            let bounds =
-                                layout_region_bounds(pane_layout, region);
-
-                            renderer.fill_quad(
-                                renderer::Quad {
-                                    bounds,
-                                    border: appearance.hovered_region.border,
-                                    ..renderer::Quad::default()
-                                },
-                                appearance.hovered_region.background,
-                            );
-                        }
+        // The tabs of a group are drawn on top of its active pane
+        for is_tab in [false, true] {
+            for (((pane, content), tree), pane_layout) in self
+                .contents
+                .iter()
+                .zip(&tree.children)
+                .zip(layout.children())
+                .filter(|(((pane, _), _), _)| hidden.contains(pane) == is_tab)
+            {
+                match picked_pane {
+                    Some((dragging, origin)) if pane == dragging => {
+                        render_picked_pane = Some((
+                            (content, tree),
+                            origin,
+                            pane_layout,
+                            is_tab,
+                        ));
+                    }
+                    _ => {
+                        draw_pane(renderer, content, tree, pane_layout, is_tab);
                     }
-                }
-                _ => {
-                    content.draw(
-                        tree,
-                        renderer,
-                        theme,
-                        style,
-                        pane_layout,
-                        pane_cursor,
-                        viewport,
-                    );
                 }
             }
         }
 
-        if let Some(edge) = pane_in_edge {
-            let bounds = edge_bounds(layout, edge);
+        for (_, active) in node.tabs() {
+            let Some(bounds) = self
+                .contents
+                .iter()
+                .zip(layout.children())
+                .find(|((pane, _), _)| *pane == active)
+                .and_then(|((_, content), layout)| {
+                    content.title_bar_bounds(layout)
+                })
+            else {
+                continue;
+            };
 
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        y: bounds.y + bounds.height
+                            - appearance.active_tab.width,
+                        height: appearance.active_tab.width,
+                        ..bounds
+                    },
+                    ..renderer::Quad::default()
+                },
+                appearance.active_tab.color,
+            );
+        }
+
+        if let Some((_, bounds)) = picked_pane.zip(cursor.position()).and_then(
+            |((dragging, _), cursor_position)| {
+                self.drop_target(layout, dragging, cursor_position, &hidden)
+            },
+        ) {
             renderer.fill_quad(
                 renderer::Quad {
                     bounds,
@@ -746,7 +998,9 @@ where
         }
 
         // Render picked pane last
-        if let Some(((content, tree), origin, layout)) = render_picked_pane {
+        if let Some(((content, tree), origin, layout, is_tab)) =
+            render_picked_pane
+        {
             if let Some(cursor_position) = cursor.position() {
                 let bounds = layout.bounds();
 
@@ -755,15 +1009,7 @@ where
 
                 renderer.with_translation(translation, |renderer| {
                     renderer.with_layer(bounds, |renderer| {
-                        content.draw(
-                            tree,
-                            renderer,
-                            theme,
-                            style,
-                            layout,
-                            pane_cursor,
-                            viewport,
-                        );
+                        draw_pane(renderer, content, tree, layout, is_tab);
                     });
                 });
             }
@@ -814,11 +1060,14 @@ where
         renderer: &Renderer,
         translation: Vector,
     ) -> Option<overlay::Element<'_, Message, Theme, Renderer>> {
+        let hidden = hidden_tabs(self.contents.layout());
+
         let children = self
             .contents
             .iter_mut()
             .zip(&mut tree.children)
             .zip(layout.children())
+            .filter(|(((pane, _), _), _)| !hidden.contains(pane))
             .filter_map(|(((_, content), state), layout)| {
                 content.overlay(state, layout, renderer, translation)
             })
@@ -870,16 +1119,19 @@ fn click_pane<'a, Message, T>(
     cursor_position: Point,
     shell: &mut Shell<'_, Message>,
     contents: impl Iterator<Item = (Pane, T)>,
+    hidden: &BTreeSet<Pane>,
     on_click: &Option<Box<dyn Fn(Pane) -> Message + 'a>>,
     on_drag: &Option<Box<dyn Fn(DragEvent) -> Message + 'a>>,
 ) where
     T: Draggable,
 {
-    let mut clicked_region = contents
+    // The tabs of a group are on top of its active pane
+    let clicked_region = contents
         .zip(layout.children())
-        .filter(|(_, layout)| layout.bounds().contains(cursor_position));
+        .filter(|(_, layout)| layout.bounds().contains(cursor_position))
+        .max_by_key(|((pane, _), _)| hidden.contains(pane));
 
-    if let Some(((pane, content), layout)) = clicked_region.next() {
+    if let Some(((pane, content), layout)) = clicked_region {
         if let Some(on_click) = &on_click {
             shell.publish(on_click(pane));
         }
@@ -954,7 +1206,7 @@ fn layout_region_bounds(layout: Layout<'_>, region: Region) -> Rectangle {
     let bounds = layout.bounds();
 
     match region {
-        Region::Center => bounds,
+        Region::Center | Region::Tabs => bounds,
         Region::Edge(edge) => match edge {
             Edge::Top => Rectangle {
                 height: bounds.height / 2.0,
@@ -1021,6 +1273,10 @@ pub enum Region {
     Center,
     /// Edge region.
     Edge(Edge),
+    /// Tab region, where panes are stacked as tabs.
+    ///
+    /// This is the title bar of a [`Pane`] or the tabs of a group.
+    Tabs,
 }
 
 /// The edges of an area.
@@ -1068,6 +1324,15 @@ fn hovered_split<'a>(
     })
 }
 
+/// Returns the panes of the tab groups in the [`Node`] that are not active.
+fn hidden_tabs(node: &Node) -> BTreeSet<Pane> {
+    node.tabs()
+        .flat_map(|(tabs, active)| {
+            tabs.iter().copied().filter(move |tab| *tab != active)
+        })
+        .collect()
+}
+
 /// The visible contents of the [`PaneGrid`]
 #[derive(Debug)]
 pub enum Contents<'a, T> {
@@ -1086,6 +1351,51 @@ impl<'a, T> Contents<'a, T> {
         }
     }
 
+    /// Returns the rectangular region for each visible [`Pane`] of the
+    /// [`Contents`], respecting the minimum size of each [`Pane`].
+    pub fn pane_regions(
+        &self,
+        spacing: f32,
+        size: Size,
+    ) -> BTreeMap<Pane, Rectangle> {
+        match self {
+            Contents::All(_, state) => state.pane_regions(spacing, size),
+            Contents::Maximized(_, _, layout) => {
+                layout.pane_regions(spacing, size)
+            }
+        }
+    }
+
+    /// Returns the axis, rectangular region, and ratio for each [`Split`] of
+    /// the [`Contents`], respecting the minimum size of each [`Pane`].
+    pub fn split_regions(
+        &self,
+        spacing: f32,
+        size: Size,
+    ) -> BTreeMap<Split, (Axis, Rectangle, f32)> {
+        match self {
+            Contents::All(_, state) => state.split_regions(spacing, size),
+            Contents::Maximized(_, _, layout) => {
+                layout.split_regions(spacing, size)
+            }
+        }
+    }
+
+    fn clamp_ratio(
+        &self,
+        split: Split,
+        ratio: f32,
+        spacing: f32,
+        size: Size,
+    ) -> f32 {
+        match self {
+            Contents::All(_, state) => {
+                state.clamp_ratio(split, ratio, spacing, size)
+            }
+            Contents::Maximized(..) => ratio,
+        }
+    }
+
     /// Returns an iterator over the values of the [`Contents`]
     pub fn iter(&self) -> Box<dyn Iterator<Item = (Pane, &T)> + '_> {
         match self {
@@ -1123,6 +1433,8 @@ pub struct Appearance {
     pub picked_split: Line,
     /// The appearance of a hovered split.
     pub hovered_split: Line,
+    /// The appearance of the line below the active tab of a group.
+    pub active_tab: Line,
 }
 
 /// The appearance of a highlight of the [`PaneGrid`].
@@ -1190,5 +1502,9 @@ pub fn default(theme: &Theme) -> Appearance {
             color: palette.primary.strong.color,
             width: 2.0,
         },
+        active_tab: Line {
+            color: palette.primary.strong.color,
+            width: 2.0,
+        },
     }
 }
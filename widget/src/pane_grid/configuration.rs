@@ -2,8 +2,18 @@ use crate::pane_grid::Axis;
 
 /// The arrangement of a [`PaneGrid`].
 ///
+/// A [`Configuration`] can be obtained from an existing [`State`] with
+/// [`State::configuration`] and turned back into an equivalent [`State`] with
+/// [`State::with_configuration`], which makes it suitable for persisting the
+/// layout of a [`PaneGrid`] between sessions.
+///
 /// [`PaneGrid`]: super::PaneGrid
-#[derive(Debug, Clone)]
+/// [`State`]: super::State
+/// [`State::configuration`]: super::State::configuration
+/// [`State::with_configuration`]: super::State::with_configuration
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "serde"))]
 pub enum Configuration<T> {
     /// A split of the available space.
     Split {
@@ -23,4 +33,38 @@ pub enum Configuration<T> {
     ///
     /// [`Pane`]: super::Pane
     Pane(T),
+    /// A group of panes shown as tabs.
+    Tabs {
+        /// The panes of the group, in the order of their tabs.
+        ///
+        /// A tab group must contain at least one pane. Empty groups are
+        /// dropped when restoring a [`State`], and fail to deserialize.
+        ///
+        /// [`State`]: super::State
+        #[cfg_attr(
+            feature = "serde",
+            serde(deserialize_with = "deserialize_tabs")
+        )]
+        tabs: Vec<T>,
+
+        /// The index of the active pane of the group.
+        active: usize,
+    },
+}
+
+#[cfg(feature = "serde")]
+fn deserialize_tabs<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: serde::Deserialize<'de>,
+{
+    use serde::Deserialize;
+
+    let tabs = Vec::deserialize(deserializer)?;
+
+    if tabs.is_empty() {
+        return Err(serde::de::Error::invalid_length(0, &"at least one pane"));
+    }
+
+    Ok(tabs)
 }
@@ -205,6 +205,200 @@ where
         }
     }
 
+    /// Returns the height of the tab of the [`Content`] with the given width.
+    pub(super) fn tab_height(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        width: f32,
+    ) -> f32 {
+        self.title_bar.as_ref().map_or(0.0, |title_bar| {
+            title_bar
+                .layout(
+                    &mut tree.children[1],
+                    renderer,
+                    &layout::Limits::new(
+                        Size::ZERO,
+                        Size::new(width, f32::INFINITY),
+                    ),
+                )
+                .size()
+                .height
+        })
+    }
+
+    /// Lays out the [`Content`] as part of a tab group with the given size.
+    ///
+    /// The [`TitleBar`] is placed in the given `tab` bounds. The body is only
+    /// laid out if the tab is active; otherwise, only the tab is laid out.
+    pub(super) fn layout_tab(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        size: Size,
+        tab: Rectangle,
+        is_active: bool,
+    ) -> layout::Node {
+        let title_bar = self.title_bar.as_ref().map(|title_bar| {
+            title_bar.layout(
+                &mut tree.children[1],
+                renderer,
+                &layout::Limits::new(Size::ZERO, tab.size()),
+            )
+        });
+
+        if !is_active {
+            return layout::Node::with_children(
+                tab.size(),
+                title_bar.into_iter().collect(),
+            )
+            .move_to(tab.position());
+        }
+
+        let body = self
+            .body
+            .as_widget()
+            .layout(
+                &mut tree.children[0],
+                renderer,
+                &layout::Limits::new(
+                    Size::ZERO,
+                    Size::new(size.width, size.height - tab.height),
+                ),
+            )
+            .move_to(Point::new(0.0, tab.height));
+
+        match title_bar {
+            Some(title_bar) => layout::Node::with_children(
+                size,
+                vec![title_bar.move_to(tab.position()), body],
+            ),
+            None => body,
+        }
+    }
+
+    /// Draws the tab of an inactive [`Content`] of a tab group.
+    pub(super) fn draw_tab(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+
+        {
+            let style = {
+                let status = if cursor.is_over(bounds) {
+                    container::Status::Hovered
+                } else {
+                    container::Status::Idle
+                };
+
+                (self.style)(theme, status)
+            };
+
+            container::draw_background(renderer, &style, bounds);
+        }
+
+        if let Some((title_bar, title_bar_layout)) =
+            self.title_bar.as_ref().zip(layout.children().next())
+        {
+            title_bar.draw(
+                &tree.children[1],
+                renderer,
+                theme,
+                style,
+                title_bar_layout,
+                cursor,
+                viewport,
+                cursor.is_over(bounds),
+            );
+        }
+    }
+
+    /// Processes an event for the tab of an inactive [`Content`] of a tab
+    /// group.
+    pub(super) fn on_event_tab(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        match self.title_bar.as_mut().zip(layout.children().next()) {
+            Some((title_bar, title_bar_layout)) => title_bar.on_event(
+                &mut tree.children[1],
+                event,
+                title_bar_layout,
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            ),
+            None => event::Status::Ignored,
+        }
+    }
+
+    /// Returns the [`mouse::Interaction`] of the tab of an inactive
+    /// [`Content`] of a tab group.
+    pub(super) fn mouse_interaction_tab(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+        drag_enabled: bool,
+    ) -> mouse::Interaction {
+        let Some((title_bar, title_bar_layout)) =
+            self.title_bar.as_ref().zip(layout.children().next())
+        else {
+            return if cursor.is_over(layout.bounds()) {
+                mouse::Interaction::Pointer
+            } else {
+                mouse::Interaction::default()
+            };
+        };
+
+        let is_over_pick_area = cursor
+            .position()
+            .map(|cursor_position| {
+                title_bar.is_over_pick_area(title_bar_layout, cursor_position)
+            })
+            .unwrap_or_default();
+
+        if is_over_pick_area && drag_enabled {
+            return mouse::Interaction::Grab;
+        }
+
+        title_bar.mouse_interaction(
+            &tree.children[1],
+            title_bar_layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    /// Returns the bounds of the [`TitleBar`] of the [`Content`], if any.
+    pub(super) fn title_bar_bounds(
+        &self,
+        layout: Layout<'_>,
+    ) -> Option<Rectangle> {
+        let _ = self.title_bar.as_ref()?;
+
+        layout.children().next().map(|layout| layout.bounds())
+    }
+
     pub(crate) fn operate(
         &self,
         tree: &mut Tree,
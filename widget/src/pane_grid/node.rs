@@ -1,5 +1,5 @@
 use crate::core::{Rectangle, Size};
-use crate::pane_grid::{Axis, Pane, Split};
+use crate::pane_grid::{Axis, Configuration, Pane, Split};
 
 use std::collections::BTreeMap;
 
@@ -27,6 +27,15 @@ pub enum Node {
     },
     /// The region of this [`Node`] is taken by a [`Pane`].
     Pane(Pane),
+    /// The region of this [`Node`] is shared by a group of panes shown as
+    /// tabs, of which only the active one is visible.
+    Tabs {
+        /// The panes of the group, in the order of their tabs.
+        tabs: Vec<Pane>,
+
+        /// The active [`Pane`] of the group.
+        active: Pane,
+    },
 }
 
 impl Node {
@@ -48,23 +57,56 @@ impl Node {
         })
     }
 
-    /// Returns the rectangular region for each [`Pane`] in the [`Node`] given
-    /// the spacing between panes and the total available space.
+    /// Returns an iterator over each tab group in this [`Node`], alongside its
+    /// active [`Pane`].
+    pub fn tabs(&self) -> impl Iterator<Item = (&[Pane], Pane)> {
+        let mut unvisited_nodes = vec![self];
+
+        std::iter::from_fn(move || {
+            while let Some(node) = unvisited_nodes.pop() {
+                match node {
+                    Node::Split { a, b, .. } => {
+                        unvisited_nodes.push(a);
+                        unvisited_nodes.push(b);
+                    }
+                    Node::Tabs { tabs, active } => {
+                        return Some((tabs.as_slice(), *active));
+                    }
+                    Node::Pane(_) => {}
+                }
+            }
+
+            None
+        })
+    }
+
+    /// Returns the rectangular region for each visible [`Pane`] in the
+    /// [`Node`] given the spacing between panes and the total available space.
+    ///
+    /// Only the active [`Pane`] of a tab group is visible.
     pub fn pane_regions(
         &self,
         spacing: f32,
         size: Size,
+    ) -> BTreeMap<Pane, Rectangle> {
+        self.pane_regions_with(spacing, size, &BTreeMap::new())
+    }
+
+    /// Returns the rectangular region for each visible [`Pane`] in the
+    /// [`Node`], keeping each [`Pane`] at least as large as its given
+    /// minimum size whenever there is enough space.
+    pub(crate) fn pane_regions_with(
+        &self,
+        spacing: f32,
+        size: Size,
+        min_sizes: &BTreeMap<Pane, Size>,
     ) -> BTreeMap<Pane, Rectangle> {
         let mut regions = BTreeMap::new();
 
         self.compute_regions(
             spacing,
-            &Rectangle {
-                x: 0.0,
-                y: 0.0,
-                width: size.width,
-                height: size.height,
-            },
+            &Rectangle::with_size(size),
+            min_sizes,
             &mut regions,
         );
 
@@ -78,23 +120,160 @@ impl Node {
         &self,
         spacing: f32,
         size: Size,
+    ) -> BTreeMap<Split, (Axis, Rectangle, f32)> {
+        self.split_regions_with(spacing, size, &BTreeMap::new())
+    }
+
+    /// Returns the axis, rectangular region, and ratio for each [`Split`] in
+    /// the [`Node`], keeping each [`Pane`] at least as large as its given
+    /// minimum size whenever there is enough space.
+    pub(crate) fn split_regions_with(
+        &self,
+        spacing: f32,
+        size: Size,
+        min_sizes: &BTreeMap<Pane, Size>,
     ) -> BTreeMap<Split, (Axis, Rectangle, f32)> {
         let mut splits = BTreeMap::new();
 
         self.compute_splits(
             spacing,
-            &Rectangle {
-                x: 0.0,
-                y: 0.0,
-                width: size.width,
-                height: size.height,
-            },
+            &Rectangle::with_size(size),
+            min_sizes,
             &mut splits,
         );
 
         splits
     }
 
+    /// Returns the closest [`Split`] with the given [`Axis`] that contains
+    /// the given [`Pane`].
+    pub(crate) fn closest_split(
+        &self,
+        pane: Pane,
+        axis: Axis,
+    ) -> Option<Split> {
+        self.find_split(pane, axis).flatten()
+    }
+
+    /// Returns `None` if the [`Pane`] is not part of this [`Node`].
+    fn find_split(&self, pane: Pane, target: Axis) -> Option<Option<Split>> {
+        match self {
+            Node::Split { id, axis, a, b, .. } => a
+                .find_split(pane, target)
+                .or_else(|| b.find_split(pane, target))
+                .map(|split| {
+                    split.or_else(|| (*axis == target).then_some(*id))
+                }),
+            Node::Pane(p) => (*p == pane).then_some(None),
+            Node::Tabs { tabs, .. } => tabs.contains(&pane).then_some(None),
+        }
+    }
+
+    /// Returns the minimum size of this [`Node`] given the spacing between
+    /// panes and the minimum size of each [`Pane`].
+    pub(crate) fn min_size(
+        &self,
+        spacing: f32,
+        min_sizes: &BTreeMap<Pane, Size>,
+    ) -> Size {
+        let min_size =
+            |pane: &Pane| min_sizes.get(pane).copied().unwrap_or(Size::ZERO);
+
+        match self {
+            Node::Split { axis, a, b, .. } => {
+                let a = a.min_size(spacing, min_sizes);
+                let b = b.min_size(spacing, min_sizes);
+
+                match axis {
+                    Axis::Horizontal => Size::new(
+                        a.width.max(b.width),
+                        a.height + spacing + b.height,
+                    ),
+                    Axis::Vertical => Size::new(
+                        a.width + spacing + b.width,
+                        a.height.max(b.height),
+                    ),
+                }
+            }
+            Node::Pane(pane) => min_size(pane),
+            Node::Tabs { tabs, .. } => {
+                tabs.iter().map(min_size).fold(Size::ZERO, Size::max)
+            }
+        }
+    }
+
+    /// Returns the closest ratio to the given one that fits the minimum sizes
+    /// of both sides of the [`Split`] in the given region.
+    pub(crate) fn clamp_ratio(
+        &self,
+        split: Split,
+        ratio: f32,
+        spacing: f32,
+        current: &Rectangle,
+        min_sizes: &BTreeMap<Pane, Size>,
+    ) -> Option<f32> {
+        match self {
+            Node::Split {
+                id,
+                axis,
+                ratio: current_ratio,
+                a,
+                b,
+            } => {
+                if *id == split {
+                    return Some(fit_ratio(
+                        *axis, ratio, spacing, current, a, b, min_sizes,
+                    ));
+                }
+
+                let (region_a, region_b) = axis.split(
+                    current,
+                    fit_ratio(
+                        *axis,
+                        *current_ratio,
+                        spacing,
+                        current,
+                        a,
+                        b,
+                        min_sizes,
+                    ),
+                    spacing,
+                );
+
+                a.clamp_ratio(split, ratio, spacing, &region_a, min_sizes)
+                    .or_else(|| {
+                        b.clamp_ratio(
+                            split, ratio, spacing, &region_b, min_sizes,
+                        )
+                    })
+            }
+            Node::Pane(_) | Node::Tabs { .. } => None,
+        }
+    }
+
+    /// Returns the [`Configuration`] of this [`Node`], obtaining the state of
+    /// each [`Pane`] with the given function.
+    pub(crate) fn configuration<T>(
+        &self,
+        state: &mut impl FnMut(Pane) -> T,
+    ) -> Configuration<T> {
+        match self {
+            Node::Split {
+                axis, ratio, a, b, ..
+            } => Configuration::Split {
+                axis: *axis,
+                ratio: *ratio,
+                a: Box::new(a.configuration(state)),
+                b: Box::new(b.configuration(state)),
+            },
+            Node::Pane(pane) => Configuration::Pane(state(*pane)),
+            Node::Tabs { tabs, active } => Configuration::Tabs {
+                active: tabs.iter().position(|tab| tab == active).unwrap_or(0),
+                tabs: tabs.iter().map(|tab| state(*tab)).collect(),
+            },
+        }
+    }
+
     pub(crate) fn find(&mut self, pane: Pane) -> Option<&mut Node> {
         match self {
             Node::Split { a, b, .. } => {
@@ -107,6 +286,42 @@ impl Node {
                     None
                 }
             }
+            Node::Tabs { tabs, .. } => {
+                if tabs.contains(&pane) {
+                    Some(self)
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    pub(crate) fn stack(&mut self, pane: Pane, target: Pane) {
+        match self {
+            Node::Split { .. } => {}
+            Node::Pane(_) => {
+                *self = Node::Tabs {
+                    tabs: vec![target, pane],
+                    active: pane,
+                };
+            }
+            Node::Tabs { tabs, active } => {
+                let index = tabs
+                    .iter()
+                    .position(|tab| *tab == target)
+                    .map_or(tabs.len(), |index| index + 1);
+
+                tabs.insert(index, pane);
+                *active = pane;
+            }
+        }
+    }
+
+    pub(crate) fn select(&mut self, pane: Pane) {
+        if let Node::Tabs { tabs, active } = self {
+            if tabs.contains(&pane) {
+                *active = pane;
+            }
         }
     }
 
@@ -154,7 +369,7 @@ impl Node {
                     b.resize(split, percentage)
                 }
             }
-            Node::Pane(_) => false,
+            Node::Pane(_) | Node::Tabs { .. } => false,
         }
     }
 
@@ -171,13 +386,29 @@ impl Node {
                     a.remove(pane).or_else(|| b.remove(pane))
                 }
             }
+            Node::Tabs { tabs, active } => {
+                let index = tabs.iter().position(|tab| *tab == pane)?;
+                let _ = tabs.remove(index);
+
+                if *active == pane {
+                    *active = tabs[index.min(tabs.len() - 1)];
+                }
+
+                let active = *active;
+
+                if tabs.len() == 1 {
+                    *self = Node::Pane(active);
+                }
+
+                Some(active)
+            }
             Node::Pane(_) => None,
         }
     }
 
     fn pane(&self) -> Option<Pane> {
         match self {
-            Node::Split { .. } => None,
+            Node::Split { .. } | Node::Tabs { .. } => None,
             Node::Pane(pane) => Some(*pane),
         }
     }
@@ -185,7 +416,7 @@ impl Node {
     fn first_pane(&self) -> Pane {
         match self {
             Node::Split { a, .. } => a.first_pane(),
-            Node::Pane(pane) => *pane,
+            Node::Pane(pane) | Node::Tabs { active: pane, .. } => *pane,
         }
     }
 
@@ -193,18 +424,22 @@ impl Node {
         &self,
         spacing: f32,
         current: &Rectangle,
+        min_sizes: &BTreeMap<Pane, Size>,
         regions: &mut BTreeMap<Pane, Rectangle>,
     ) {
         match self {
             Node::Split {
                 axis, ratio, a, b, ..
             } => {
-                let (region_a, region_b) = axis.split(current, *ratio, spacing);
+                let ratio =
+                    fit_ratio(*axis, *ratio, spacing, current, a, b, min_sizes);
+
+                let (region_a, region_b) = axis.split(current, ratio, spacing);
 
-                a.compute_regions(spacing, &region_a, regions);
-                b.compute_regions(spacing, &region_b, regions);
+                a.compute_regions(spacing, &region_a, min_sizes, regions);
+                b.compute_regions(spacing, &region_b, min_sizes, regions);
             }
-            Node::Pane(pane) => {
+            Node::Pane(pane) | Node::Tabs { active: pane, .. } => {
                 let _ = regions.insert(*pane, *current);
             }
         }
@@ -214,6 +449,7 @@ impl Node {
         &self,
         spacing: f32,
         current: &Rectangle,
+        min_sizes: &BTreeMap<Pane, Size>,
         splits: &mut BTreeMap<Split, (Axis, Rectangle, f32)>,
     ) {
         match self {
@@ -224,18 +460,57 @@ impl Node {
                 b,
                 id,
             } => {
-                let (region_a, region_b) = axis.split(current, *ratio, spacing);
+                let ratio =
+                    fit_ratio(*axis, *ratio, spacing, current, a, b, min_sizes);
 
-                let _ = splits.insert(*id, (*axis, *current, *ratio));
+                let (region_a, region_b) = axis.split(current, ratio, spacing);
 
-                a.compute_splits(spacing, &region_a, splits);
-                b.compute_splits(spacing, &region_b, splits);
+                let _ = splits.insert(*id, (*axis, *current, ratio));
+
+                a.compute_splits(spacing, &region_a, min_sizes, splits);
+                b.compute_splits(spacing, &region_b, min_sizes, splits);
             }
-            Node::Pane(_) => {}
+            Node::Pane(_) | Node::Tabs { .. } => {}
         }
     }
 }
 
+/// Clamps the ratio of a split so both of its sides fit their minimum size.
+///
+/// If there is not enough space for both, the left/top side is favored.
+fn fit_ratio(
+    axis: Axis,
+    ratio: f32,
+    spacing: f32,
+    region: &Rectangle,
+    a: &Node,
+    b: &Node,
+    min_sizes: &BTreeMap<Pane, Size>,
+) -> f32 {
+    if min_sizes.is_empty() {
+        return ratio;
+    }
+
+    let (length, min_a, min_b) = {
+        let a = a.min_size(spacing, min_sizes);
+        let b = b.min_size(spacing, min_sizes);
+
+        match axis {
+            Axis::Horizontal => (region.height, a.height, b.height),
+            Axis::Vertical => (region.width, a.width, b.width),
+        }
+    };
+
+    if length <= 0.0 {
+        return ratio;
+    }
+
+    let max = (length - spacing / 2.0 - min_b) / length;
+    let min = (min_a + spacing / 2.0) / length;
+
+    ratio.min(max).max(min)
+}
+
 impl std::hash::Hash for Node {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
         match self {
@@ -255,6 +530,10 @@ impl std::hash::Hash for Node {
             Node::Pane(pane) => {
                 pane.hash(state);
             }
+            Node::Tabs { tabs, active } => {
+                tabs.hash(state);
+                active.hash(state);
+            }
         }
     }
 }
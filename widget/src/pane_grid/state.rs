@@ -1,12 +1,12 @@
 //! The state of a [`PaneGrid`].
 //!
 //! [`PaneGrid`]: super::PaneGrid
-use crate::core::{Point, Size};
+use crate::core::{Point, Rectangle, Size};
 use crate::pane_grid::{
     Axis, Configuration, Direction, Edge, Node, Pane, Region, Split, Target,
 };
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 /// The state of a [`PaneGrid`].
 ///
@@ -36,6 +36,11 @@ pub struct State<T> {
     ///
     /// [`PaneGrid`]: super::PaneGrid
     pub(super) maximized: Option<Pane>,
+
+    /// The focused [`Pane`] of the [`PaneGrid`].
+    ///
+    /// [`PaneGrid`]: super::PaneGrid
+    pub(super) focused: Option<Pane>,
 }
 
 impl<T> State<T> {
@@ -51,6 +56,11 @@ impl<T> State<T> {
     }
 
     /// Creates a new [`State`] with the given [`Configuration`].
+    ///
+    /// Empty tab groups in the [`Configuration`] are dropped.
+    ///
+    /// # Panics
+    /// Panics if the [`Configuration`] contains no panes at all.
     pub fn with_configuration(config: impl Into<Configuration<T>>) -> Self {
         let mut panes = HashMap::new();
 
@@ -61,9 +71,25 @@ impl<T> State<T> {
             panes,
             internal,
             maximized: None,
+            focused: None,
         }
     }
 
+    /// Returns the [`Configuration`] of the [`State`].
+    ///
+    /// The [`Configuration`] can be stored and used to recreate an equivalent
+    /// [`State`] later with [`State::with_configuration`]. The identifiers of
+    /// the panes and splits of the new [`State`] may differ, but restoring
+    /// the same [`Configuration`] always produces the same [`State`].
+    pub fn configuration(&self) -> Configuration<T>
+    where
+        T: Clone,
+    {
+        self.internal
+            .layout
+            .configuration(&mut |pane| self.panes[&pane].clone())
+    }
+
     /// Returns the total amount of panes in the [`State`].
     pub fn len(&self) -> usize {
         self.panes.len()
@@ -137,6 +163,71 @@ impl<T> State<T> {
         Some(*pane)
     }
 
+    /// Focuses the given [`Pane`].
+    ///
+    /// If the [`Pane`] is a tab of a group, it also becomes the active tab
+    /// of the group.
+    ///
+    /// If you want to focus panes when they are clicked in your [`PaneGrid`],
+    /// you will need to call this method when handling
+    /// [`PaneGrid::on_click`].
+    ///
+    /// [`PaneGrid`]: super::PaneGrid
+    /// [`PaneGrid::on_click`]: super::PaneGrid::on_click
+    pub fn focus(&mut self, pane: Pane) {
+        if self.panes.contains_key(&pane) {
+            self.select(pane);
+            self.focused = Some(pane);
+        }
+    }
+
+    /// Focuses the adjacent [`Pane`] of the focused [`Pane`] in the given
+    /// direction, if there is one.
+    ///
+    /// It returns the newly focused [`Pane`].
+    pub fn focus_adjacent(&mut self, direction: Direction) -> Option<Pane> {
+        let adjacent = self.adjacent(self.focused?, direction)?;
+
+        self.focus(adjacent);
+
+        Some(adjacent)
+    }
+
+    /// Clears the focus of the [`State`].
+    pub fn unfocus(&mut self) {
+        let _ = self.focused.take();
+    }
+
+    /// Returns the focused [`Pane`] of the [`PaneGrid`], if any.
+    ///
+    /// [`PaneGrid`]: super::PaneGrid
+    pub fn focused(&self) -> Option<Pane> {
+        self.focused
+    }
+
+    /// Sets the minimum [`Size`] of the given [`Pane`].
+    ///
+    /// The [`PaneGrid`] will keep the [`Pane`] at least this large whenever
+    /// there is enough space, no matter the ratio of its splits. Resizing
+    /// a [`Split`] by dragging or with the keyboard also stops at the minimum
+    /// size of the panes on each side.
+    ///
+    /// [`PaneGrid`]: super::PaneGrid
+    pub fn set_min_size(&mut self, pane: Pane, min_size: impl Into<Size>) {
+        if self.panes.contains_key(&pane) {
+            let _ = self.internal.min_sizes.insert(pane, min_size.into());
+        }
+    }
+
+    /// Returns the minimum [`Size`] of the given [`Pane`].
+    pub fn min_size(&self, pane: Pane) -> Size {
+        self.internal
+            .min_sizes
+            .get(&pane)
+            .copied()
+            .unwrap_or(Size::ZERO)
+    }
+
     /// Splits the given [`Pane`] into two in the given [`Axis`] and
     /// initializing the new [`Pane`] with the provided internal state.
     pub fn split(
@@ -150,10 +241,12 @@ impl<T> State<T> {
 
     /// Split a target [`Pane`] with a given [`Pane`] on a given [`Region`].
     ///
-    /// Panes will be swapped by default for [`Region::Center`].
+    /// Panes will be swapped by default for [`Region::Center`], and stacked
+    /// as tabs for [`Region::Tabs`].
     pub fn split_with(&mut self, target: Pane, pane: Pane, region: Region) {
         match region {
             Region::Center => self.swap(pane, target),
+            Region::Tabs => self.stack(pane, target),
             Region::Edge(edge) => match edge {
                 Edge::Top => {
                     self.split_and_swap(Axis::Horizontal, target, pane, true);
@@ -226,13 +319,7 @@ impl<T> State<T> {
         pane: Pane,
         swap: bool,
     ) {
-        if let Some((state, _)) = self.close(pane) {
-            if let Some((new_pane, _)) = self.split(axis, target, state) {
-                if swap {
-                    self.swap(target, new_pane);
-                }
-            }
-        }
+        self.move_and_split(axis, Some(target), pane, swap);
     }
 
     /// Move [`Pane`] to an [`Edge`] of the [`PaneGrid`].
@@ -261,8 +348,36 @@ impl<T> State<T> {
         pane: Pane,
         swap: bool,
     ) {
-        if let Some((state, _)) = self.close(pane) {
-            let _ = self.split_node(axis, None, state, swap);
+        self.move_and_split(axis, None, pane, swap);
+    }
+
+    /// Moves the given [`Pane`] into a new split of the target [`Pane`], or of
+    /// the major node, keeping its minimum size and focus.
+    fn move_and_split(
+        &mut self,
+        axis: Axis,
+        target: Option<Pane>,
+        pane: Pane,
+        swap: bool,
+    ) {
+        let min_size = self.internal.min_sizes.get(&pane).copied();
+        let is_focused = self.focused == Some(pane);
+
+        let Some((state, _)) = self.close(pane) else {
+            return;
+        };
+
+        let Some((new_pane, _)) = self.split_node(axis, target, state, swap)
+        else {
+            return;
+        };
+
+        if let Some(min_size) = min_size {
+            let _ = self.internal.min_sizes.insert(new_pane, min_size);
+        }
+
+        if is_focused {
+            self.focused = Some(new_pane);
         }
     }
 
@@ -274,22 +389,82 @@ impl<T> State<T> {
     /// [`PaneGrid`]: super::PaneGrid
     /// [`DragEvent`]: super::DragEvent
     pub fn swap(&mut self, a: Pane, b: Pane) {
+        let swap = |pane: &mut Pane| {
+            if *pane == a {
+                *pane = b;
+            } else if *pane == b {
+                *pane = a;
+            }
+        };
+
         self.internal.layout.update(&|node| match node {
             Node::Split { .. } => {}
-            Node::Pane(pane) => {
-                if *pane == a {
-                    *node = Node::Pane(b);
-                } else if *pane == b {
-                    *node = Node::Pane(a);
-                }
+            Node::Pane(pane) => swap(pane),
+            Node::Tabs { tabs, active } => {
+                tabs.iter_mut().for_each(swap);
+                swap(active);
             }
         });
     }
 
+    /// Stacks the given [`Pane`] as a tab in the group of the target [`Pane`],
+    /// making it the active tab of the group.
+    ///
+    /// The [`Pane`] is placed right after the target tab. If the target
+    /// [`Pane`] is not part of a group, a new group is created with both
+    /// panes.
+    ///
+    /// If you want to stack panes on drag and drop in your [`PaneGrid`], you
+    /// can simply call [`State::drop`] when handling a [`DragEvent`].
+    ///
+    /// [`PaneGrid`]: super::PaneGrid
+    /// [`DragEvent`]: super::DragEvent
+    pub fn stack(&mut self, pane: Pane, target: Pane) {
+        if pane == target
+            || !self.panes.contains_key(&pane)
+            || !self.panes.contains_key(&target)
+        {
+            return;
+        }
+
+        if self.maximized == Some(pane) {
+            let _ = self.maximized.take();
+        }
+
+        let _ = self.internal.layout.remove(pane);
+
+        if let Some(node) = self.internal.layout.find(target) {
+            node.stack(pane, target);
+        }
+    }
+
+    /// Makes the given [`Pane`] the active tab of its group.
+    ///
+    /// If you want to switch tabs when they are clicked in your [`PaneGrid`],
+    /// you will need to call this method when handling
+    /// [`PaneGrid::on_click`].
+    ///
+    /// If the group was focused, the [`Pane`] gets focused as well.
+    ///
+    /// [`PaneGrid`]: super::PaneGrid
+    /// [`PaneGrid::on_click`]: super::PaneGrid::on_click
+    pub fn select(&mut self, pane: Pane) {
+        if let Some(node) = self.internal.layout.find(pane) {
+            if let Node::Tabs { tabs, .. } = node {
+                if self.focused.is_some_and(|focused| tabs.contains(&focused)) {
+                    self.focused = Some(pane);
+                }
+            }
+
+            node.select(pane);
+        }
+    }
+
     /// Resizes two panes by setting the position of the provided [`Split`].
     ///
     /// The ratio is a value in [0, 1], representing the exact position of a
-    /// [`Split`] between two panes.
+    /// [`Split`] between two panes. The [`Split`] will still never be laid
+    /// out past the minimum size of the panes on each side.
     ///
     /// If you want to enable resize interactions in your [`PaneGrid`], you will
     /// need to call this method when handling a [`ResizeEvent`].
@@ -302,16 +477,23 @@ impl<T> State<T> {
 
     /// Closes the given [`Pane`] and returns its internal state and its closest
     /// sibling, if it exists.
+    ///
+    /// If the [`Pane`] is focused, its sibling gets focused instead.
     pub fn close(&mut self, pane: Pane) -> Option<(T, Pane)> {
         if self.maximized == Some(pane) {
             let _ = self.maximized.take();
         }
 
-        if let Some(sibling) = self.internal.layout.remove(pane) {
-            self.panes.remove(&pane).map(|state| (state, sibling))
-        } else {
-            None
+        let sibling = self.internal.layout.remove(pane)?;
+        let state = self.panes.remove(&pane)?;
+
+        let _ = self.internal.min_sizes.remove(&pane);
+
+        if self.focused == Some(pane) {
+            self.focused = Some(sibling);
         }
+
+        Some((state, sibling))
     }
 
     /// Maximize the given [`Pane`]. Only this pane will be rendered by the
@@ -338,6 +520,17 @@ impl<T> State<T> {
     }
 }
 
+impl<T> From<State<T>> for Configuration<T> {
+    fn from(mut state: State<T>) -> Self {
+        state.internal.layout.configuration(&mut |pane| {
+            state
+                .panes
+                .remove(&pane)
+                .expect("Every pane of the layout must have a state")
+        })
+    }
+}
+
 /// The internal state of a [`PaneGrid`].
 ///
 /// [`PaneGrid`]: super::PaneGrid
@@ -345,52 +538,94 @@ impl<T> State<T> {
 pub struct Internal {
     layout: Node,
     last_id: usize,
+    min_sizes: BTreeMap<Pane, Size>,
 }
 
 impl Internal {
     /// Initializes the [`Internal`] state of a [`PaneGrid`] from a
     /// [`Configuration`].
     ///
+    /// Empty tab groups are dropped, collapsing their parent split into the
+    /// sibling of the group.
+    ///
+    /// # Panics
+    /// Panics if the [`Configuration`] contains no panes at all.
+    ///
     /// [`PaneGrid`]: super::PaneGrid
     pub fn from_configuration<T>(
         panes: &mut HashMap<Pane, T>,
         content: Configuration<T>,
         next_id: usize,
     ) -> Self {
-        let (layout, last_id) = match content {
+        let (layout, last_id) = Self::build(panes, content, next_id)
+            .expect("A configuration must contain at least one pane");
+
+        Self {
+            layout,
+            last_id,
+            min_sizes: BTreeMap::new(),
+        }
+    }
+
+    /// Builds the [`Node`] of a [`Configuration`], alongside the next
+    /// available identifier; unless the [`Configuration`] has no panes.
+    fn build<T>(
+        panes: &mut HashMap<Pane, T>,
+        content: Configuration<T>,
+        next_id: usize,
+    ) -> Option<(Node, usize)> {
+        match content {
             Configuration::Split { axis, ratio, a, b } => {
-                let Internal {
-                    layout: a,
-                    last_id: next_id,
-                    ..
-                } = Self::from_configuration(panes, *a, next_id);
-
-                let Internal {
-                    layout: b,
-                    last_id: next_id,
-                    ..
-                } = Self::from_configuration(panes, *b, next_id);
-
-                (
-                    Node::Split {
-                        id: Split(next_id),
-                        axis,
-                        ratio,
-                        a: Box::new(a),
-                        b: Box::new(b),
-                    },
-                    next_id + 1,
-                )
+                let a = Self::build(panes, *a, next_id);
+                let next_id = a.as_ref().map_or(next_id, |(_, id)| *id);
+                let b = Self::build(panes, *b, next_id);
+
+                match (a, b) {
+                    (Some((a, _)), Some((b, next_id))) => Some((
+                        Node::Split {
+                            id: Split(next_id),
+                            axis,
+                            ratio,
+                            a: Box::new(a),
+                            b: Box::new(b),
+                        },
+                        next_id + 1,
+                    )),
+                    (Some(node), None) | (None, Some(node)) => Some(node),
+                    (None, None) => None,
+                }
             }
             Configuration::Pane(state) => {
                 let id = Pane(next_id);
                 let _ = panes.insert(id, state);
 
-                (Node::Pane(id), next_id + 1)
+                Some((Node::Pane(id), next_id + 1))
             }
-        };
-
-        Self { layout, last_id }
+            Configuration::Tabs { tabs, .. } if tabs.is_empty() => None,
+            Configuration::Tabs { tabs, active } => {
+                let tabs: Vec<_> = tabs
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, state)| {
+                        let id = Pane(next_id + i);
+                        let _ = panes.insert(id, state);
+
+                        id
+                    })
+                    .collect();
+
+                let last_id = next_id + tabs.len();
+                let active = tabs[active.min(tabs.len() - 1)];
+
+                let node = if tabs.len() == 1 {
+                    Node::Pane(active)
+                } else {
+                    Node::Tabs { tabs, active }
+                };
+
+                Some((node, last_id))
+            }
+        }
     }
 }
 
@@ -446,4 +681,341 @@ impl Internal {
     pub fn layout(&self) -> &Node {
         &self.layout
     }
+
+    /// Returns the rectangular region for each visible [`Pane`] given the
+    /// spacing between panes and the total available space, respecting the
+    /// minimum size of each [`Pane`].
+    pub fn pane_regions(
+        &self,
+        spacing: f32,
+        size: Size,
+    ) -> BTreeMap<Pane, Rectangle> {
+        self.layout
+            .pane_regions_with(spacing, size, &self.min_sizes)
+    }
+
+    /// Returns the axis, rectangular region, and ratio for each [`Split`]
+    /// given the spacing between panes and the total available space,
+    /// respecting the minimum size of each [`Pane`].
+    pub fn split_regions(
+        &self,
+        spacing: f32,
+        size: Size,
+    ) -> BTreeMap<Split, (Axis, Rectangle, f32)> {
+        self.layout
+            .split_regions_with(spacing, size, &self.min_sizes)
+    }
+
+    /// Returns the closest ratio to the given one that the [`Split`] can
+    /// take without shrinking any [`Pane`] below its minimum size.
+    pub(super) fn clamp_ratio(
+        &self,
+        split: Split,
+        ratio: f32,
+        spacing: f32,
+        size: Size,
+    ) -> f32 {
+        self.layout
+            .clamp_ratio(
+                split,
+                ratio,
+                spacing,
+                &Rectangle::with_size(size),
+                &self.min_sizes,
+            )
+            .unwrap_or(ratio)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn configuration() -> Configuration<&'static str> {
+        Configuration::Split {
+            axis: Axis::Vertical,
+            ratio: 0.3,
+            a: Box::new(Configuration::Pane("files")),
+            b: Box::new(Configuration::Split {
+                axis: Axis::Horizontal,
+                ratio: 0.75,
+                a: Box::new(Configuration::Pane("editor")),
+                b: Box::new(Configuration::Pane("terminal")),
+            }),
+        }
+    }
+
+    #[test]
+    fn configuration_round_trip() {
+        let state = State::with_configuration(configuration());
+
+        assert_eq!(state.configuration(), configuration());
+        assert_eq!(Configuration::from(state.clone()), configuration());
+
+        let restored = State::with_configuration(state.configuration());
+
+        assert_eq!(restored.panes, state.panes);
+        assert_eq!(
+            restored.layout().pane_regions(0.0, Size::new(100.0, 100.0)),
+            state.layout().pane_regions(0.0, Size::new(100.0, 100.0))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn configuration_serde_round_trip() {
+        let json = serde_json::to_string(&configuration()).unwrap();
+        let restored: Configuration<&str> =
+            serde_json::from_str(&json).unwrap();
+
+        assert_eq!(restored, configuration());
+
+        let empty = r#"{"Tabs":{"tabs":[],"active":0}}"#;
+
+        assert!(serde_json::from_str::<Configuration<&str>>(empty).is_err());
+    }
+
+    #[test]
+    fn empty_tab_groups_are_dropped() {
+        let state = State::with_configuration(Configuration::Split {
+            axis: Axis::Vertical,
+            ratio: 0.3,
+            a: Box::new(Configuration::Tabs {
+                tabs: vec![],
+                active: 0,
+            }),
+            b: Box::new(Configuration::Split {
+                axis: Axis::Horizontal,
+                ratio: 0.75,
+                a: Box::new(Configuration::Pane("editor")),
+                b: Box::new(Configuration::Pane("terminal")),
+            }),
+        });
+
+        assert_eq!(
+            state.configuration(),
+            Configuration::Split {
+                axis: Axis::Horizontal,
+                ratio: 0.75,
+                a: Box::new(Configuration::Pane("editor")),
+                b: Box::new(Configuration::Pane("terminal")),
+            }
+        );
+        assert_eq!(state.len(), 2);
+    }
+
+    #[test]
+    fn configuration_after_changes() {
+        let mut state = State::with_configuration(configuration());
+        let editor =
+            *state.iter().find(|(_, name)| **name == "editor").unwrap().0;
+
+        let (_, split) =
+            state.split(Axis::Vertical, editor, "preview").unwrap();
+        state.resize(split, 0.6);
+
+        let restored = State::with_configuration(state.configuration());
+
+        assert_eq!(restored.configuration(), state.configuration());
+    }
+
+    fn find(state: &State<&'static str>, name: &str) -> Pane {
+        *state.iter().find(|(_, pane)| **pane == name).unwrap().0
+    }
+
+    #[test]
+    fn stack_and_unstack_tabs() {
+        let mut state = State::with_configuration(configuration());
+        let editor = find(&state, "editor");
+        let terminal = find(&state, "terminal");
+        let files = find(&state, "files");
+
+        state.stack(terminal, editor);
+
+        assert_eq!(
+            state.configuration(),
+            Configuration::Split {
+                axis: Axis::Vertical,
+                ratio: 0.3,
+                a: Box::new(Configuration::Pane("files")),
+                b: Box::new(Configuration::Tabs {
+                    tabs: vec!["editor", "terminal"],
+                    active: 1,
+                }),
+            }
+        );
+
+        // Only the active tab is visible
+        let regions = state.layout().pane_regions(0.0, Size::new(100.0, 100.0));
+
+        assert!(regions.contains_key(&terminal));
+        assert!(!regions.contains_key(&editor));
+
+        state.select(editor);
+        state.stack(files, terminal);
+
+        assert_eq!(
+            state.configuration(),
+            Configuration::Tabs {
+                tabs: vec!["editor", "terminal", "files"],
+                active: 2,
+            }
+        );
+
+        // Splitting a tab out of its group
+        state.drop(
+            files,
+            Target::Pane(editor, Region::Edge(crate::pane_grid::Edge::Left)),
+        );
+
+        assert_eq!(
+            state.configuration(),
+            Configuration::Split {
+                axis: Axis::Vertical,
+                ratio: 0.5,
+                a: Box::new(Configuration::Pane("files")),
+                b: Box::new(Configuration::Tabs {
+                    tabs: vec!["editor", "terminal"],
+                    active: 1,
+                }),
+            }
+        );
+
+        let _ = state.close(editor);
+
+        assert_eq!(
+            state.configuration(),
+            Configuration::Split {
+                axis: Axis::Vertical,
+                ratio: 0.5,
+                a: Box::new(Configuration::Pane("files")),
+                b: Box::new(Configuration::Pane("terminal")),
+            }
+        );
+    }
+
+    #[test]
+    fn tabs_configuration_round_trip() {
+        let configuration = Configuration::Split {
+            axis: Axis::Horizontal,
+            ratio: 0.4,
+            a: Box::new(Configuration::Tabs {
+                tabs: vec!["a", "b", "c"],
+                active: 1,
+            }),
+            b: Box::new(Configuration::Pane("d")),
+        };
+
+        let state = State::with_configuration(configuration.clone());
+
+        assert_eq!(state.configuration(), configuration);
+        assert_eq!(
+            State::with_configuration(state.configuration()).configuration(),
+            configuration
+        );
+    }
+
+    #[test]
+    fn focus_in_direction() {
+        let mut state = State::with_configuration(configuration());
+        let files = find(&state, "files");
+        let editor = find(&state, "editor");
+        let terminal = find(&state, "terminal");
+
+        assert_eq!(state.focus_adjacent(Direction::Right), None);
+
+        state.focus(files);
+
+        assert_eq!(state.focus_adjacent(Direction::Left), None);
+        assert_eq!(state.focus_adjacent(Direction::Right), Some(editor));
+        assert_eq!(state.focus_adjacent(Direction::Down), Some(terminal));
+        assert_eq!(state.focus_adjacent(Direction::Left), Some(files));
+        assert_eq!(state.focused(), Some(files));
+
+        let _ = state.close(files);
+
+        assert_eq!(state.focused(), Some(editor));
+
+        state.unfocus();
+
+        assert_eq!(state.focused(), None);
+    }
+
+    #[test]
+    fn focus_follows_tabs() {
+        let mut state = State::with_configuration(configuration());
+        let editor = find(&state, "editor");
+        let terminal = find(&state, "terminal");
+
+        state.stack(terminal, editor);
+        state.focus(editor);
+
+        assert_eq!(state.focused(), Some(editor));
+        assert!(state.layout().tabs().any(|(_, active)| active == editor));
+
+        state.select(terminal);
+
+        assert_eq!(state.focused(), Some(terminal));
+    }
+
+    #[test]
+    fn minimum_sizes() {
+        let mut state = State::with_configuration(configuration());
+        let files = find(&state, "files");
+        let editor = find(&state, "editor");
+        let terminal = find(&state, "terminal");
+        let size = Size::new(100.0, 100.0);
+
+        state.set_min_size(files, Size::new(40.0, 0.0));
+        state.set_min_size(terminal, Size::new(0.0, 50.0));
+
+        let regions = state.internal.pane_regions(0.0, size);
+
+        assert_eq!(regions[&files].width, 40.0);
+        assert_eq!(regions[&editor].height, 50.0);
+        assert_eq!(regions[&terminal].height, 50.0);
+
+        // Resizing past a minimum size has no effect on the layout
+        let splits = state.internal.split_regions(0.0, size);
+        let (split, _) = splits
+            .iter()
+            .find(|(_, (axis, _, _))| *axis == Axis::Vertical)
+            .unwrap();
+
+        state.resize(*split, 0.1);
+
+        assert_eq!(state.internal.pane_regions(0.0, size), regions);
+        assert_eq!(state.internal.clamp_ratio(*split, 0.1, 0.0, size), 0.4);
+        assert_eq!(state.internal.clamp_ratio(*split, 0.7, 0.0, size), 0.7);
+
+        // The minimum size of a closed pane is forgotten
+        let _ = state.close(files);
+
+        assert_eq!(state.min_size(files), Size::ZERO);
+    }
+
+    #[test]
+    fn dropped_panes_keep_min_size_and_focus() {
+        let mut state = State::with_configuration(configuration());
+        let files = find(&state, "files");
+
+        state.set_min_size(files, Size::new(40.0, 0.0));
+        state.focus(files);
+
+        state.drop(files, Target::Edge(Edge::Right));
+
+        let files = find(&state, "files");
+
+        assert_eq!(state.min_size(files), Size::new(40.0, 0.0));
+        assert_eq!(state.focused(), Some(files));
+
+        let editor = find(&state, "editor");
+
+        state.drop(files, Target::Pane(editor, Region::Edge(Edge::Top)));
+
+        let files = find(&state, "files");
+
+        assert_eq!(state.min_size(files), Size::new(40.0, 0.0));
+        assert_eq!(state.focused(), Some(files));
+    }
 }
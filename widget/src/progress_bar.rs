@@ -1,10 +1,13 @@
 //! Provide progress feedback to your users.
+use crate::core::alignment;
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::renderer;
+use crate::core::text::{self, Text};
 use crate::core::widget::Tree;
 use crate::core::{
-    Background, Border, Element, Layout, Length, Rectangle, Size, Theme, Widget,
+    Background, Border, Element, Layout, Length, Pixels, Rectangle, Size,
+    Theme, Widget,
 };
 
 use std::ops::RangeInclusive;
@@ -20,13 +23,32 @@ use std::ops::RangeInclusive;
 /// ProgressBar::new(0.0..=100.0, value);
 /// ```
 ///
+/// A [`ProgressBar`] can also be laid out vertically, in which case it fills
+/// up from the bottom, and display a label on top of the bar:
+///
+/// ```no_run
+/// # type ProgressBar = iced_widget::ProgressBar;
+/// #
+/// let value = 50.0;
+///
+/// ProgressBar::new(0.0..=100.0, value)
+///     .vertical(true)
+///     .label(format!("{value}%"));
+/// ```
+///
+/// Since the label is drawn as text, a [`ProgressBar`] can only be used with
+/// a [`text::Renderer`].
+///
 /// ![Progress bar drawn with `iced_wgpu`](https://user-images.githubusercontent.com/18618951/71662391-a316c200-2d51-11ea-9cef-52758cab85e3.png)
 #[allow(missing_debug_implementations)]
 pub struct ProgressBar<Theme = crate::Theme> {
     range: RangeInclusive<f32>,
     value: f32,
-    width: Length,
+    width: Option<Length>,
     height: Option<Length>,
+    is_vertical: bool,
+    label: Option<String>,
+    label_size: Option<Pixels>,
     style: Style<Theme>,
 }
 
@@ -46,15 +68,18 @@ impl<Theme> ProgressBar<Theme> {
         ProgressBar {
             value: value.clamp(*range.start(), *range.end()),
             range,
-            width: Length::Fill,
+            width: None,
             height: None,
+            is_vertical: false,
+            label: None,
+            label_size: None,
             style: Theme::default_style(),
         }
     }
 
     /// Sets the width of the [`ProgressBar`].
     pub fn width(mut self, width: impl Into<Length>) -> Self {
-        self.width = width.into();
+        self.width = Some(width.into());
         self
     }
 
@@ -64,22 +89,59 @@ impl<Theme> ProgressBar<Theme> {
         self
     }
 
+    /// Sets whether the [`ProgressBar`] is vertical.
+    ///
+    /// A vertical [`ProgressBar`] fills up from the bottom and, by default,
+    /// swaps its default width and height.
+    pub fn vertical(mut self, is_vertical: bool) -> Self {
+        self.is_vertical = is_vertical;
+        self
+    }
+
+    /// Sets the label displayed in the middle of the [`ProgressBar`].
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Sets the text size of the label of the [`ProgressBar`].
+    pub fn label_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.label_size = Some(size.into());
+        self
+    }
+
     /// Sets the style of the [`ProgressBar`].
     pub fn style(mut self, style: fn(&Theme) -> Appearance) -> Self {
         self.style = style.into();
         self
     }
+
+    fn width_or_default(&self) -> Length {
+        self.width.unwrap_or(if self.is_vertical {
+            Length::Fixed(Self::DEFAULT_HEIGHT)
+        } else {
+            Length::Fill
+        })
+    }
+
+    fn height_or_default(&self) -> Length {
+        self.height.unwrap_or(if self.is_vertical {
+            Length::Fill
+        } else {
+            Length::Fixed(Self::DEFAULT_HEIGHT)
+        })
+    }
 }
 
 impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
     for ProgressBar<Theme>
 where
-    Renderer: crate::core::Renderer,
+    Renderer: text::Renderer,
 {
     fn size(&self) -> Size<Length> {
         Size {
-            width: self.width,
-            height: self.height.unwrap_or(Length::Fixed(Self::DEFAULT_HEIGHT)),
+            width: self.width_or_default(),
+            height: self.height_or_default(),
         }
     }
 
@@ -91,8 +153,8 @@ where
     ) -> layout::Node {
         layout::atomic(
             limits,
-            self.width,
-            self.height.unwrap_or(Length::Fixed(Self::DEFAULT_HEIGHT)),
+            self.width_or_default(),
+            self.height_or_default(),
         )
     }
 
@@ -101,19 +163,33 @@ where
         _state: &Tree,
         renderer: &mut Renderer,
         theme: &Theme,
-        _style: &renderer::Style,
+        style: &renderer::Style,
         layout: Layout<'_>,
         _cursor: mouse::Cursor,
-        _viewport: &Rectangle,
+        viewport: &Rectangle,
     ) {
         let bounds = layout.bounds();
         let (range_start, range_end) = self.range.clone().into_inner();
 
-        let active_progress_width = if range_start >= range_end {
+        let progress = if range_start >= range_end {
             0.0
         } else {
-            bounds.width * (self.value - range_start)
-                / (range_end - range_start)
+            (self.value - range_start) / (range_end - range_start)
+        };
+
+        let active_bounds = if self.is_vertical {
+            let height = bounds.height * progress;
+
+            Rectangle {
+                y: bounds.y + bounds.height - height,
+                height,
+                ..bounds
+            }
+        } else {
+            Rectangle {
+                width: bounds.width * progress,
+                ..bounds
+            }
         };
 
         let appearance = (self.style)(theme);
@@ -127,19 +203,36 @@ where
             appearance.background,
         );
 
-        if active_progress_width > 0.0 {
+        if active_bounds.width > 0.0 && active_bounds.height > 0.0 {
             renderer.fill_quad(
                 renderer::Quad {
-                    bounds: Rectangle {
-                        width: active_progress_width,
-                        ..bounds
-                    },
+                    bounds: active_bounds,
                     border: Border::rounded(appearance.border.radius),
                     ..renderer::Quad::default()
                 },
                 appearance.bar,
             );
         }
+
+        if let Some(label) = &self.label {
+            renderer.fill_text(
+                Text {
+                    content: label,
+                    bounds: bounds.size(),
+                    size: self
+                        .label_size
+                        .unwrap_or_else(|| renderer.default_size()),
+                    line_height: text::LineHeight::default(),
+                    font: renderer.default_font(),
+                    horizontal_alignment: alignment::Horizontal::Center,
+                    vertical_alignment: alignment::Vertical::Center,
+                    shaping: text::Shaping::Advanced,
+                },
+                bounds.center(),
+                style.text_color,
+                *viewport,
+            );
+        }
     }
 }
 
@@ -148,7 +241,7 @@ impl<'a, Message, Theme, Renderer> From<ProgressBar<Theme>>
 where
     Message: 'a,
     Theme: 'a,
-    Renderer: 'a + crate::core::Renderer,
+    Renderer: 'a + text::Renderer,
 {
     fn from(
         progress_bar: ProgressBar<Theme>,
@@ -0,0 +1,177 @@
+//! Show indeterminate progress feedback to your users.
+//!
+//! Unlike a [`ProgressBar`], the indicators of this module do not display
+//! any actual progress. Instead, they keep animating for as long as they are
+//! visible, letting your users know that something is happening.
+//!
+//! The animation is driven by the redraw requests of the window, so an
+//! indicator stops requesting new frames as soon as it is scrolled out of
+//! view or removed from the widget tree.
+//!
+//! [`ProgressBar`]: crate::ProgressBar
+//!
+//! # Example
+//! ```no_run
+//! # type Element<'a, Message> =
+//! #     iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! use iced_widget::{column, linear_progress, text};
+//!
+//! fn loading<'a, Message: 'a>() -> Element<'a, Message> {
+//!     column![text("Loading..."), linear_progress().width(200)]
+//!         .spacing(10)
+//!         .into()
+//! }
+//! ```
+mod linear;
+
+#[cfg(feature = "canvas")]
+mod circular;
+
+pub use linear::Linear;
+
+#[cfg(feature = "canvas")]
+pub use circular::Circular;
+
+use crate::core::time::Duration;
+use crate::core::{Color, Theme};
+
+/// The default duration of a full cycle of an indicator.
+pub const DEFAULT_CYCLE_DURATION: Duration = Duration::from_millis(1200);
+
+/// The current phase of the cycle of an indicator.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Phase {
+    /// The bar is growing, with the given eased progress.
+    Expanding(f32),
+    /// The bar is shrinking, with the given eased progress.
+    Contracting(f32),
+}
+
+/// Returns the amount of completed cycles and the current [`Phase`] of an
+/// indicator after the given elapsed time.
+fn phase(elapsed: Duration, cycle_duration: Duration) -> (u32, Phase) {
+    let cycles =
+        elapsed.as_secs_f32() / cycle_duration.as_secs_f32().max(f32::EPSILON);
+
+    let progress = cycles.fract() * 2.0;
+
+    let phase = if progress < 1.0 {
+        Phase::Expanding(ease(progress))
+    } else {
+        Phase::Contracting(ease(progress - 1.0))
+    };
+
+    (cycles as u32, phase)
+}
+
+/// The standard easing curve of an indicator; a cubic Bézier curve with
+/// control points `(0.2, 0.0)` and `(0.0, 1.0)`.
+fn ease(x: f32) -> f32 {
+    fn bezier(p1: f32, p2: f32, t: f32) -> f32 {
+        let u = 1.0 - t;
+
+        3.0 * p1 * u * u * t + 3.0 * p2 * u * t * t + t * t * t
+    }
+
+    let (mut low, mut high) = (0.0, 1.0);
+
+    for _ in 0..20 {
+        let t = (low + high) / 2.0;
+
+        if bezier(0.2, 0.0, t) < x {
+            low = t;
+        } else {
+            high = t;
+        }
+    }
+
+    bezier(0.0, 1.0, (low + high) / 2.0)
+}
+
+/// The appearance of a progress indicator.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Appearance {
+    /// The [`Color`] of the track of the progress indicator.
+    pub track: Color,
+    /// The [`Color`] of the animated bar of the progress indicator.
+    pub bar: Color,
+}
+
+/// The style of a progress indicator.
+pub type Style<Theme> = fn(&Theme) -> Appearance;
+
+/// The default style of a progress indicator.
+pub trait DefaultStyle {
+    /// Returns the default style of a progress indicator.
+    fn default_style() -> Style<Self>;
+}
+
+impl DefaultStyle for Theme {
+    fn default_style() -> Style<Self> {
+        primary
+    }
+}
+
+impl DefaultStyle for Appearance {
+    fn default_style() -> Style<Self> {
+        |appearance| *appearance
+    }
+}
+
+/// The primary style of a progress indicator.
+pub fn primary(theme: &Theme) -> Appearance {
+    let palette = theme.extended_palette();
+
+    Appearance {
+        track: palette.background.weak.color,
+        bar: palette.primary.base.color,
+    }
+}
+
+/// The secondary style of a progress indicator.
+pub fn secondary(theme: &Theme) -> Appearance {
+    let palette = theme.extended_palette();
+
+    Appearance {
+        track: palette.background.weak.color,
+        bar: palette.secondary.base.color,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn easing_is_monotonic() {
+        assert!(ease(0.0).abs() < 1e-3);
+        assert!((ease(1.0) - 1.0).abs() < 1e-3);
+
+        let samples: Vec<f32> =
+            (0..=100).map(|i| ease(i as f32 / 100.0)).collect();
+
+        assert!(samples.windows(2).all(|pair| pair[0] <= pair[1]));
+    }
+
+    #[test]
+    fn phases_alternate() {
+        let cycle = Duration::from_secs(2);
+
+        assert_eq!(
+            phase(Duration::ZERO, cycle),
+            (0, Phase::Expanding(ease(0.0)))
+        );
+        assert!(matches!(
+            phase(Duration::from_millis(500), cycle),
+            (0, Phase::Expanding(_))
+        ));
+        assert!(matches!(
+            phase(Duration::from_millis(1500), cycle),
+            (0, Phase::Contracting(_))
+        ));
+        assert!(matches!(
+            phase(Duration::from_millis(2500), cycle),
+            (1, Phase::Expanding(_))
+        ));
+    }
+}
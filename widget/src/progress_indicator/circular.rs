@@ -0,0 +1,242 @@
+use crate::canvas;
+use crate::core::event::{self, Event};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer::{self, Renderer as _};
+use crate::core::time::{Duration, Instant};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::window;
+use crate::core::{
+    Clipboard, Element, Layout, Length, Pixels, Point, Radians, Rectangle,
+    Shell, Size, Widget,
+};
+use crate::graphics::geometry::Renderer as _;
+use crate::progress_indicator::{
+    phase, Appearance, DefaultStyle, Phase, Style, DEFAULT_CYCLE_DURATION,
+};
+use crate::Renderer;
+
+use std::f32::consts::PI;
+
+const MIN_ANGLE: Radians = Radians(PI / 8.0);
+const WRAP_ANGLE: Radians = Radians(2.0 * PI - PI / 4.0);
+
+/// An indeterminate progress indicator shaped like a ring.
+///
+/// An arc keeps growing, shrinking and rotating along its track while
+/// visible.
+#[allow(missing_debug_implementations)]
+pub struct Circular<Theme = crate::Theme> {
+    size: f32,
+    bar_height: f32,
+    cycle_duration: Duration,
+    rotation_duration: Duration,
+    style: Style<Theme>,
+}
+
+impl<Theme> Circular<Theme> {
+    /// The default size of a [`Circular`] indicator.
+    pub const DEFAULT_SIZE: f32 = 40.0;
+
+    /// The default bar height of a [`Circular`] indicator.
+    pub const DEFAULT_BAR_HEIGHT: f32 = 4.0;
+
+    /// Creates a new [`Circular`] indicator.
+    pub fn new() -> Self
+    where
+        Theme: DefaultStyle,
+    {
+        Self {
+            size: Self::DEFAULT_SIZE,
+            bar_height: Self::DEFAULT_BAR_HEIGHT,
+            cycle_duration: DEFAULT_CYCLE_DURATION,
+            rotation_duration: Duration::from_secs(2),
+            style: Theme::default_style(),
+        }
+    }
+
+    /// Sets the size of the [`Circular`] indicator.
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.size = size.into().0;
+        self
+    }
+
+    /// Sets the height of the bar of the [`Circular`] indicator.
+    pub fn bar_height(mut self, bar_height: impl Into<Pixels>) -> Self {
+        self.bar_height = bar_height.into().0;
+        self
+    }
+
+    /// Sets the duration of a full cycle of the [`Circular`] indicator; that
+    /// is, the time it takes for its arc to grow and shrink once.
+    pub fn cycle_duration(mut self, duration: Duration) -> Self {
+        self.cycle_duration = duration;
+        self
+    }
+
+    /// Sets the duration of a full rotation of the [`Circular`] indicator,
+    /// ignoring the rotation caused by the growth of its arc.
+    pub fn rotation_duration(mut self, duration: Duration) -> Self {
+        self.rotation_duration = duration;
+        self
+    }
+
+    /// Sets the style of the [`Circular`] indicator.
+    pub fn style(mut self, style: fn(&Theme) -> Appearance) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+impl<Theme> Default for Circular<Theme>
+where
+    Theme: DefaultStyle,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct State {
+    start: Instant,
+    now: Instant,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        let now = Instant::now();
+
+        Self { start: now, now }
+    }
+}
+
+impl<Message, Theme> Widget<Message, Theme, Renderer> for Circular<Theme> {
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: Length::Fixed(self.size),
+            height: Length::Fixed(self.size),
+        }
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::atomic(limits, self.size, self.size)
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        if let Event::Window(_, window::Event::RedrawRequested(now)) = event {
+            let state = tree.state.downcast_mut::<State>();
+
+            state.now = now;
+
+            if layout.bounds().intersects(viewport) {
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+            }
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let appearance = (self.style)(theme);
+
+        let elapsed = state.now.duration_since(state.start);
+        let (cycles, phase) = phase(elapsed, self.cycle_duration);
+
+        // Every cycle leaves the arc rotated by its wrap angle
+        let rotation = elapsed.as_secs_f32()
+            / self.rotation_duration.as_secs_f32().max(f32::EPSILON)
+            + cycles as f32 * WRAP_ANGLE.0 / (2.0 * PI);
+
+        let start = Radians(rotation.fract() * 2.0 * PI);
+
+        let (start_angle, end_angle) = match phase {
+            Phase::Expanding(progress) => {
+                (start, start + MIN_ANGLE + WRAP_ANGLE * progress)
+            }
+            Phase::Contracting(progress) => (
+                start + WRAP_ANGLE * progress,
+                start + MIN_ANGLE + WRAP_ANGLE,
+            ),
+        };
+
+        let mut frame = canvas::Frame::new(renderer, bounds.size());
+
+        let center = frame.center();
+        let radius = self.size / 2.0 - self.bar_height;
+
+        frame.stroke(
+            &canvas::Path::circle(center, radius),
+            canvas::Stroke::default()
+                .with_color(appearance.track)
+                .with_width(self.bar_height),
+        );
+
+        frame.stroke(
+            &canvas::Path::new(|builder| {
+                builder.arc(canvas::path::Arc {
+                    center,
+                    radius,
+                    start_angle,
+                    end_angle,
+                });
+            }),
+            canvas::Stroke::default()
+                .with_color(appearance.bar)
+                .with_width(self.bar_height),
+        );
+
+        let geometry = frame.into_geometry();
+
+        renderer.with_translation(
+            bounds.position() - Point::ORIGIN,
+            |renderer| {
+                renderer.draw(vec![geometry]);
+            },
+        );
+    }
+}
+
+impl<'a, Message, Theme> From<Circular<Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Theme: 'a,
+{
+    fn from(circular: Circular<Theme>) -> Self {
+        Self::new(circular)
+    }
+}
@@ -0,0 +1,202 @@
+use crate::core::event::{self, Event};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::time::{Duration, Instant};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::window;
+use crate::core::{
+    Clipboard, Element, Layout, Length, Rectangle, Shell, Size, Widget,
+};
+use crate::progress_indicator::{
+    phase, Appearance, DefaultStyle, Phase, Style, DEFAULT_CYCLE_DURATION,
+};
+
+/// An indeterminate progress indicator shaped like a bar.
+///
+/// The bar keeps growing and shrinking along its track while visible.
+#[allow(missing_debug_implementations)]
+pub struct Linear<Theme = crate::Theme> {
+    width: Length,
+    height: Length,
+    cycle_duration: Duration,
+    style: Style<Theme>,
+}
+
+impl<Theme> Linear<Theme> {
+    /// The default width of a [`Linear`] indicator.
+    pub const DEFAULT_WIDTH: f32 = 100.0;
+
+    /// The default height of a [`Linear`] indicator.
+    pub const DEFAULT_HEIGHT: f32 = 4.0;
+
+    /// Creates a new [`Linear`] indicator.
+    pub fn new() -> Self
+    where
+        Theme: DefaultStyle,
+    {
+        Self {
+            width: Length::Fixed(Self::DEFAULT_WIDTH),
+            height: Length::Fixed(Self::DEFAULT_HEIGHT),
+            cycle_duration: DEFAULT_CYCLE_DURATION,
+            style: Theme::default_style(),
+        }
+    }
+
+    /// Sets the width of the [`Linear`] indicator.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Linear`] indicator.
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the duration of a full cycle of the [`Linear`] indicator; that
+    /// is, the time it takes for its bar to grow and shrink once.
+    pub fn cycle_duration(mut self, duration: Duration) -> Self {
+        self.cycle_duration = duration;
+        self
+    }
+
+    /// Sets the style of the [`Linear`] indicator.
+    pub fn style(mut self, style: fn(&Theme) -> Appearance) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+impl<Theme> Default for Linear<Theme>
+where
+    Theme: DefaultStyle,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct State {
+    start: Instant,
+    now: Instant,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        let now = Instant::now();
+
+        Self { start: now, now }
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Linear<Theme>
+where
+    Renderer: crate::core::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::atomic(limits, self.width, self.height)
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        if let Event::Window(_, window::Event::RedrawRequested(now)) = event {
+            let state = tree.state.downcast_mut::<State>();
+
+            state.now = now;
+
+            if layout.bounds().intersects(viewport) {
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+            }
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let appearance = (self.style)(theme);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                ..renderer::Quad::default()
+            },
+            appearance.track,
+        );
+
+        let (_, phase) =
+            phase(state.now.duration_since(state.start), self.cycle_duration);
+
+        let (start, end) = match phase {
+            Phase::Expanding(progress) => (0.0, progress),
+            Phase::Contracting(progress) => (progress, 1.0),
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: bounds.x + start * bounds.width,
+                    width: (end - start) * bounds.width,
+                    ..bounds
+                },
+                ..renderer::Quad::default()
+            },
+            appearance.bar,
+        );
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Linear<Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: crate::core::Renderer + 'a,
+{
+    fn from(linear: Linear<Theme>) -> Self {
+        Self::new(linear)
+    }
+}
@@ -0,0 +1,659 @@
+//! Display an interactive selector of a range of values.
+use std::ops::RangeInclusive;
+
+pub use crate::slider::{
+    default, Appearance, DefaultStyle, Handle, HandleShape, Status, Style,
+};
+
+use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::keyboard::key::{self, Key};
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::text;
+use crate::core::touch;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::{
+    Border, Clipboard, Element, Length, Pixels, Rectangle, Shell, Size, Widget,
+};
+use crate::slider::Ticks;
+
+/// An horizontal bar and two handles that select a range of values from a
+/// range of values.
+///
+/// The handles cannot cross each other, and the segment between them can be
+/// dragged to move both at once.
+///
+/// # Example
+/// ```no_run
+/// # type RangeSlider<'a, T, Message> = iced_widget::RangeSlider<'a, T, Message>;
+/// #
+/// #[derive(Clone)]
+/// pub enum Message {
+///     PriceChanged((f32, f32)),
+/// }
+///
+/// let price = (20.0, 80.0);
+///
+/// RangeSlider::new(0.0..=100.0, price, Message::PriceChanged)
+///     .ticks([0.0, 25.0, 50.0, 75.0, 100.0])
+///     .tick_label(|price| format!("${price}"));
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct RangeSlider<'a, T, Message, Theme = crate::Theme> {
+    range: RangeInclusive<T>,
+    step: T,
+    shift_step: Option<T>,
+    values: (T, T),
+    on_change: Box<dyn Fn((T, T)) -> Message + 'a>,
+    on_release: Option<Message>,
+    width: Length,
+    height: f32,
+    ticks: Ticks<'a, T>,
+    style: Style<Theme>,
+}
+
+impl<'a, T, Message, Theme> RangeSlider<'a, T, Message, Theme>
+where
+    T: Copy + From<u8> + PartialOrd,
+    Message: Clone,
+{
+    /// The default height of a [`RangeSlider`].
+    pub const DEFAULT_HEIGHT: f32 = 16.0;
+
+    /// Creates a new [`RangeSlider`].
+    ///
+    /// It expects:
+    ///   * an inclusive range of possible values
+    ///   * the current start and end values of the [`RangeSlider`]
+    ///   * a function that will be called when the [`RangeSlider`] is
+    ///     dragged. It receives the new start and end values of the
+    ///     [`RangeSlider`] and must produce a `Message`.
+    pub fn new<F>(
+        range: RangeInclusive<T>,
+        values: (T, T),
+        on_change: F,
+    ) -> Self
+    where
+        Theme: DefaultStyle,
+        F: 'a + Fn((T, T)) -> Message,
+    {
+        let clamp = |value: T| {
+            if value < *range.start() {
+                *range.start()
+            } else if value > *range.end() {
+                *range.end()
+            } else {
+                value
+            }
+        };
+
+        let (start, end) = (clamp(values.0), clamp(values.1));
+
+        let values = if start <= end {
+            (start, end)
+        } else {
+            (end, start)
+        };
+
+        RangeSlider {
+            values,
+            range,
+            step: T::from(1),
+            shift_step: None,
+            on_change: Box::new(on_change),
+            on_release: None,
+            width: Length::Fill,
+            height: Self::DEFAULT_HEIGHT,
+            ticks: Ticks::default(),
+            style: Theme::default_style(),
+        }
+    }
+
+    /// Sets the release message of the [`RangeSlider`].
+    /// This is called when the mouse is released from the slider.
+    pub fn on_release(mut self, on_release: Message) -> Self {
+        self.on_release = Some(on_release);
+        self
+    }
+
+    /// Sets the width of the [`RangeSlider`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`RangeSlider`].
+    pub fn height(mut self, height: impl Into<Pixels>) -> Self {
+        self.height = height.into().0;
+        self
+    }
+
+    /// Sets the style of the [`RangeSlider`].
+    pub fn style(mut self, style: fn(&Theme, Status) -> Appearance) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Sets the step size of the [`RangeSlider`].
+    pub fn step(mut self, step: impl Into<T>) -> Self {
+        self.step = step.into();
+        self
+    }
+
+    /// Sets the optional "shift" step for the [`RangeSlider`].
+    ///
+    /// If set, this value is used as the step while the shift key is pressed.
+    pub fn shift_step(mut self, shift_step: impl Into<T>) -> Self {
+        self.shift_step = Some(shift_step.into());
+        self
+    }
+
+    /// Sets the values where tick marks are drawn along the [`RangeSlider`].
+    pub fn ticks(mut self, ticks: impl IntoIterator<Item = T>) -> Self {
+        self.ticks.values = ticks.into_iter().collect();
+        self
+    }
+
+    /// Sets the function used to label the tick marks of the
+    /// [`RangeSlider`].
+    pub fn tick_label(mut self, label: impl Fn(T) -> String + 'a) -> Self {
+        self.ticks.label = Some(Box::new(label));
+        self
+    }
+}
+
+/// The part of a [`RangeSlider`] being dragged.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+enum Drag {
+    #[default]
+    None,
+    Start,
+    End,
+    /// The segment between both handles, grabbed at the given value.
+    Segment {
+        grab: f64,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+struct State {
+    drag: Drag,
+    keyboard_modifiers: keyboard::Modifiers,
+}
+
+impl<'a, T, Message, Theme> RangeSlider<'a, T, Message, Theme>
+where
+    T: Copy + Into<f64> + num_traits::FromPrimitive,
+{
+    fn current_step(&self, modifiers: keyboard::Modifiers) -> f64 {
+        if modifiers.shift() {
+            self.shift_step.unwrap_or(self.step)
+        } else {
+            self.step
+        }
+        .into()
+    }
+
+    fn bounds(&self) -> (f64, f64) {
+        let (start, end) = self.range.clone().into_inner();
+
+        (start.into(), end.into())
+    }
+
+    /// Returns the value at the given horizontal position of the rail,
+    /// without snapping it to any step.
+    fn value_at(&self, x: f32, rail: Rectangle) -> f64 {
+        let (start, end) = self.bounds();
+        let percent = f64::from((x - rail.x) / rail.width).clamp(0.0, 1.0);
+
+        start + percent * (end - start)
+    }
+
+    fn publish(&mut self, values: (f64, f64), shell: &mut Shell<'_, Message>) {
+        let (Some(start), Some(end)) =
+            (T::from_f64(values.0), T::from_f64(values.1))
+        else {
+            return;
+        };
+
+        if (self.values.0.into() - values.0).abs() > f64::EPSILON
+            || (self.values.1.into() - values.1).abs() > f64::EPSILON
+        {
+            shell.publish((self.on_change)((start, end)));
+
+            self.values = (start, end);
+        }
+    }
+}
+
+/// Snaps the value to the closest step from the start of the range.
+fn snap(value: f64, (start, end): (f64, f64), step: f64) -> f64 {
+    if step <= 0.0 {
+        return value.clamp(start, end);
+    }
+
+    (((value - start) / step).round() * step + start).clamp(start, end)
+}
+
+/// Returns the handle closest to the given value.
+///
+/// When both handles are equally close, like when they overlap, the one
+/// in the given direction is picked; so the range can always be widened.
+fn closest((low, high): (f64, f64), value: f64, direction: f64) -> Drag {
+    let to_low = (value - low).abs();
+    let to_high = (value - high).abs();
+
+    if to_low < to_high || (to_low == to_high && direction <= 0.0) {
+        Drag::Start
+    } else {
+        Drag::End
+    }
+}
+
+/// Returns the values of a selected range after moving the handle or
+/// segment being dragged to the given value.
+fn drag(
+    drag: Drag,
+    (low, high): (f64, f64),
+    value: f64,
+    bounds: (f64, f64),
+    step: f64,
+) -> (f64, f64) {
+    match drag {
+        Drag::None => (low, high),
+        Drag::Start => (snap(value, bounds, step).min(high), high),
+        Drag::End => (low, snap(value, bounds, step).max(low)),
+        Drag::Segment { grab } => {
+            let width = high - low;
+
+            let delta = if step > 0.0 {
+                ((value - grab) / step).round() * step
+            } else {
+                value - grab
+            };
+
+            let low = (low + delta).clamp(bounds.0, bounds.1 - width);
+
+            (low, low + width)
+        }
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for RangeSlider<'a, T, Message, Theme>
+where
+    T: Copy + Into<f64> + num_traits::FromPrimitive,
+    Message: Clone,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::atomic(
+            limits,
+            self.width,
+            self.height + self.ticks.cross_size(renderer, false),
+        )
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+        let step = self.current_step(state.keyboard_modifiers);
+        let values = (self.values.0.into(), self.values.1.into());
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if let Some(cursor_position) = cursor.position_over(bounds) {
+                    let value = self.value_at(cursor_position.x, bounds);
+                    let (low, high) = values;
+
+                    state.drag = if value > low && value < high {
+                        Drag::Segment { grab: value }
+                    } else {
+                        closest(values, value, value - high)
+                    };
+
+                    if !matches!(state.drag, Drag::Segment { .. }) {
+                        self.publish(
+                            drag(
+                                state.drag,
+                                values,
+                                value,
+                                self.bounds(),
+                                step,
+                            ),
+                            shell,
+                        );
+                    }
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. })
+                if state.drag != Drag::None =>
+            {
+                if let Some(on_release) = self.on_release.clone() {
+                    shell.publish(on_release);
+                }
+
+                state.drag = Drag::None;
+
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. })
+                if state.drag != Drag::None =>
+            {
+                if let Some(cursor_position) = cursor.position() {
+                    let value = self.value_at(cursor_position.x, bounds);
+
+                    // Overlapping handles follow the direction of the drag
+                    if values.0 == values.1
+                        && matches!(state.drag, Drag::Start | Drag::End)
+                    {
+                        state.drag = closest(values, value, value - values.1);
+                    }
+
+                    let new_values =
+                        drag(state.drag, values, value, self.bounds(), step);
+
+                    if let Drag::Segment { grab } = state.drag {
+                        state.drag = Drag::Segment {
+                            grab: grab + (new_values.0 - values.0),
+                        };
+                    }
+
+                    self.publish(new_values, shell);
+                }
+
+                return event::Status::Captured;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed { key, .. }) => {
+                if let Some(cursor_position) = cursor.position_over(bounds) {
+                    let delta = match key {
+                        Key::Named(key::Named::ArrowUp) => step,
+                        Key::Named(key::Named::ArrowDown) => -step,
+                        _ => return event::Status::Captured,
+                    };
+
+                    // Move the handle closest to the cursor
+                    let value = self.value_at(cursor_position.x, bounds);
+                    let (low, high) = values;
+                    let handle = closest(values, value, delta);
+
+                    let target = match handle {
+                        Drag::Start => low + delta,
+                        _ => high + delta,
+                    };
+
+                    self.publish(
+                        drag(handle, values, target, self.bounds(), step),
+                        shell,
+                    );
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                state.keyboard_modifiers = modifiers;
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        renderer_style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let is_mouse_over = cursor.is_over(layout.bounds());
+
+        let bounds = Rectangle {
+            height: self.height,
+            ..layout.bounds()
+        };
+
+        let style = (self.style)(
+            theme,
+            if state.drag != Drag::None {
+                Status::Dragged
+            } else if is_mouse_over {
+                Status::Hovered
+            } else {
+                Status::Active
+            },
+        );
+
+        let (handle_width, handle_height, handle_border_radius) =
+            match style.handle.shape {
+                HandleShape::Circle { radius } => {
+                    (radius * 2.0, radius * 2.0, radius.into())
+                }
+                HandleShape::Rectangle {
+                    width,
+                    border_radius,
+                } => (f32::from(width), bounds.height, border_radius),
+            };
+
+        let (range_start, range_end) = {
+            let (start, end) = self.bounds();
+
+            (start as f32, end as f32)
+        };
+
+        let position = |value: f32| {
+            if range_start >= range_end {
+                0.0
+            } else {
+                (bounds.width - handle_width) * (value - range_start)
+                    / (range_end - range_start)
+            }
+        };
+
+        let start_offset = position(self.values.0.into() as f32);
+        let end_offset = position(self.values.1.into() as f32);
+
+        self.ticks.draw(
+            renderer,
+            bounds,
+            |value| bounds.x + handle_width / 2.0 + position(value),
+            false,
+            style.rail.colors.1,
+            renderer_style.text_color,
+            viewport,
+        );
+
+        let rail_y = bounds.y + bounds.height / 2.0;
+
+        let mut rail = |x: f32, width: f32, color| {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x,
+                        y: rail_y - style.rail.width / 2.0,
+                        width,
+                        height: style.rail.width,
+                    },
+                    border: Border::rounded(style.rail.border_radius),
+                    ..renderer::Quad::default()
+                },
+                color,
+            );
+        };
+
+        rail(
+            bounds.x,
+            start_offset + handle_width / 2.0,
+            style.rail.colors.1,
+        );
+
+        rail(
+            bounds.x + start_offset + handle_width / 2.0,
+            end_offset - start_offset,
+            style.rail.colors.0,
+        );
+
+        rail(
+            bounds.x + end_offset + handle_width / 2.0,
+            bounds.width - end_offset - handle_width / 2.0,
+            style.rail.colors.1,
+        );
+
+        for offset in [start_offset, end_offset] {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: bounds.x + offset,
+                        y: rail_y - handle_height / 2.0,
+                        width: handle_width,
+                        height: handle_height,
+                    },
+                    border: Border {
+                        radius: handle_border_radius,
+                        width: style.handle.border_width,
+                        color: style.handle.border_color,
+                    },
+                    ..renderer::Quad::default()
+                },
+                style.handle.color,
+            );
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        let is_mouse_over = cursor.is_over(layout.bounds());
+
+        if state.drag != Drag::None {
+            mouse::Interaction::Grabbing
+        } else if is_mouse_over {
+            mouse::Interaction::Grab
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer> From<RangeSlider<'a, T, Message, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    T: Copy + Into<f64> + num_traits::FromPrimitive + 'a,
+    Message: Clone + 'a,
+    Theme: 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(
+        slider: RangeSlider<'a, T, Message, Theme>,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(slider)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BOUNDS: (f64, f64) = (0.0, 100.0);
+
+    #[test]
+    fn handles_do_not_cross() {
+        assert_eq!(
+            drag(Drag::Start, (20.0, 60.0), 80.0, BOUNDS, 1.0),
+            (60.0, 60.0)
+        );
+        assert_eq!(
+            drag(Drag::End, (20.0, 60.0), 10.0, BOUNDS, 1.0),
+            (20.0, 20.0)
+        );
+        assert_eq!(
+            drag(Drag::Start, (20.0, 60.0), 32.4, BOUNDS, 5.0),
+            (30.0, 60.0)
+        );
+    }
+
+    #[test]
+    fn overlapping_handles_can_be_pulled_apart() {
+        let values = (50.0, 50.0);
+
+        let handle = closest(values, 70.0, 70.0 - 50.0);
+        assert_eq!(handle, Drag::End);
+        assert_eq!(drag(handle, values, 70.0, BOUNDS, 1.0), (50.0, 70.0));
+
+        let handle = closest(values, 30.0, 30.0 - 50.0);
+        assert_eq!(handle, Drag::Start);
+        assert_eq!(drag(handle, values, 30.0, BOUNDS, 1.0), (30.0, 50.0));
+
+        // Arrow keys pick the handle in the direction of the step
+        let handle = closest(values, 50.0, 1.0);
+        assert_eq!(handle, Drag::End);
+        assert_eq!(drag(handle, values, 51.0, BOUNDS, 1.0), (50.0, 51.0));
+
+        let handle = closest(values, 50.0, -1.0);
+        assert_eq!(handle, Drag::Start);
+        assert_eq!(drag(handle, values, 49.0, BOUNDS, 1.0), (49.0, 50.0));
+    }
+
+    #[test]
+    fn segment_keeps_its_width() {
+        let segment = Drag::Segment { grab: 40.0 };
+
+        assert_eq!(
+            drag(segment, (20.0, 60.0), 50.0, BOUNDS, 1.0),
+            (30.0, 70.0)
+        );
+        assert_eq!(
+            drag(segment, (20.0, 60.0), 90.0, BOUNDS, 1.0),
+            (60.0, 100.0)
+        );
+        assert_eq!(drag(segment, (20.0, 60.0), 0.0, BOUNDS, 1.0), (0.0, 40.0));
+    }
+}
@@ -1,4 +1,15 @@
 //! Navigate an endless amount of content with a scrollbar.
+mod fade;
+mod kinetic;
+mod sticky;
+
+pub use fade::Fade;
+pub use kinetic::Kinetic;
+pub use sticky::Sticky;
+
+use fade::Visibility;
+use kinetic::{Animation, Tracker};
+
 // use crate::container;
 use crate::container;
 use crate::core::event::{self, Event};
@@ -7,17 +18,19 @@ use crate::core::layout;
 use crate::core::mouse;
 use crate::core::overlay;
 use crate::core::renderer;
+use crate::core::time::{Duration, Instant};
 use crate::core::touch;
 use crate::core::widget;
 use crate::core::widget::operation::{self, Operation};
 use crate::core::widget::tree::{self, Tree};
+use crate::core::window;
 use crate::core::{
     Background, Border, Clipboard, Color, Element, Layout, Length, Pixels,
     Point, Rectangle, Shell, Size, Theme, Vector, Widget,
 };
 use crate::runtime::Command;
 
-pub use operation::scrollable::{AbsoluteOffset, RelativeOffset};
+pub use operation::scrollable::{AbsoluteOffset, Anchor, RelativeOffset};
 
 /// A widget that can vertically display an infinite amount of content with a
 /// scrollbar.
@@ -34,6 +47,7 @@ pub struct Scrollable<
     width: Length,
     height: Length,
     direction: Direction,
+    kinetic: Kinetic,
     content: Element<'a, Message, Theme, Renderer>,
     on_scroll: Option<Box<dyn Fn(Viewport) -> Message + 'a>>,
     style: Style<Theme>,
@@ -93,6 +107,7 @@ where
             width: Length::Shrink,
             height: Length::Shrink,
             direction,
+            kinetic: Kinetic::default(),
             content,
             on_scroll: None,
             style: style.into(),
@@ -117,6 +132,12 @@ where
         self
     }
 
+    /// Sets the [`Kinetic`] scrolling physics of the [`Scrollable`].
+    pub fn kinetic(mut self, kinetic: Kinetic) -> Self {
+        self.kinetic = kinetic;
+        self
+    }
+
     /// Sets a function to call when the [`Scrollable`] is scrolled.
     ///
     /// The function takes the [`Viewport`] of the [`Scrollable`]
@@ -166,6 +187,17 @@ impl Direction {
             Self::Horizontal(_) => None,
         }
     }
+
+    /// Returns the longest time an interaction keeps the overlay scrollbars
+    /// on screen, if any.
+    fn overlay_lifetime(&self) -> Option<Duration> {
+        self.horizontal()
+            .into_iter()
+            .chain(self.vertical())
+            .filter_map(|properties| properties.overlay)
+            .map(|fade| fade.lifetime())
+            .max()
+    }
 }
 
 impl Default for Direction {
@@ -181,6 +213,7 @@ pub struct Properties {
     margin: f32,
     scroller_width: f32,
     alignment: Alignment,
+    overlay: Option<Fade>,
 }
 
 impl Default for Properties {
@@ -190,6 +223,7 @@ impl Default for Properties {
             margin: 0.0,
             scroller_width: 10.0,
             alignment: Alignment::Start,
+            overlay: None,
         }
     }
 }
@@ -223,6 +257,14 @@ impl Properties {
         self.alignment = alignment;
         self
     }
+
+    /// Turns the scrollbar of the [`Scrollable`] into an overlay that is only
+    /// visible while the [`Scrollable`] is hovered or scrolled, with the given
+    /// [`Fade`] timing.
+    pub fn overlay(mut self, fade: Fade) -> Self {
+        self.overlay = Some(fade);
+        self
+    }
 }
 
 /// Alignment of the scrollable's content relative to it's [`Viewport`] in one direction.
@@ -245,7 +287,10 @@ where
     }
 
     fn state(&self) -> tree::State {
-        tree::State::new(State::new())
+        tree::State::new(State {
+            kinetic: self.kinetic,
+            ..State::new()
+        })
     }
 
     fn children(&self) -> Vec<Tree> {
@@ -253,6 +298,7 @@ where
     }
 
     fn diff(&self, tree: &mut Tree) {
+        tree.state.downcast_mut::<State>().kinetic = self.kinetic;
         tree.diff_children(std::slice::from_ref(&self.content));
     }
 
@@ -310,7 +356,12 @@ where
             state.translation(self.direction, bounds, content_bounds);
 
         operation.scrollable(
-            state,
+            &mut Operand {
+                state,
+                direction: self.direction,
+                bounds,
+                content_bounds,
+            },
             self.id.as_ref().map(|id| &id.0),
             bounds,
             translation,
@@ -348,6 +399,46 @@ where
         let content = layout.children().next().unwrap();
         let content_bounds = content.bounds();
 
+        if let Some(lifetime) = self.direction.overlay_lifetime() {
+            match event {
+                Event::Mouse(
+                    mouse::Event::CursorMoved { .. }
+                    | mouse::Event::WheelScrolled { .. },
+                )
+                | Event::Touch(_)
+                    if cursor_over_scrollable.is_some() =>
+                {
+                    state.scrollbars.activate(Instant::now(), lifetime);
+
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+                }
+                _ => {}
+            }
+        }
+
+        if let Event::Window(_, window::Event::RedrawRequested(now)) = event {
+            if state.tick(now, self.direction, bounds, content_bounds) {
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+            }
+
+            if let Some(request) = state.fade_scrollbars(
+                now,
+                self.direction,
+                bounds,
+                content_bounds,
+            ) {
+                shell.request_redraw(request);
+            }
+
+            notify_on_scroll(
+                state,
+                &self.on_scroll,
+                bounds,
+                content_bounds,
+                shell,
+            );
+        }
+
         let scrollbars =
             Scrollbars::new(state, self.direction, bounds, content_bounds);
 
@@ -408,7 +499,9 @@ where
                     return event::Status::Ignored;
                 }
 
-                let delta = match delta {
+                state.velocity = Vector::ZERO;
+
+                match delta {
                     mouse::ScrollDelta::Lines { x, y } => {
                         // TODO: Configurable speed/friction (?)
                         let movement = if state.keyboard_modifiers.shift() {
@@ -417,12 +510,57 @@ where
                             Vector::new(x, y)
                         };
 
-                        movement * 60.0
+                        let delta = movement * 60.0;
+
+                        if let Some(duration) = self.kinetic.smooth {
+                            state.animate_by(
+                                delta,
+                                self.direction,
+                                bounds,
+                                content_bounds,
+                                duration,
+                            );
+
+                            shell.request_redraw(
+                                window::RedrawRequest::NextFrame,
+                            );
+                        } else {
+                            state.scroll(
+                                delta,
+                                self.direction,
+                                bounds,
+                                content_bounds,
+                            );
+                        }
                     }
-                    mouse::ScrollDelta::Pixels { x, y } => Vector::new(x, y),
-                };
+                    mouse::ScrollDelta::Pixels { x, y } => {
+                        let delta = Vector::new(x, y);
 
-                state.scroll(delta, self.direction, bounds, content_bounds);
+                        state.animation = None;
+
+                        state.scroll(
+                            delta,
+                            self.direction,
+                            bounds,
+                            content_bounds,
+                        );
+
+                        if self.kinetic.deceleration.is_some() {
+                            let now = Instant::now();
+
+                            state.tracker = Some(Tracker::track(
+                                state.tracker,
+                                delta,
+                                now,
+                                false,
+                            ));
+
+                            shell.request_redraw(window::RedrawRequest::At(
+                                now + kinetic::FLING_DELAY,
+                            ));
+                        }
+                    }
+                }
 
                 notify_on_scroll(
                     state,
@@ -445,6 +583,7 @@ where
                         };
 
                         state.scroll_area_touched_at = Some(cursor_position);
+                        state.stop();
                     }
                     touch::Event::FingerMoved { .. } => {
                         if let Some(scroll_box_touched_at) =
@@ -460,16 +599,26 @@ where
                                 cursor_position.y - scroll_box_touched_at.y,
                             );
 
-                            state.scroll(
+                            state.scroll_by(
                                 delta,
                                 self.direction,
                                 bounds,
                                 content_bounds,
+                                self.kinetic.bounce,
                             );
 
                             state.scroll_area_touched_at =
                                 Some(cursor_position);
 
+                            if self.kinetic.deceleration.is_some() {
+                                state.tracker = Some(Tracker::track(
+                                    state.tracker,
+                                    delta,
+                                    Instant::now(),
+                                    true,
+                                ));
+                            }
+
                             notify_on_scroll(
                                 state,
                                 &self.on_scroll,
@@ -482,6 +631,20 @@ where
                     touch::Event::FingerLifted { .. }
                     | touch::Event::FingerLost { .. } => {
                         state.scroll_area_touched_at = None;
+
+                        if let Some(velocity) = state
+                            .tracker
+                            .take()
+                            .and_then(|tracker| tracker.fling(Instant::now()))
+                        {
+                            state.velocity = velocity;
+                        }
+
+                        if state.is_moving() {
+                            shell.request_redraw(
+                                window::RedrawRequest::NextFrame,
+                            );
+                        }
                     }
                 }
 
@@ -739,10 +902,24 @@ where
                 );
             });
 
+            let opacity = |properties: Option<&Properties>, is_shown: bool| {
+                match properties.and_then(|properties| properties.overlay) {
+                    Some(fade) if !is_shown => state.scrollbars.opacity(fade),
+                    _ => 1.0,
+                }
+            };
+
             let draw_scrollbar =
                 |renderer: &mut Renderer,
                  style: Scrollbar,
-                 scrollbar: &internals::Scrollbar| {
+                 scrollbar: &internals::Scrollbar,
+                 opacity: f32| {
+                    if opacity <= 0.0 {
+                        return;
+                    }
+
+                    let style = style.scale_alpha(opacity);
+
                     if scrollbar.bounds.width > 0.0
                         && scrollbar.bounds.height > 0.0
                         && (style.background.is_some()
@@ -791,6 +968,11 @@ where
                             renderer,
                             appearance.vertical_scrollbar,
                             &scrollbar,
+                            opacity(
+                                self.direction.vertical(),
+                                mouse_over_y_scrollbar
+                                    || state.y_scroller_grabbed_at.is_some(),
+                            ),
                         );
                     }
 
@@ -799,10 +981,19 @@ where
                             renderer,
                             appearance.horizontal_scrollbar,
                             &scrollbar,
+                            opacity(
+                                self.direction.horizontal(),
+                                mouse_over_x_scrollbar
+                                    || state.x_scroller_grabbed_at.is_some(),
+                            ),
                         );
                     }
 
-                    if let (Some(x), Some(y)) = (scrollbars.x, scrollbars.y) {
+                    if let (Some(x), Some(y), None) = (
+                        scrollbars.x,
+                        scrollbars.y,
+                        self.direction.overlay_lifetime(),
+                    ) {
                         let background =
                             appearance.gap.or(appearance.container.background);
 
@@ -975,6 +1166,19 @@ pub fn scroll_to<Message: 'static>(
     Command::widget(operation::scrollable::scroll_to(id.0, offset))
 }
 
+/// Produces a [`Command`] that scrolls every [`Scrollable`] containing the
+/// widget with the given [`Id`](widget::Id), so that the widget becomes
+/// visible.
+pub fn scroll_into_view<Message: 'static>(
+    target: impl Into<widget::Id>,
+    anchor: Anchor,
+) -> Command<Message> {
+    Command::widget(operation::scrollable::scroll_into_view(
+        target.into(),
+        anchor,
+    ))
+}
+
 fn notify_on_scroll<Message>(
     state: &mut State,
     on_scroll: &Option<Box<dyn Fn(Viewport) -> Message + '_>>,
@@ -1031,6 +1235,18 @@ struct State {
     x_scroller_grabbed_at: Option<f32>,
     keyboard_modifiers: keyboard::Modifiers,
     last_notified: Option<Viewport>,
+    kinetic: Kinetic,
+    /// The distance the content has been dragged past its edges.
+    overscroll: Vector,
+    animation: Option<Animation>,
+    /// The velocity of the current fling, in pixels per second.
+    velocity: Vector,
+    tracker: Option<Tracker>,
+    last_frame: Option<Instant>,
+    scrollbars: Visibility,
+    /// The translation of the [`State`] when its overlay scrollbars were last
+    /// faded.
+    faded_translation: Option<Vector>,
 }
 
 impl Default for State {
@@ -1043,22 +1259,56 @@ impl Default for State {
             x_scroller_grabbed_at: None,
             keyboard_modifiers: keyboard::Modifiers::default(),
             last_notified: None,
+            kinetic: Kinetic::default(),
+            overscroll: Vector::ZERO,
+            animation: None,
+            velocity: Vector::ZERO,
+            tracker: None,
+            last_frame: None,
+            scrollbars: Visibility::default(),
+            faded_translation: None,
         }
     }
 }
 
-impl operation::Scrollable for State {
+/// The [`State`] of a [`Scrollable`] being operated on.
+struct Operand<'a> {
+    state: &'a mut State,
+    direction: Direction,
+    bounds: Rectangle,
+    content_bounds: Rectangle,
+}
+
+impl operation::Scrollable for Operand<'_> {
     fn snap_to(&mut self, offset: RelativeOffset) {
-        State::snap_to(self, offset);
+        let from = (self.state.offset_x, self.state.offset_y);
+
+        self.state.snap_to(offset);
+        self.state.animate_from(from);
     }
 
     fn scroll_to(&mut self, offset: AbsoluteOffset) {
-        State::scroll_to(self, offset);
+        let from = (self.state.offset_x, self.state.offset_y);
+
+        self.state.scroll_to(offset);
+        self.state.animate_from(from);
+    }
+
+    fn scroll_by(&mut self, offset: AbsoluteOffset) {
+        let from = (self.state.offset_x, self.state.offset_y);
+
+        self.state.scroll(
+            Vector::new(-offset.x, -offset.y),
+            self.direction,
+            self.bounds,
+            self.content_bounds,
+        );
+        self.state.animate_from(from);
     }
 }
 
 #[derive(Debug, Clone, Copy)]
-enum Offset {
+pub(super) enum Offset {
     Absolute(f32),
     Relative(f32),
 }
@@ -1161,6 +1411,19 @@ impl State {
         direction: Direction,
         bounds: Rectangle,
         content_bounds: Rectangle,
+    ) {
+        self.scroll_by(delta, direction, bounds, content_bounds, false);
+    }
+
+    /// Apply a scrolling offset to the current [`State`], letting the content
+    /// go past its edges if `is_elastic`.
+    fn scroll_by(
+        &mut self,
+        delta: Vector<f32>,
+        direction: Direction,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+        is_elastic: bool,
     ) {
         let horizontal_alignment = direction
             .horizontal()
@@ -1182,23 +1445,226 @@ impl State {
             align(vertical_alignment, delta.y),
         );
 
+        let scroll = |offset: Offset,
+                      overscroll: f32,
+                      delta: f32,
+                      viewport: f32,
+                      content: f32| {
+            let target =
+                offset.absolute(viewport, content) + overscroll - delta;
+            let clamped = target.clamp(0.0, content - viewport);
+
+            let overscroll = if is_elastic {
+                kinetic::rubber_band(overscroll, target - clamped)
+            } else {
+                0.0
+            };
+
+            (Offset::Absolute(clamped), overscroll)
+        };
+
         if bounds.height < content_bounds.height {
-            self.offset_y = Offset::Absolute(
-                (self.offset_y.absolute(bounds.height, content_bounds.height)
-                    - delta.y)
-                    .clamp(0.0, content_bounds.height - bounds.height),
+            (self.offset_y, self.overscroll.y) = scroll(
+                self.offset_y,
+                self.overscroll.y,
+                delta.y,
+                bounds.height,
+                content_bounds.height,
             );
         }
 
         if bounds.width < content_bounds.width {
-            self.offset_x = Offset::Absolute(
-                (self.offset_x.absolute(bounds.width, content_bounds.width)
-                    - delta.x)
-                    .clamp(0.0, content_bounds.width - bounds.width),
+            (self.offset_x, self.overscroll.x) = scroll(
+                self.offset_x,
+                self.overscroll.x,
+                delta.x,
+                bounds.width,
+                content_bounds.width,
             );
         }
     }
 
+    /// Animates a scrolling offset, continuing the current animation if any.
+    fn animate_by(
+        &mut self,
+        delta: Vector<f32>,
+        direction: Direction,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+        duration: Duration,
+    ) {
+        let mut target = *self;
+
+        if let Some(animation) = self.animation {
+            (target.offset_x, target.offset_y) = animation.to;
+        }
+
+        target.scroll(delta, direction, bounds, content_bounds);
+
+        self.animation = Some(Animation::new(
+            (self.offset_x, self.offset_y),
+            (target.offset_x, target.offset_y),
+            duration,
+        ));
+    }
+
+    /// Animates the current offsets from the given ones, if smooth scrolling
+    /// is enabled.
+    fn animate_from(&mut self, from: (Offset, Offset)) {
+        self.stop();
+        self.overscroll = Vector::ZERO;
+
+        if let Some(duration) = self.kinetic.smooth {
+            let to = (self.offset_x, self.offset_y);
+
+            (self.offset_x, self.offset_y) = from;
+            self.animation = Some(Animation::new(from, to, duration));
+        }
+    }
+
+    /// Stops any animation or fling in progress.
+    fn stop(&mut self) {
+        self.animation = None;
+        self.velocity = Vector::ZERO;
+        self.tracker = None;
+    }
+
+    /// Returns whether the [`State`] is animating, flinging, or bouncing
+    /// back.
+    fn is_moving(&self) -> bool {
+        self.animation.is_some()
+            || self.velocity != Vector::ZERO
+            || (self.overscroll != Vector::ZERO
+                && self.scroll_area_touched_at.is_none())
+    }
+
+    /// Advances the fading of the overlay scrollbars of the [`State`] to the
+    /// given time, showing them if it has been scrolled.
+    ///
+    /// Returns when the scrollbars need to be redrawn, if ever.
+    fn fade_scrollbars(
+        &mut self,
+        now: Instant,
+        direction: Direction,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) -> Option<window::RedrawRequest> {
+        let lifetime = direction.overlay_lifetime()?;
+
+        let translation =
+            self.clamped_translation(direction, bounds, content_bounds);
+
+        if self
+            .faded_translation
+            .replace(translation)
+            .is_some_and(|faded| faded != translation)
+        {
+            self.scrollbars.activate(now, lifetime);
+        }
+
+        self.scrollbars.now = now;
+
+        let next_redraw = direction
+            .horizontal()
+            .into_iter()
+            .chain(direction.vertical())
+            .filter_map(|properties| properties.overlay)
+            .filter_map(|fade| self.scrollbars.next_redraw(fade))
+            .min()?;
+
+        Some(if next_redraw > now {
+            window::RedrawRequest::At(next_redraw)
+        } else {
+            window::RedrawRequest::NextFrame
+        })
+    }
+
+    /// Advances any animation, fling, or bounce of the [`State`] to the given
+    /// time.
+    ///
+    /// Returns whether the [`State`] is still moving.
+    fn tick(
+        &mut self,
+        now: Instant,
+        direction: Direction,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) -> bool {
+        let dt = self.last_frame.map_or(0.0, |last_frame| {
+            now.saturating_duration_since(last_frame).as_secs_f32()
+        });
+
+        if let Some(animation) = &mut self.animation {
+            let progress = animation.progress(now);
+
+            if progress >= 1.0 {
+                (self.offset_x, self.offset_y) = animation.to;
+                self.animation = None;
+            } else {
+                let interpolate =
+                    |from: Offset, to: Offset, viewport, content| {
+                        let from = from.absolute(viewport, content);
+                        let to = to.absolute(viewport, content);
+
+                        Offset::Absolute(from + (to - from) * progress)
+                    };
+
+                self.offset_x = interpolate(
+                    animation.from.0,
+                    animation.to.0,
+                    bounds.width,
+                    content_bounds.width,
+                );
+                self.offset_y = interpolate(
+                    animation.from.1,
+                    animation.to.1,
+                    bounds.height,
+                    content_bounds.height,
+                );
+            }
+        }
+
+        // Trackpads keep sending deltas until they are flung
+        if let Some(tracker) = self.tracker.filter(|tracker| {
+            !tracker.is_touch
+                && now.saturating_duration_since(tracker.at)
+                    >= kinetic::FLING_DELAY
+        }) {
+            self.tracker = None;
+            self.velocity = tracker.fling(now).unwrap_or(Vector::ZERO);
+        }
+
+        if self.velocity != Vector::ZERO {
+            self.scroll_by(
+                self.velocity * dt,
+                direction,
+                bounds,
+                content_bounds,
+                self.kinetic.bounce,
+            );
+
+            self.velocity = kinetic::decelerate(
+                self.velocity,
+                self.kinetic.deceleration.unwrap_or_default(),
+                self.overscroll != Vector::ZERO,
+                dt,
+            );
+        }
+
+        if self.scroll_area_touched_at.is_none() {
+            self.overscroll = Vector::new(
+                kinetic::relax(self.overscroll.x, dt),
+                kinetic::relax(self.overscroll.y, dt),
+            );
+        }
+
+        let is_moving = self.is_moving();
+
+        self.last_frame = is_moving.then_some(now);
+
+        is_moving
+    }
+
     /// Scrolls the [`Scrollable`] to a relative amount along the y axis.
     ///
     /// `0` represents scrollbar at the beginning, while `1` represents scrollbar at
@@ -1210,6 +1676,8 @@ impl State {
         content_bounds: Rectangle,
     ) {
         self.offset_y = Offset::Relative(percentage.clamp(0.0, 1.0));
+        self.overscroll.y = 0.0;
+        self.stop();
         self.unsnap(bounds, content_bounds);
     }
 
@@ -1224,6 +1692,8 @@ impl State {
         content_bounds: Rectangle,
     ) {
         self.offset_x = Offset::Relative(percentage.clamp(0.0, 1.0));
+        self.overscroll.x = 0.0;
+        self.stop();
         self.unsnap(bounds, content_bounds);
     }
 
@@ -1257,6 +1727,30 @@ impl State {
         direction: Direction,
         bounds: Rectangle,
         content_bounds: Rectangle,
+    ) -> Vector {
+        let align = |alignment: Alignment, overscroll: f32| match alignment {
+            Alignment::Start => overscroll,
+            Alignment::End => -overscroll,
+        };
+
+        self.clamped_translation(direction, bounds, content_bounds)
+            + Vector::new(
+                direction.horizontal().map_or(0.0, |horizontal| {
+                    align(horizontal.alignment, self.overscroll.x)
+                }),
+                direction.vertical().map_or(0.0, |vertical| {
+                    align(vertical.alignment, self.overscroll.y)
+                }),
+            )
+    }
+
+    /// Returns the scrolling translation of the [`State`], ignoring any
+    /// overscroll.
+    fn clamped_translation(
+        &self,
+        direction: Direction,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
     ) -> Vector {
         Vector::new(
             if let Some(horizontal) = direction.horizontal() {
@@ -1302,7 +1796,8 @@ impl Scrollbars {
         bounds: Rectangle,
         content_bounds: Rectangle,
     ) -> Self {
-        let translation = state.translation(direction, bounds, content_bounds);
+        let translation =
+            state.clamped_translation(direction, bounds, content_bounds);
 
         let show_scrollbar_x = direction
             .horizontal()
@@ -1599,6 +2094,29 @@ pub struct Scrollbar {
     pub scroller: Scroller,
 }
 
+impl Scrollbar {
+    /// Scales the alpha channel of the colors of the [`Scrollbar`] by the
+    /// given factor.
+    pub fn scale_alpha(self, factor: f32) -> Self {
+        Self {
+            background: self
+                .background
+                .map(|background| background.scale_alpha(factor)),
+            border: Border {
+                color: self.border.color.scale_alpha(factor),
+                ..self.border
+            },
+            scroller: Scroller {
+                color: self.scroller.color.scale_alpha(factor),
+                border: Border {
+                    color: self.scroller.border.color.scale_alpha(factor),
+                    ..self.scroller.border
+                },
+            },
+        }
+    }
+}
+
 /// The appearance of the scroller of a scrollable.
 #[derive(Debug, Clone, Copy)]
 pub struct Scroller {
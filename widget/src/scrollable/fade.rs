@@ -0,0 +1,155 @@
+use crate::core::time::{Duration, Instant};
+
+/// The fade timing of an overlay scrollbar.
+///
+/// An overlay scrollbar fades in while its [`Scrollable`] is hovered or
+/// scrolled, and fades out once it stays idle for the [`delay`].
+///
+/// [`Scrollable`]: crate::Scrollable
+/// [`delay`]: Self::delay
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Fade {
+    /// The time the scrollbar stays visible after the last interaction.
+    pub delay: Duration,
+    /// The duration of the fade in and out.
+    pub duration: Duration,
+}
+
+impl Default for Fade {
+    fn default() -> Self {
+        Self {
+            delay: Duration::from_millis(1000),
+            duration: Duration::from_millis(200),
+        }
+    }
+}
+
+impl Fade {
+    /// Returns the time an interaction keeps the scrollbar on screen.
+    pub(super) fn lifetime(&self) -> Duration {
+        self.delay + self.duration
+    }
+}
+
+/// The visibility of the overlay scrollbars of a [`Scrollable`].
+///
+/// [`Scrollable`]: crate::Scrollable
+#[derive(Debug, Clone, Copy)]
+pub(super) struct Visibility {
+    shown_at: Option<Instant>,
+    active_at: Option<Instant>,
+    pub now: Instant,
+}
+
+impl Default for Visibility {
+    fn default() -> Self {
+        Self {
+            shown_at: None,
+            active_at: None,
+            now: Instant::now(),
+        }
+    }
+}
+
+impl Visibility {
+    /// Shows the scrollbars, given the longest time an interaction keeps them
+    /// on screen.
+    pub fn activate(&mut self, now: Instant, lifetime: Duration) {
+        let is_hidden = self
+            .active_at
+            .is_none_or(|active_at| now >= active_at + lifetime);
+
+        if is_hidden {
+            self.shown_at = Some(now);
+        }
+
+        self.active_at = Some(now);
+    }
+
+    /// Returns the opacity of a scrollbar with the given [`Fade`].
+    pub fn opacity(&self, fade: Fade) -> f32 {
+        let (Some(shown_at), Some(active_at)) = (self.shown_at, self.active_at)
+        else {
+            return 0.0;
+        };
+
+        let progress = |since: Instant| {
+            let elapsed = self.now.saturating_duration_since(since);
+
+            if fade.duration.is_zero() {
+                1.0
+            } else {
+                (elapsed.as_secs_f32() / fade.duration.as_secs_f32()).min(1.0)
+            }
+        };
+
+        let fade_in = progress(shown_at);
+
+        let fade_out = if self.now > active_at + fade.delay {
+            progress(active_at + fade.delay)
+        } else {
+            0.0
+        };
+
+        fade_in.min(1.0 - fade_out)
+    }
+
+    /// Returns when a scrollbar with the given [`Fade`] needs to be redrawn
+    /// next, if ever.
+    ///
+    /// An instant that is not in the future means the next frame.
+    pub fn next_redraw(&self, fade: Fade) -> Option<Instant> {
+        let (Some(shown_at), Some(active_at)) = (self.shown_at, self.active_at)
+        else {
+            return None;
+        };
+
+        let fade_out = active_at + fade.delay;
+
+        if self.now < shown_at + fade.duration {
+            Some(self.now)
+        } else if self.now < fade_out {
+            Some(fade_out)
+        } else if self.now < fade_out + fade.duration {
+            Some(self.now)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scrollbars_fade_in_and_out() {
+        let fade = Fade::default();
+        let start = Instant::now();
+
+        let at = |visibility: &mut Visibility, millis| {
+            visibility.now = start + Duration::from_millis(millis);
+            visibility.opacity(fade)
+        };
+
+        let mut visibility = Visibility::default();
+        assert_eq!(at(&mut visibility, 0), 0.0);
+
+        visibility.activate(start, fade.lifetime());
+
+        assert_eq!(at(&mut visibility, 100), 0.5);
+        assert_eq!(at(&mut visibility, 500), 1.0);
+        assert_eq!(at(&mut visibility, 1100), 0.5);
+        assert_eq!(at(&mut visibility, 1200), 0.0);
+        assert_eq!(visibility.next_redraw(fade), None);
+
+        visibility
+            .activate(start + Duration::from_millis(600), fade.lifetime());
+
+        assert_eq!(at(&mut visibility, 1200), 1.0);
+        assert_eq!(
+            visibility.next_redraw(fade),
+            Some(start + Duration::from_millis(1600))
+        );
+    }
+}
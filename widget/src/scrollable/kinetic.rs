@@ -0,0 +1,236 @@
+use crate::core::time::{Duration, Instant};
+use crate::core::Vector;
+use crate::scrollable::Offset;
+
+/// The scrolling physics of a [`Scrollable`].
+///
+/// By default, a [`Scrollable`] scrolls instantly and stops as soon as its
+/// user stops scrolling it.
+///
+/// [`Scrollable`]: crate::Scrollable
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Kinetic {
+    pub(super) smooth: Option<Duration>,
+    pub(super) deceleration: Option<f32>,
+    pub(super) bounce: bool,
+}
+
+impl Kinetic {
+    /// The default duration of a smooth scroll.
+    pub const DEFAULT_SMOOTH_DURATION: Duration = Duration::from_millis(150);
+
+    /// The default deceleration of a fling, in pixels per second squared.
+    pub const DEFAULT_DECELERATION: f32 = 2000.0;
+
+    /// Animates line-based mouse wheel steps and programmatic scrolls, like
+    /// [`snap_to`] and [`scroll_to`], over the given duration.
+    ///
+    /// [`snap_to`]: crate::scrollable::snap_to
+    /// [`scroll_to`]: crate::scrollable::scroll_to
+    pub fn smooth(mut self, duration: Duration) -> Self {
+        self.smooth = Some(duration);
+        self
+    }
+
+    /// Keeps scrolling after touch and trackpad flings, slowing down with the
+    /// given deceleration; in pixels per second squared.
+    pub fn momentum(mut self, deceleration: f32) -> Self {
+        self.deceleration = Some(deceleration.max(0.0));
+        self
+    }
+
+    /// Sets whether the content can be dragged or flung past its edges,
+    /// bouncing back afterwards.
+    pub fn bounce(mut self, bounce: bool) -> Self {
+        self.bounce = bounce;
+        self
+    }
+}
+
+/// The time a trackpad must stay idle before its last motion is turned into a
+/// fling.
+pub(super) const FLING_DELAY: Duration = Duration::from_millis(50);
+
+/// The maximum time between the last motion and the end of a touch for it to
+/// be considered a fling.
+const FLING_TIMEOUT: Duration = Duration::from_millis(100);
+
+/// The minimum speed of a fling, in pixels per second.
+const MIN_FLING_SPEED: f32 = 50.0;
+
+/// The portion of the motion past an edge that moves the content.
+const RESISTANCE: f32 = 0.5;
+
+/// The rate at which an overscroll settles back, per second.
+const RELAXATION: f32 = 12.0;
+
+/// The rate at which a fling slows down while overscrolled, per second.
+const BOUNCE_DAMPING: f32 = 20.0;
+
+/// An animation of the offsets of a [`Scrollable`].
+///
+/// [`Scrollable`]: crate::Scrollable
+#[derive(Debug, Clone, Copy)]
+pub(super) struct Animation {
+    pub from: (Offset, Offset),
+    pub to: (Offset, Offset),
+    duration: Duration,
+    started_at: Option<Instant>,
+}
+
+impl Animation {
+    pub fn new(
+        from: (Offset, Offset),
+        to: (Offset, Offset),
+        duration: Duration,
+    ) -> Self {
+        Self {
+            from,
+            to,
+            duration,
+            started_at: None,
+        }
+    }
+
+    /// Returns the eased progress of the [`Animation`] at the given time.
+    ///
+    /// The [`Animation`] starts on its first frame.
+    pub fn progress(&mut self, now: Instant) -> f32 {
+        let started_at = *self.started_at.get_or_insert(now);
+        let elapsed = now.saturating_duration_since(started_at);
+
+        let t = (elapsed.as_secs_f32()
+            / self.duration.as_secs_f32().max(f32::EPSILON))
+        .min(1.0);
+
+        // Ease out cubic
+        1.0 - (1.0 - t).powi(3)
+    }
+}
+
+/// The recent motion of a drag or a trackpad.
+#[derive(Debug, Clone, Copy)]
+pub(super) struct Tracker {
+    /// The velocity of the motion, in pixels per second.
+    pub velocity: Vector,
+    pub at: Instant,
+    pub is_touch: bool,
+}
+
+impl Tracker {
+    /// Tracks the given motion, continuing the previous [`Tracker`] if it
+    /// is recent enough.
+    pub fn track(
+        previous: Option<Tracker>,
+        delta: Vector,
+        now: Instant,
+        is_touch: bool,
+    ) -> Self {
+        let velocity = match previous {
+            Some(previous) if previous.is_touch == is_touch => {
+                let elapsed = now.saturating_duration_since(previous.at);
+
+                if elapsed > FLING_TIMEOUT {
+                    Vector::ZERO
+                } else {
+                    let current =
+                        delta * (1.0 / elapsed.as_secs_f32().max(0.001));
+
+                    current * 0.8 + previous.velocity * 0.2
+                }
+            }
+            _ => Vector::ZERO,
+        };
+
+        Self {
+            velocity,
+            at: now,
+            is_touch,
+        }
+    }
+
+    /// Returns the velocity of the fling produced by the [`Tracker`] at the
+    /// given time, if any.
+    pub fn fling(&self, now: Instant) -> Option<Vector> {
+        let is_recent = now.saturating_duration_since(self.at)
+            <= FLING_TIMEOUT + FLING_DELAY;
+
+        let speed = self.velocity.x.hypot(self.velocity.y);
+
+        (is_recent && speed >= MIN_FLING_SPEED).then_some(self.velocity)
+    }
+}
+
+/// Returns the overscroll of an axis, given its previous overscroll and the
+/// motion past its edge.
+///
+/// Any motion away from the edge is slowed down.
+pub(super) fn rubber_band(previous: f32, excess: f32) -> f32 {
+    if excess * previous >= 0.0 && excess.abs() > previous.abs() {
+        previous + (excess - previous) * RESISTANCE
+    } else {
+        excess
+    }
+}
+
+/// Slows down the given velocity, keeping its direction.
+pub(super) fn decelerate(
+    velocity: Vector,
+    deceleration: f32,
+    is_overscrolled: bool,
+    dt: f32,
+) -> Vector {
+    let speed = velocity.x.hypot(velocity.y);
+
+    let mut new_speed = (speed - deceleration * dt).max(0.0);
+
+    if is_overscrolled {
+        new_speed *= (-BOUNCE_DAMPING * dt).exp();
+    }
+
+    if new_speed < MIN_FLING_SPEED / 10.0 {
+        Vector::ZERO
+    } else {
+        velocity * (new_speed / speed)
+    }
+}
+
+/// Settles the given overscroll back towards zero.
+pub(super) fn relax(overscroll: f32, dt: f32) -> f32 {
+    let overscroll = overscroll * (-RELAXATION * dt).exp();
+
+    if overscroll.abs() < 0.5 {
+        0.0
+    } else {
+        overscroll
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rubber_band_resists_outward_motion() {
+        assert_eq!(rubber_band(0.0, 10.0), 5.0);
+        assert_eq!(rubber_band(5.0, 15.0), 10.0);
+        assert_eq!(rubber_band(10.0, 4.0), 4.0);
+        assert_eq!(rubber_band(-4.0, -8.0), -6.0);
+    }
+
+    #[test]
+    fn flings_come_to_a_stop() {
+        let mut velocity = Vector::new(0.0, 1000.0);
+        let mut frames = 0;
+
+        while velocity != Vector::ZERO {
+            velocity = decelerate(velocity, 2000.0, false, 1.0 / 60.0);
+            frames += 1;
+
+            assert!(velocity.x == 0.0 && velocity.y >= 0.0);
+        }
+
+        assert!((29..=31).contains(&frames));
+        assert_eq!(relax(100.0, 1.0), 0.0);
+    }
+}
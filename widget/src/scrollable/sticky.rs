@@ -0,0 +1,369 @@
+use crate::core::event::{self, Event};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::Operation;
+use crate::core::{
+    Alignment, Clipboard, Element, Layout, Length, Padding, Pixels, Rectangle,
+    Shell, Size, Vector, Widget,
+};
+
+/// A section of the content of a [`Scrollable`] with a header that sticks to
+/// the top of the [`Scrollable`] while the rest of the section is visible.
+///
+/// Once the section scrolls out of view, the next one pushes its header away.
+///
+/// The header is drawn over the content scrolling beneath it, so it should
+/// have an opaque background; for instance, by placing it in a [`Container`].
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::core::Length; }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::renderer::Renderer>;
+/// use iced::widget::{column, container, scrollable, sticky, text};
+/// use iced::Length;
+///
+/// enum Message {}
+///
+/// fn view<'a>(groups: &'a [(String, Vec<String>)]) -> Element<'a, Message> {
+///     scrollable(column(groups.iter().map(|(name, items)| {
+///         sticky(
+///             container(text(name).size(20))
+///                 .width(Length::Fill)
+///                 .style(container::bordered_box),
+///             column(items.iter().map(|item| text(item).into())),
+///         )
+///         .into()
+///     })))
+///     .into()
+/// }
+/// ```
+///
+/// [`Scrollable`]: crate::Scrollable
+/// [`Container`]: crate::Container
+#[allow(missing_debug_implementations)]
+pub struct Sticky<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer>
+{
+    spacing: f32,
+    width: Length,
+    /// The header and the content of the section, in that order.
+    children: [Element<'a, Message, Theme, Renderer>; 2],
+}
+
+impl<'a, Message, Theme, Renderer> Sticky<'a, Message, Theme, Renderer>
+where
+    Renderer: crate::core::Renderer,
+{
+    /// Creates a new [`Sticky`] section with the given header and content.
+    pub fn new(
+        header: impl Into<Element<'a, Message, Theme, Renderer>>,
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        let header = header.into();
+        let content = content.into();
+
+        let size = header.as_widget().size_hint();
+        let content_size = content.as_widget().size_hint();
+
+        Self {
+            spacing: 0.0,
+            width: size.width.enclose(content_size.width),
+            children: [header, content],
+        }
+    }
+
+    /// Sets the vertical spacing between the header and the content of the
+    /// [`Sticky`] section.
+    pub fn spacing(mut self, amount: impl Into<Pixels>) -> Self {
+        self.spacing = amount.into().0;
+        self
+    }
+
+    /// Sets the width of the [`Sticky`] section.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    /// The offset of the header the last time the [`Sticky`] section
+    /// processed an event.
+    offset: Vector,
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Sticky<'a, Message, Theme, Renderer>
+where
+    Renderer: crate::core::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.children.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&self.children);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::flex::resolve(
+            layout::flex::Axis::Vertical,
+            renderer,
+            limits,
+            self.width,
+            Length::Shrink,
+            Padding::ZERO,
+            self.spacing,
+            Alignment::Start,
+            &self.children,
+            &mut tree.children,
+        )
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        operation.container(None, layout.bounds(), &mut |operation| {
+            self.children
+                .iter()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .for_each(|((child, state), layout)| {
+                    child
+                        .as_widget()
+                        .operate(state, layout, renderer, operation);
+                });
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let offset = offset(layout, viewport);
+        tree.state.downcast_mut::<State>().offset = offset;
+
+        let (header_cursor, content_cursor) = cursors(layout, cursor, offset);
+
+        let mut children = layout.children();
+        let [header, content] = &mut self.children;
+        let [header_tree, content_tree] = &mut tree.children[..] else {
+            return event::Status::Ignored;
+        };
+
+        let header_status = header.as_widget_mut().on_event(
+            header_tree,
+            event.clone(),
+            children.next().unwrap(),
+            header_cursor,
+            renderer,
+            clipboard,
+            shell,
+            &(*viewport - offset),
+        );
+
+        let content_status = content.as_widget_mut().on_event(
+            content_tree,
+            event,
+            children.next().unwrap(),
+            content_cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        header_status.merge(content_status)
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let offset = offset(layout, viewport);
+        let (header_cursor, content_cursor) = cursors(layout, cursor, offset);
+
+        let mut children = layout.children();
+        let [header, content] = &self.children;
+
+        let header_interaction = header.as_widget().mouse_interaction(
+            &tree.children[0],
+            children.next().unwrap(),
+            header_cursor,
+            &(*viewport - offset),
+            renderer,
+        );
+
+        let content_interaction = content.as_widget().mouse_interaction(
+            &tree.children[1],
+            children.next().unwrap(),
+            content_cursor,
+            viewport,
+            renderer,
+        );
+
+        header_interaction.max(content_interaction)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let offset = offset(layout, viewport);
+        let (header_cursor, content_cursor) = cursors(layout, cursor, offset);
+
+        let mut children = layout.children();
+        let header_layout = children.next().unwrap();
+        let [header, content] = &self.children;
+
+        content.as_widget().draw(
+            &tree.children[1],
+            renderer,
+            theme,
+            style,
+            children.next().unwrap(),
+            content_cursor,
+            viewport,
+        );
+
+        // The header is drawn in its own layer, so it covers the content
+        // scrolling beneath it
+        renderer.with_layer(header_layout.bounds() + offset, |renderer| {
+            renderer.with_translation(offset, |renderer| {
+                header.as_widget().draw(
+                    &tree.children[0],
+                    renderer,
+                    theme,
+                    style,
+                    header_layout,
+                    header_cursor,
+                    &(*viewport - offset),
+                );
+            });
+        });
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let offset = tree.state.downcast_ref::<State>().offset;
+
+        let mut children = layout.children();
+        let [header, content] = &mut self.children;
+        let [header_tree, content_tree] = &mut tree.children[..] else {
+            return None;
+        };
+
+        let header_overlay = header.as_widget_mut().overlay(
+            header_tree,
+            children.next().unwrap(),
+            renderer,
+            translation + offset,
+        );
+
+        let content_overlay = content.as_widget_mut().overlay(
+            content_tree,
+            children.next().unwrap(),
+            renderer,
+            translation,
+        );
+
+        let overlays: Vec<_> =
+            header_overlay.into_iter().chain(content_overlay).collect();
+
+        (!overlays.is_empty())
+            .then(|| overlay::Group::with_children(overlays).overlay())
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Sticky<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: crate::core::Renderer + 'a,
+{
+    fn from(sticky: Sticky<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(sticky)
+    }
+}
+
+/// Returns the offset that keeps the header of a [`Sticky`] section at the
+/// top of the given viewport, without leaving the section.
+fn offset(layout: Layout<'_>, viewport: &Rectangle) -> Vector {
+    let bounds = layout.bounds();
+    let header = layout.children().next().unwrap().bounds();
+
+    let max = (bounds.y + bounds.height - header.y - header.height).max(0.0);
+
+    Vector::new(0.0, (viewport.y - header.y).clamp(0.0, max))
+}
+
+/// Returns the cursors of the header and the content of a [`Sticky`] section,
+/// given the offset of its header.
+///
+/// The content is not hovered where the header covers it.
+fn cursors(
+    layout: Layout<'_>,
+    cursor: mouse::Cursor,
+    offset: Vector,
+) -> (mouse::Cursor, mouse::Cursor) {
+    let header = layout.children().next().unwrap().bounds() + offset;
+
+    match cursor.position() {
+        Some(position) if header.contains(position) => (
+            mouse::Cursor::Available(position - offset),
+            mouse::Cursor::Unavailable,
+        ),
+        Some(_) => (mouse::Cursor::Unavailable, cursor),
+        None => (cursor, cursor),
+    }
+}
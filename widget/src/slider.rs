@@ -1,4 +1,5 @@
 //! Display an interactive selector of a single value from a range of values.
+use crate::core::alignment;
 use crate::core::border;
 use crate::core::event::{self, Event};
 use crate::core::keyboard;
@@ -6,6 +7,7 @@ use crate::core::keyboard::key::{self, Key};
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::renderer;
+use crate::core::text::{self, Paragraph, Text};
 use crate::core::touch;
 use crate::core::widget::tree::{self, Tree};
 use crate::core::{
@@ -37,6 +39,44 @@ use std::ops::RangeInclusive;
 /// Slider::new(0.0..=100.0, value, Message::SliderChanged);
 /// ```
 ///
+/// Tick marks, optionally labeled, can be drawn below the [`Slider`]:
+///
+/// ```no_run
+/// # type Slider<'a, T, Message> = iced_widget::Slider<'a, Message, T>;
+/// #
+/// # #[derive(Clone)]
+/// # pub enum Message {
+/// #     VolumeChanged(f32),
+/// # }
+/// #
+/// let volume = -20.0;
+///
+/// Slider::new(-60.0..=0.0, volume, Message::VolumeChanged)
+///     .ticks([-60.0, -40.0, -20.0, 0.0])
+///     .tick_label(|volume| format!("{volume} dB"));
+/// ```
+///
+/// Since the labels are drawn as text, a [`Slider`] can only be used with a
+/// [`text::Renderer`].
+///
+/// By default, the value changes linearly along the [`Slider`]. A different
+/// [`Mapping`] can be used for logarithmic scales or custom curves:
+///
+/// ```no_run
+/// # type Slider<'a, T, Message> = iced_widget::Slider<'a, Message, T>;
+/// # use iced_widget::slider::Mapping;
+/// #
+/// # #[derive(Clone)]
+/// # pub enum Message {
+/// #     FrequencyChanged(f32),
+/// # }
+/// #
+/// let frequency = 440.0;
+///
+/// Slider::new(20.0..=20_000.0, frequency, Message::FrequencyChanged)
+///     .mapping(Mapping::Logarithmic);
+/// ```
+///
 /// ![Slider drawn by Coffee's renderer](https://github.com/hecrj/coffee/blob/bda9818f823dfcb8a7ad0ff4940b4d4b387b5208/images/ui/slider.png?raw=true)
 #[allow(missing_debug_implementations)]
 pub struct Slider<'a, T, Message, Theme = crate::Theme> {
@@ -49,6 +89,8 @@ pub struct Slider<'a, T, Message, Theme = crate::Theme> {
     on_release: Option<Message>,
     width: Length,
     height: f32,
+    ticks: Ticks<'a, T>,
+    mapping: Mapping,
     style: Style<Theme>,
 }
 
@@ -95,6 +137,8 @@ where
             on_release: None,
             width: Length::Fill,
             height: Self::DEFAULT_HEIGHT,
+            ticks: Ticks::default(),
+            mapping: Mapping::default(),
             style: Theme::default_style(),
         }
     }
@@ -149,6 +193,25 @@ where
         self.shift_step = Some(shift_step.into());
         self
     }
+
+    /// Sets the [`Mapping`] between the position of the handle and the value
+    /// of the [`Slider`].
+    pub fn mapping(mut self, mapping: Mapping) -> Self {
+        self.mapping = mapping;
+        self
+    }
+
+    /// Sets the values where tick marks are drawn along the [`Slider`].
+    pub fn ticks(mut self, ticks: impl IntoIterator<Item = T>) -> Self {
+        self.ticks.values = ticks.into_iter().collect();
+        self
+    }
+
+    /// Sets the function used to label the tick marks of the [`Slider`].
+    pub fn tick_label(mut self, label: impl Fn(T) -> String + 'a) -> Self {
+        self.ticks.label = Some(Box::new(label));
+        self
+    }
 }
 
 impl<'a, T, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
@@ -156,7 +219,7 @@ impl<'a, T, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
 where
     T: Copy + Into<f64> + num_traits::FromPrimitive,
     Message: Clone,
-    Renderer: crate::core::Renderer,
+    Renderer: text::Renderer,
 {
     fn tag(&self) -> tree::Tag {
         tree::Tag::of::<State>()
@@ -176,10 +239,14 @@ where
     fn layout(
         &self,
         _tree: &mut Tree,
-        _renderer: &Renderer,
+        renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        layout::atomic(limits, self.width, self.height)
+        layout::atomic(
+            limits,
+            self.width,
+            self.height + self.ticks.cross_size(renderer, false),
+        )
     }
 
     fn on_event(
@@ -218,10 +285,7 @@ where
                 let percent = f64::from(cursor_position.x - bounds.x)
                     / f64::from(bounds.width);
 
-                let steps = (percent * (end - start) / step).round();
-                let value = steps * step + start;
-
-                T::from_f64(value)
+                T::from_f64(self.mapping.locate(percent, start, end, step))
             };
 
             new_value
@@ -235,14 +299,12 @@ where
             }
             .into();
 
-            let steps = (value.into() / step).round();
-            let new_value = step * (steps + 1.0);
-
-            if new_value > (*self.range.end()).into() {
-                return Some(*self.range.end());
-            }
-
-            T::from_f64(new_value)
+            T::from_f64(self.mapping.increment(
+                value.into(),
+                (*self.range.start()).into(),
+                (*self.range.end()).into(),
+                step,
+            ))
         };
 
         let decrement = |value: T| -> Option<T> {
@@ -253,14 +315,12 @@ where
             }
             .into();
 
-            let steps = (value.into() / step).round();
-            let new_value = step * (steps - 1.0);
-
-            if new_value < (*self.range.start()).into() {
-                return Some(*self.range.start());
-            }
-
-            T::from_f64(new_value)
+            T::from_f64(self.mapping.increment(
+                value.into(),
+                (*self.range.start()).into(),
+                (*self.range.end()).into(),
+                -step,
+            ))
         };
 
         let change = |new_value: T| {
@@ -337,14 +397,18 @@ where
         tree: &Tree,
         renderer: &mut Renderer,
         theme: &Theme,
-        _style: &renderer::Style,
+        renderer_style: &renderer::Style,
         layout: Layout<'_>,
         cursor: mouse::Cursor,
-        _viewport: &Rectangle,
+        viewport: &Rectangle,
     ) {
         let state = tree.state.downcast_ref::<State>();
-        let bounds = layout.bounds();
-        let is_mouse_over = cursor.is_over(bounds);
+        let is_mouse_over = cursor.is_over(layout.bounds());
+
+        let bounds = Rectangle {
+            height: self.height,
+            ..layout.bounds()
+        };
 
         let style = (self.style)(
             theme,
@@ -375,13 +439,31 @@ where
             (start.into() as f32, end.into() as f32)
         };
 
-        let offset = if range_start >= range_end {
-            0.0
-        } else {
-            (bounds.width - handle_width) * (value - range_start)
-                / (range_end - range_start)
+        let position = |value: f32| {
+            if range_start >= range_end {
+                0.0
+            } else {
+                (bounds.width - handle_width)
+                    * self.mapping.to_position(
+                        f64::from(value),
+                        f64::from(range_start),
+                        f64::from(range_end),
+                    ) as f32
+            }
         };
 
+        let offset = position(value);
+
+        self.ticks.draw(
+            renderer,
+            bounds,
+            |value| bounds.x + handle_width / 2.0 + position(value),
+            false,
+            style.rail.colors.1,
+            renderer_style.text_color,
+            viewport,
+        );
+
         let rail_y = bounds.y + bounds.height / 2.0;
 
         renderer.fill_quad(
@@ -459,7 +541,7 @@ where
     T: Copy + Into<f64> + num_traits::FromPrimitive + 'a,
     Message: Clone + 'a,
     Theme: 'a,
-    Renderer: crate::core::Renderer + 'a,
+    Renderer: text::Renderer + 'a,
 {
     fn from(
         slider: Slider<'a, T, Message, Theme>,
@@ -468,6 +550,280 @@ where
     }
 }
 
+/// The mapping between the position of the handle of a slider and its
+/// value.
+///
+/// Positions go from `0.0`, at the start of the rail, to `1.0`, at its end.
+/// Steps are always applied to the resulting value.
+#[derive(Debug, Clone, Copy, Default)]
+pub enum Mapping {
+    /// The value changes linearly with the position.
+    #[default]
+    Linear,
+    /// The value changes exponentially with the position, so every ratio
+    /// between values takes the same space.
+    ///
+    /// The range of the slider must be positive; otherwise, the
+    /// [`Mapping`] is linear.
+    Logarithmic,
+    /// The value and the position are mapped by a pair of functions, which
+    /// should be the inverse of each other.
+    Custom {
+        /// Returns the value at the given position.
+        to_value: fn(f64) -> f64,
+        /// Returns the position of the given value.
+        to_position: fn(f64) -> f64,
+    },
+}
+
+impl Mapping {
+    /// Returns the value at the given position of a slider with the given
+    /// range.
+    pub fn to_value(&self, position: f64, start: f64, end: f64) -> f64 {
+        match self {
+            Self::Logarithmic if start > 0.0 && end > start => {
+                start * (end / start).powf(position)
+            }
+            Self::Linear | Self::Logarithmic => {
+                start + position * (end - start)
+            }
+            Self::Custom { to_value, .. } => to_value(position),
+        }
+    }
+
+    /// Returns the position of the given value in a slider with the given
+    /// range.
+    pub fn to_position(&self, value: f64, start: f64, end: f64) -> f64 {
+        match self {
+            Self::Logarithmic if start > 0.0 && end > start => {
+                (value / start).ln() / (end / start).ln()
+            }
+            Self::Linear | Self::Logarithmic => {
+                if end > start {
+                    (value - start) / (end - start)
+                } else {
+                    0.0
+                }
+            }
+            Self::Custom { to_position, .. } => to_position(value),
+        }
+    }
+
+    /// Returns the value at the given position, snapped to the given step.
+    pub(crate) fn locate(
+        &self,
+        position: f64,
+        start: f64,
+        end: f64,
+        step: f64,
+    ) -> f64 {
+        let value = self.to_value(position.clamp(0.0, 1.0), start, end);
+        let steps = ((value - start) / step).round();
+
+        (steps * step + start).clamp(start, end)
+    }
+
+    /// Returns the value after moving the handle from the given value by the
+    /// distance that the given step takes in a linear slider.
+    ///
+    /// The new value is snapped to the step and always differs from the
+    /// current one, unless it is at the end of the range.
+    pub(crate) fn increment(
+        &self,
+        value: f64,
+        start: f64,
+        end: f64,
+        step: f64,
+    ) -> f64 {
+        if end <= start {
+            return start;
+        }
+
+        let position =
+            self.to_position(value, start, end) + step / (end - start);
+        let steps = (self.to_value(position, start, end) / step).round();
+
+        let new_value = if steps * step == value {
+            step * ((value / step).round() + 1.0)
+        } else {
+            steps * step
+        };
+
+        new_value.clamp(start, end)
+    }
+}
+
+/// The tick marks of a slider, with their optional labels.
+pub(crate) struct Ticks<'a, T> {
+    pub(crate) values: Vec<T>,
+    pub(crate) label: Option<Box<dyn Fn(T) -> String + 'a>>,
+}
+
+impl<'a, T> Default for Ticks<'a, T> {
+    fn default() -> Self {
+        Self {
+            values: Vec::new(),
+            label: None,
+        }
+    }
+}
+
+impl<'a, T> Ticks<'a, T>
+where
+    T: Copy + Into<f64>,
+{
+    /// The distance between the rail and the tick marks.
+    const SPACING: f32 = 2.0;
+
+    /// The length of a tick mark.
+    const LENGTH: f32 = 4.0;
+
+    /// The distance between a tick mark and its label.
+    const LABEL_SPACING: f32 = 2.0;
+
+    /// The text size of the labels of the tick marks.
+    const LABEL_SIZE: Pixels = Pixels(12.0);
+
+    /// Returns the space needed by the tick marks and their labels across
+    /// the rail of a slider.
+    pub(crate) fn cross_size<Renderer>(
+        &self,
+        renderer: &Renderer,
+        is_vertical: bool,
+    ) -> f32
+    where
+        Renderer: text::Renderer,
+    {
+        if self.values.is_empty() {
+            return 0.0;
+        }
+
+        let ticks = Self::SPACING + Self::LENGTH;
+
+        let Some(label) = &self.label else {
+            return ticks;
+        };
+
+        let labels = if is_vertical {
+            self.values
+                .iter()
+                .map(|value| {
+                    Renderer::Paragraph::with_text(
+                        self.text(&label(*value), renderer),
+                    )
+                    .min_width()
+                })
+                .fold(0.0, f32::max)
+        } else {
+            text::LineHeight::default().to_absolute(Self::LABEL_SIZE).0
+        };
+
+        ticks + Self::LABEL_SPACING + labels
+    }
+
+    /// Draws the tick marks next to the given rail bounds, placing each
+    /// one at the coordinate returned by `position` along the rail.
+    pub(crate) fn draw<Renderer>(
+        &self,
+        renderer: &mut Renderer,
+        rail: Rectangle,
+        position: impl Fn(f32) -> f32,
+        is_vertical: bool,
+        color: Color,
+        text_color: Color,
+        viewport: &Rectangle,
+    ) where
+        Renderer: text::Renderer,
+    {
+        for value in &self.values {
+            let position = position((*value).into() as f32);
+
+            let (tick, label_position) = if is_vertical {
+                let x = rail.x + rail.width + Self::SPACING;
+
+                (
+                    Rectangle {
+                        x,
+                        y: position - 0.5,
+                        width: Self::LENGTH,
+                        height: 1.0,
+                    },
+                    Point::new(
+                        x + Self::LENGTH + Self::LABEL_SPACING,
+                        position,
+                    ),
+                )
+            } else {
+                let y = rail.y + rail.height + Self::SPACING;
+
+                (
+                    Rectangle {
+                        x: position - 0.5,
+                        y,
+                        width: 1.0,
+                        height: Self::LENGTH,
+                    },
+                    Point::new(
+                        position,
+                        y + Self::LENGTH + Self::LABEL_SPACING,
+                    ),
+                )
+            };
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: tick,
+                    ..renderer::Quad::default()
+                },
+                color,
+            );
+
+            if let Some(label) = &self.label {
+                let content = label(*value);
+
+                let text = self.text(&content, renderer);
+
+                renderer.fill_text(
+                    if is_vertical {
+                        Text {
+                            vertical_alignment: alignment::Vertical::Center,
+                            ..text
+                        }
+                    } else {
+                        Text {
+                            horizontal_alignment: alignment::Horizontal::Center,
+                            ..text
+                        }
+                    },
+                    label_position,
+                    text_color,
+                    *viewport,
+                );
+            }
+        }
+    }
+
+    fn text<'b, Renderer>(
+        &self,
+        content: &'b str,
+        renderer: &Renderer,
+    ) -> Text<'b, Renderer::Font>
+    where
+        Renderer: text::Renderer,
+    {
+        Text {
+            content,
+            bounds: Size::INFINITY,
+            size: Self::LABEL_SIZE,
+            line_height: text::LineHeight::default(),
+            font: renderer.default_font(),
+            horizontal_alignment: alignment::Horizontal::Left,
+            vertical_alignment: alignment::Vertical::Top,
+            shaping: text::Shaping::Basic,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 struct State {
     is_dragging: bool,
@@ -591,3 +947,46 @@ pub fn default(theme: &Theme, status: Status) -> Appearance {
         },
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Mapping;
+
+    #[test]
+    fn linear_stepping() {
+        let mapping = Mapping::Linear;
+
+        assert_eq!(mapping.locate(0.5, 0.0, 100.0, 1.0), 50.0);
+        assert_eq!(mapping.locate(0.504, 0.0, 100.0, 5.0), 50.0);
+        assert_eq!(mapping.increment(50.0, 0.0, 100.0, 5.0), 55.0);
+        assert_eq!(mapping.increment(50.0, 0.0, 100.0, -5.0), 45.0);
+        assert_eq!(mapping.increment(98.0, 0.0, 100.0, 5.0), 100.0);
+    }
+
+    #[test]
+    fn logarithmic_stepping() {
+        let mapping = Mapping::Logarithmic;
+
+        assert!((mapping.to_value(0.5, 10.0, 1000.0) - 100.0).abs() < 1e-9);
+        assert!((mapping.to_position(100.0, 10.0, 1000.0) - 0.5).abs() < 1e-9);
+        assert_eq!(mapping.locate(0.5, 10.0, 1000.0, 1.0), 100.0);
+
+        // Keyboard increments grow with the value
+        let small = mapping.increment(20.0, 10.0, 1000.0, 10.0) - 20.0;
+        let large = mapping.increment(500.0, 10.0, 1000.0, 10.0) - 500.0;
+
+        assert!(small > 0.0);
+        assert!(large > small);
+    }
+
+    #[test]
+    fn custom_mapping() {
+        let mapping = Mapping::Custom {
+            to_value: |position| position * position * 100.0,
+            to_position: |value| (value / 100.0).sqrt(),
+        };
+
+        assert_eq!(mapping.locate(0.5, 0.0, 100.0, 1.0), 25.0);
+        assert_eq!(mapping.to_position(25.0, 0.0, 100.0), 0.5);
+    }
+}
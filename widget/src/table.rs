@@ -0,0 +1,1425 @@
+//! Display data in rows and columns.
+use crate::container::Container;
+use crate::core::alignment;
+use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text::{self, Text};
+use crate::core::touch;
+use crate::core::widget::operation::{self, Operation};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::Id;
+use crate::core::{
+    Background, Border, Clipboard, Color, Element, Layout, Length, Padding,
+    Pixels, Point, Rectangle, Shell, Size, Theme, Vector, Widget,
+};
+use crate::scrollable::{self, AbsoluteOffset, Scrollable};
+use crate::{container, List, Row};
+
+use std::collections::BTreeSet;
+
+/// A table displaying rows of data divided into columns.
+///
+/// The rows of a [`Table`] have a fixed height and are built lazily: only
+/// the visible ones are created, laid out, and diffed. The header of a
+/// [`Table`] stays on top while its rows are scrolled.
+///
+/// A [`Table`] is turned into an [`Element`] once configured.
+#[allow(missing_debug_implementations)]
+pub struct Table<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer>
+{
+    columns: Vec<Column<'a, Message, Theme, Renderer>>,
+    rows: usize,
+    cell:
+        Box<dyn Fn(usize, usize) -> Element<'a, Message, Theme, Renderer> + 'a>,
+    width: Length,
+    height: Length,
+    row_height: f32,
+    padding: Padding,
+    selection: Option<&'a Selection>,
+    sort: Option<(usize, Order)>,
+    on_select: Option<Box<dyn Fn(Action) -> Message + 'a>>,
+    on_sort: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    on_resize: Option<Box<dyn Fn(ResizeEvent) -> Message + 'a>>,
+    style: Style<Theme>,
+}
+
+impl<'a, Message, Theme, Renderer> Table<'a, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    /// The default height of the rows of a [`Table`].
+    pub const DEFAULT_ROW_HEIGHT: f32 = 30.0;
+
+    /// The default [`Padding`] of the cells of a [`Table`].
+    pub const DEFAULT_PADDING: Padding = Padding {
+        top: 5.0,
+        right: 10.0,
+        bottom: 5.0,
+        left: 10.0,
+    };
+
+    /// Creates a new [`Table`] with the given columns, amount of rows, and a
+    /// closure that produces the cell for a given row and column index.
+    pub fn new(
+        columns: impl IntoIterator<Item = Column<'a, Message, Theme, Renderer>>,
+        rows: usize,
+        cell: impl Fn(usize, usize) -> Element<'a, Message, Theme, Renderer> + 'a,
+    ) -> Self
+    where
+        Theme: DefaultStyle,
+    {
+        Self {
+            columns: columns.into_iter().collect(),
+            rows,
+            cell: Box::new(cell),
+            width: Length::Fill,
+            height: Length::Fill,
+            row_height: Self::DEFAULT_ROW_HEIGHT,
+            padding: Self::DEFAULT_PADDING,
+            selection: None,
+            sort: None,
+            on_select: None,
+            on_sort: None,
+            on_resize: None,
+            style: Theme::default_style(),
+        }
+    }
+
+    /// Sets the width of the [`Table`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Table`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the height of the rows of the [`Table`].
+    pub fn row_height(mut self, height: impl Into<Pixels>) -> Self {
+        self.row_height = height.into().0;
+        self
+    }
+
+    /// Sets the [`Padding`] of the cells of the [`Table`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the current [`Selection`] of the [`Table`].
+    pub fn selection(mut self, selection: &'a Selection) -> Self {
+        self.selection = Some(selection);
+        self
+    }
+
+    /// Sets the message that should be produced when the [`Selection`] of
+    /// the [`Table`] changes.
+    ///
+    /// Rows can be selected with the mouse or, once the [`Table`] is focused,
+    /// with the keyboard. The [`Action`] should be performed on the
+    /// [`Selection`] of the [`Table`].
+    pub fn on_select(mut self, f: impl Fn(Action) -> Message + 'a) -> Self {
+        self.on_select = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the column the rows of the [`Table`] are currently sorted by,
+    /// displaying an indicator in its header.
+    pub fn sort(mut self, column: usize, order: Order) -> Self {
+        self.sort = Some((column, order));
+        self
+    }
+
+    /// Sets the message that should be produced when the header of a
+    /// sortable [`Column`] is pressed.
+    ///
+    /// The function takes the index of the [`Column`].
+    pub fn on_sort(mut self, f: impl Fn(usize) -> Message + 'a) -> Self {
+        self.on_sort = Some(Box::new(f));
+        self
+    }
+
+    /// Enables the resize interactions of the [`Table`], which will use the
+    /// provided function to produce messages.
+    ///
+    /// Columns can be resized by dragging the separators of the header.
+    pub fn on_resize(
+        mut self,
+        f: impl Fn(ResizeEvent) -> Message + 'a,
+    ) -> Self {
+        self.on_resize = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the style of the [`Table`].
+    pub fn style(mut self, style: fn(&Theme, Status) -> Appearance) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+/// A column of a [`Table`].
+#[allow(missing_debug_implementations)]
+pub struct Column<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer>
+{
+    header: Element<'a, Message, Theme, Renderer>,
+    width: Length,
+    horizontal_alignment: alignment::Horizontal,
+    is_sortable: bool,
+}
+
+impl<'a, Message, Theme, Renderer> Column<'a, Message, Theme, Renderer> {
+    /// Creates a new [`Column`] with the given header.
+    pub fn new(
+        header: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            header: header.into(),
+            width: Length::Fill,
+            horizontal_alignment: alignment::Horizontal::Left,
+            is_sortable: false,
+        }
+    }
+
+    /// Sets the width of the [`Column`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the horizontal alignment of the cells of the [`Column`].
+    pub fn align_x(mut self, alignment: alignment::Horizontal) -> Self {
+        self.horizontal_alignment = alignment;
+        self
+    }
+
+    /// Sets whether the [`Table`] can be sorted by the [`Column`].
+    pub fn sortable(mut self, is_sortable: bool) -> Self {
+        self.is_sortable = is_sortable;
+        self
+    }
+}
+
+/// The sorting order of a [`Table`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Order {
+    /// Smaller values come first.
+    Ascending,
+    /// Greater values come first.
+    Descending,
+}
+
+impl Order {
+    /// Returns the opposite [`Order`].
+    pub fn reverse(self) -> Self {
+        match self {
+            Self::Ascending => Self::Descending,
+            Self::Descending => Self::Ascending,
+        }
+    }
+}
+
+/// The new width of a [`Column`] being resized.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ResizeEvent {
+    /// The index of the [`Column`] being resized.
+    pub column: usize,
+    /// The new width of the [`Column`].
+    pub width: f32,
+}
+
+/// The selected rows of a [`Table`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Selection {
+    rows: BTreeSet<usize>,
+    anchor: Option<usize>,
+    focus: Option<usize>,
+    is_multiple: bool,
+}
+
+impl Selection {
+    /// Creates an empty [`Selection`] that can only contain a single row.
+    pub fn single() -> Self {
+        Self::default()
+    }
+
+    /// Creates an empty [`Selection`] that can contain multiple rows.
+    pub fn multiple() -> Self {
+        Self {
+            is_multiple: true,
+            ..Self::default()
+        }
+    }
+
+    /// Returns whether the given row is selected.
+    pub fn contains(&self, row: usize) -> bool {
+        self.rows.contains(&row)
+    }
+
+    /// Returns an iterator over the selected rows, in ascending order.
+    pub fn rows(&self) -> impl Iterator<Item = usize> + '_ {
+        self.rows.iter().copied()
+    }
+
+    /// Returns the row that was last selected, if any.
+    pub fn focus(&self) -> Option<usize> {
+        self.focus
+    }
+
+    /// Returns true if there are no selected rows.
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    /// Deselects all the rows.
+    pub fn clear(&mut self) {
+        self.rows.clear();
+        self.anchor = None;
+        self.focus = None;
+    }
+
+    /// Performs an [`Action`] on the [`Selection`].
+    pub fn perform(&mut self, action: Action) {
+        match action {
+            Action::Toggle(row) if self.is_multiple => {
+                if !self.rows.remove(&row) {
+                    let _ = self.rows.insert(row);
+                }
+
+                self.anchor = Some(row);
+                self.focus = Some(row);
+            }
+            Action::Extend(row) if self.is_multiple => {
+                let anchor = self.anchor.unwrap_or(row);
+
+                self.rows = (anchor.min(row)..=anchor.max(row)).collect();
+                self.anchor = Some(anchor);
+                self.focus = Some(row);
+            }
+            Action::SelectAll(count) if self.is_multiple => {
+                self.rows = (0..count).collect();
+            }
+            Action::SelectAll(_) => {}
+            Action::Select(row) | Action::Toggle(row) | Action::Extend(row) => {
+                self.rows.clear();
+                let _ = self.rows.insert(row);
+
+                self.anchor = Some(row);
+                self.focus = Some(row);
+            }
+        }
+    }
+}
+
+/// An interaction with the [`Selection`] of a [`Table`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    /// Select the given row, deselecting the rest.
+    Select(usize),
+    /// Toggle the selection of the given row.
+    Toggle(usize),
+    /// Select all the rows between the last selected row and the given one.
+    Extend(usize),
+    /// Select all the rows, given the amount of rows of the [`Table`].
+    SelectAll(usize),
+}
+
+impl<'a, Message, Theme, Renderer> From<Table<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: container::DefaultStyle + scrollable::DefaultStyle + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(table: Table<'a, Message, Theme, Renderer>) -> Self {
+        let Table {
+            columns,
+            rows,
+            cell,
+            width,
+            height,
+            row_height,
+            padding,
+            selection,
+            sort,
+            on_select,
+            on_sort,
+            on_resize,
+            style,
+        } = table;
+
+        let layout: Vec<_> = columns
+            .iter()
+            .map(|column| (column.width, column.horizontal_alignment))
+            .collect();
+
+        let sortable: Vec<_> =
+            columns.iter().map(|column| column.is_sortable).collect();
+
+        let header = Row::with_children(columns.into_iter().map(|column| {
+            let padding = if column.is_sortable {
+                Padding {
+                    right: padding.right + INDICATOR_SPACE,
+                    ..padding
+                }
+            } else {
+                padding
+            };
+
+            Container::new(column.header)
+                .width(column.width)
+                .padding(padding)
+                .align_x(column.horizontal_alignment)
+                .into()
+        }))
+        .width(Length::Fill)
+        .align_items(crate::core::Alignment::Center);
+
+        let body_id = scrollable::Id::unique();
+
+        let body = Scrollable::new(
+            List::new(rows, move |row| {
+                let cells = layout.iter().enumerate().map(
+                    |(column, (width, horizontal_alignment))| {
+                        Container::new(cell(row, column))
+                            .width(*width)
+                            .height(Length::Fill)
+                            .padding(padding)
+                            .align_x(*horizontal_alignment)
+                            .center_y()
+                            .into()
+                    },
+                );
+
+                Element::new(Entry {
+                    content: Row::with_children(cells)
+                        .width(Length::Fill)
+                        .into(),
+                    is_selected: selection
+                        .is_some_and(|selection| selection.contains(row)),
+                    is_alternate: row % 2 == 1,
+                    style,
+                })
+            })
+            .item_height(row_height),
+        )
+        .id(body_id.clone())
+        .width(Length::Fill)
+        .height(Length::Fill);
+
+        Element::new(Inner {
+            header: header.into(),
+            body: body.into(),
+            body_id: body_id.into(),
+            width,
+            height,
+            rows,
+            row_height,
+            sortable,
+            sort,
+            focus: selection.and_then(Selection::focus),
+            on_select,
+            on_sort,
+            on_resize,
+            style,
+        })
+    }
+}
+
+/// The horizontal space reserved for the sort indicator of a header.
+const INDICATOR_SPACE: f32 = 16.0;
+
+/// The distance from a separator where it can still be grabbed.
+const SEPARATOR_LEEWAY: f32 = 4.0;
+
+/// The minimum width a [`Column`] can be resized to.
+const MIN_COLUMN_WIDTH: f32 = 20.0;
+
+struct Inner<'a, Message, Theme, Renderer> {
+    header: Element<'a, Message, Theme, Renderer>,
+    body: Element<'a, Message, Theme, Renderer>,
+    body_id: Id,
+    width: Length,
+    height: Length,
+    rows: usize,
+    row_height: f32,
+    sortable: Vec<bool>,
+    sort: Option<(usize, Order)>,
+    focus: Option<usize>,
+    on_select: Option<Box<dyn Fn(Action) -> Message + 'a>>,
+    on_sort: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    on_resize: Option<Box<dyn Fn(ResizeEvent) -> Message + 'a>>,
+    style: Style<Theme>,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    is_focused: bool,
+    keyboard_modifiers: keyboard::Modifiers,
+    resizing: Option<Resizing>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Resizing {
+    column: usize,
+    origin: f32,
+    width: f32,
+}
+
+impl operation::Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Inner<'a, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    /// Returns the index of the separator at the given position, if any.
+    fn separator_at(
+        &self,
+        header: Layout<'_>,
+        position: Point,
+    ) -> Option<usize> {
+        if self.on_resize.is_none() || !header.bounds().contains(position) {
+            return None;
+        }
+
+        header.children().position(|cell| {
+            let bounds = cell.bounds();
+
+            (bounds.x + bounds.width - position.x).abs() <= SEPARATOR_LEEWAY
+        })
+    }
+
+    /// Returns the index of the sortable column at the given position, if
+    /// any.
+    fn sortable_at(
+        &self,
+        header: Layout<'_>,
+        position: Point,
+    ) -> Option<usize> {
+        let _ = self.on_sort.as_ref()?;
+
+        header
+            .children()
+            .position(|cell| cell.bounds().contains(position))
+            .filter(|column| self.sortable.get(*column).copied() == Some(true))
+    }
+
+    /// Scrolls the body of the [`Table`] until the given row is visible.
+    fn reveal(
+        &self,
+        tree: &mut Tree,
+        body: Layout<'_>,
+        renderer: &Renderer,
+        row: usize,
+    ) {
+        struct Reveal {
+            target: Id,
+            top: f32,
+            bottom: f32,
+        }
+
+        impl<T> Operation<T> for Reveal {
+            fn container(
+                &mut self,
+                _id: Option<&Id>,
+                _bounds: Rectangle,
+                operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+            ) {
+                operate_on_children(self);
+            }
+
+            fn scrollable(
+                &mut self,
+                state: &mut dyn operation::Scrollable,
+                id: Option<&Id>,
+                bounds: Rectangle,
+                translation: Vector,
+            ) {
+                if id != Some(&self.target) {
+                    return;
+                }
+
+                if self.top < translation.y {
+                    state.scroll_to(AbsoluteOffset {
+                        x: translation.x,
+                        y: self.top,
+                    });
+                } else if self.bottom > translation.y + bounds.height {
+                    state.scroll_to(AbsoluteOffset {
+                        x: translation.x,
+                        y: self.bottom - bounds.height,
+                    });
+                }
+            }
+        }
+
+        let top = row as f32 * self.row_height;
+
+        self.body.as_widget().operate(
+            tree,
+            body,
+            renderer,
+            &mut Reveal {
+                target: self.body_id.clone(),
+                top,
+                bottom: top + self.row_height,
+            },
+        );
+    }
+
+    /// Returns the current scrolling translation of the body of the
+    /// [`Table`].
+    fn translation(
+        &self,
+        tree: &mut Tree,
+        body: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Vector {
+        struct Translation {
+            target: Id,
+            translation: Vector,
+        }
+
+        impl<T> Operation<T> for Translation {
+            fn container(
+                &mut self,
+                _id: Option<&Id>,
+                _bounds: Rectangle,
+                operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+            ) {
+                operate_on_children(self);
+            }
+
+            fn scrollable(
+                &mut self,
+                _state: &mut dyn operation::Scrollable,
+                id: Option<&Id>,
+                _bounds: Rectangle,
+                translation: Vector,
+            ) {
+                if id == Some(&self.target) {
+                    self.translation = translation;
+                }
+            }
+        }
+
+        let mut operation = Translation {
+            target: self.body_id.clone(),
+            translation: Vector::ZERO,
+        };
+
+        self.body
+            .as_widget()
+            .operate(tree, body, renderer, &mut operation);
+
+        operation.translation
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Inner<'a, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.header), Tree::new(&self.body)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&[&self.header, &self.body]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+        let width =
+            limits.resolve(self.width, Length::Shrink, Size::ZERO).width;
+
+        let header = self.header.as_widget().layout(
+            &mut tree.children[0],
+            renderer,
+            &layout::Limits::new(
+                Size::new(width, 0.0),
+                Size::new(width, limits.max().height),
+            ),
+        );
+
+        let header_height = header.size().height;
+
+        let size = limits.resolve(
+            self.width,
+            self.height,
+            Size::new(
+                width,
+                header_height + self.rows as f32 * self.row_height,
+            ),
+        );
+
+        let body = self.body.as_widget().layout(
+            &mut tree.children[1],
+            renderer,
+            &layout::Limits::new(
+                Size::ZERO,
+                Size::new(width, (size.height - header_height).max(0.0)),
+            ),
+        );
+
+        layout::Node::with_children(
+            size,
+            vec![header, body.move_to(Point::new(0.0, header_height))],
+        )
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        operation.focusable(state, None);
+
+        operation.container(None, layout.bounds(), &mut |operation| {
+            let mut children = layout.children();
+
+            self.header.as_widget().operate(
+                &mut tree.children[0],
+                children.next().unwrap(),
+                renderer,
+                operation,
+            );
+
+            self.body.as_widget().operate(
+                &mut tree.children[1],
+                children.next().unwrap(),
+                renderer,
+                operation,
+            );
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+        let mut children = layout.children();
+        let header = children.next().unwrap();
+        let body = children.next().unwrap();
+
+        {
+            let state = tree.state.downcast_mut::<State>();
+
+            match event {
+                Event::Mouse(mouse::Event::ButtonPressed(_))
+                | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                    state.is_focused = cursor.is_over(bounds);
+                }
+                Event::Keyboard(keyboard::Event::ModifiersChanged(
+                    modifiers,
+                )) => {
+                    state.keyboard_modifiers = modifiers;
+                }
+                _ => {}
+            }
+
+            if let Some(resizing) = state.resizing {
+                match event {
+                    Event::Mouse(mouse::Event::CursorMoved { position })
+                    | Event::Touch(touch::Event::FingerMoved {
+                        position,
+                        ..
+                    }) => {
+                        if let Some(on_resize) = &self.on_resize {
+                            shell.publish(on_resize(ResizeEvent {
+                                column: resizing.column,
+                                width: (resizing.width + position.x
+                                    - resizing.origin)
+                                    .max(MIN_COLUMN_WIDTH),
+                            }));
+                        }
+
+                        return event::Status::Captured;
+                    }
+                    Event::Mouse(mouse::Event::ButtonReleased(
+                        mouse::Button::Left,
+                    ))
+                    | Event::Touch(touch::Event::FingerLifted { .. })
+                    | Event::Touch(touch::Event::FingerLost { .. }) => {
+                        state.resizing = None;
+
+                        return event::Status::Captured;
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerPressed { .. }) = event
+        {
+            if let Some(position) = cursor.position() {
+                if let Some(column) = self.separator_at(header, position) {
+                    let state = tree.state.downcast_mut::<State>();
+                    let cell = header.children().nth(column).unwrap();
+
+                    state.resizing = Some(Resizing {
+                        column,
+                        origin: position.x,
+                        width: cell.bounds().width,
+                    });
+
+                    return event::Status::Captured;
+                }
+            }
+        }
+
+        let header_status = self.header.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event.clone(),
+            header,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        if header_status == event::Status::Captured {
+            return event::Status::Captured;
+        }
+
+        let body_status = self.body.as_widget_mut().on_event(
+            &mut tree.children[1],
+            event.clone(),
+            body,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        if body_status == event::Status::Captured {
+            return event::Status::Captured;
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(position) = cursor.position() else {
+                    return event::Status::Ignored;
+                };
+
+                if let Some(column) = self.sortable_at(header, position) {
+                    if let Some(on_sort) = &self.on_sort {
+                        shell.publish(on_sort(column));
+                    }
+
+                    return event::Status::Captured;
+                }
+
+                let Some(on_select) = &self.on_select else {
+                    return event::Status::Ignored;
+                };
+
+                if !body.bounds().contains(position) {
+                    return event::Status::Ignored;
+                }
+
+                let translation =
+                    self.translation(&mut tree.children[1], body, renderer);
+
+                let row = ((position.y - body.bounds().y + translation.y)
+                    / self.row_height) as usize;
+
+                if row >= self.rows {
+                    return event::Status::Ignored;
+                }
+
+                let modifiers =
+                    tree.state.downcast_ref::<State>().keyboard_modifiers;
+
+                shell.publish(on_select(if modifiers.command() {
+                    Action::Toggle(row)
+                } else if modifiers.shift() {
+                    Action::Extend(row)
+                } else {
+                    Action::Select(row)
+                }));
+
+                event::Status::Captured
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key,
+                modifiers,
+                ..
+            }) => {
+                let state = tree.state.downcast_ref::<State>();
+
+                let Some(on_select) = &self.on_select else {
+                    return event::Status::Ignored;
+                };
+
+                if !state.is_focused || self.rows == 0 {
+                    return event::Status::Ignored;
+                }
+
+                let last = self.rows - 1;
+                let page =
+                    (body.bounds().height / self.row_height).max(1.0) as usize;
+
+                let row = match key.as_ref() {
+                    keyboard::Key::Named(key::Named::ArrowUp) => {
+                        self.focus.map_or(0, |row| row.saturating_sub(1))
+                    }
+                    keyboard::Key::Named(key::Named::ArrowDown) => {
+                        self.focus.map_or(0, |row| (row + 1).min(last))
+                    }
+                    keyboard::Key::Named(key::Named::PageUp) => {
+                        self.focus.map_or(0, |row| row.saturating_sub(page))
+                    }
+                    keyboard::Key::Named(key::Named::PageDown) => {
+                        self.focus.map_or(0, |row| (row + page).min(last))
+                    }
+                    keyboard::Key::Named(key::Named::Home) => 0,
+                    keyboard::Key::Named(key::Named::End) => last,
+                    keyboard::Key::Character("a") if modifiers.command() => {
+                        shell.publish(on_select(Action::SelectAll(self.rows)));
+
+                        return event::Status::Captured;
+                    }
+                    _ => return event::Status::Ignored,
+                };
+
+                shell.publish(on_select(if modifiers.shift() {
+                    Action::Extend(row)
+                } else {
+                    Action::Select(row)
+                }));
+
+                self.reveal(&mut tree.children[1], body, renderer, row);
+
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        let mut children = layout.children();
+        let header = children.next().unwrap();
+        let body = children.next().unwrap();
+
+        if state.resizing.is_some() {
+            return mouse::Interaction::ResizingHorizontally;
+        }
+
+        if let Some(position) = cursor.position() {
+            if self.separator_at(header, position).is_some() {
+                return mouse::Interaction::ResizingHorizontally;
+            }
+        }
+
+        let interaction = self
+            .header
+            .as_widget()
+            .mouse_interaction(
+                &tree.children[0],
+                header,
+                cursor,
+                viewport,
+                renderer,
+            )
+            .max(self.body.as_widget().mouse_interaction(
+                &tree.children[1],
+                body,
+                cursor,
+                viewport,
+                renderer,
+            ));
+
+        if interaction == mouse::Interaction::default() {
+            if let Some(position) = cursor.position() {
+                if self.sortable_at(header, position).is_some() {
+                    return mouse::Interaction::Pointer;
+                }
+            }
+        }
+
+        interaction
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let mut children = layout.children();
+        let header = children.next().unwrap();
+        let body = children.next().unwrap();
+
+        let status = if state.is_focused {
+            Status::Focused
+        } else {
+            Status::Active
+        };
+
+        let appearance = (self.style)(theme, status);
+
+        if let Some(background) = appearance.background {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    ..renderer::Quad::default()
+                },
+                background,
+            );
+        }
+
+        if let Some(background) = appearance.header_background {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: header.bounds(),
+                    ..renderer::Quad::default()
+                },
+                background,
+            );
+        }
+
+        self.header.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            &renderer::Style {
+                text_color: appearance
+                    .header_text_color
+                    .unwrap_or(style.text_color),
+            },
+            header,
+            cursor,
+            viewport,
+        );
+
+        for (column, cell) in header.children().enumerate() {
+            let cell_bounds = cell.bounds();
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: cell_bounds.x + cell_bounds.width - 0.5,
+                        y: cell_bounds.y,
+                        width: 1.0,
+                        height: cell_bounds.height,
+                    },
+                    ..renderer::Quad::default()
+                },
+                appearance.separator,
+            );
+
+            let Some((_, order)) =
+                self.sort.filter(|(sorted, _)| *sorted == column)
+            else {
+                continue;
+            };
+
+            let size = renderer.default_size();
+
+            renderer.fill_text(
+                Text {
+                    content: match order {
+                        Order::Ascending => "▲",
+                        Order::Descending => "▼",
+                    },
+                    size: Pixels(size.0 * 0.6),
+                    line_height: text::LineHeight::default(),
+                    font: renderer.default_font(),
+                    bounds: Size::new(INDICATOR_SPACE, cell_bounds.height),
+                    horizontal_alignment: alignment::Horizontal::Center,
+                    vertical_alignment: alignment::Vertical::Center,
+                    shaping: text::Shaping::Advanced,
+                },
+                Point::new(
+                    cell_bounds.x + cell_bounds.width - INDICATOR_SPACE,
+                    cell_bounds.center_y(),
+                ),
+                appearance.header_text_color.unwrap_or(style.text_color),
+                cell_bounds,
+            );
+        }
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: bounds.x,
+                    y: body.bounds().y - 0.5,
+                    width: bounds.width,
+                    height: 1.0,
+                },
+                ..renderer::Quad::default()
+            },
+            appearance.separator,
+        );
+
+        self.body.as_widget().draw(
+            &tree.children[1],
+            renderer,
+            theme,
+            style,
+            body,
+            cursor,
+            viewport,
+        );
+
+        if appearance.border.width > 0.0 {
+            renderer.with_layer(bounds, |renderer| {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border: appearance.border,
+                        ..renderer::Quad::default()
+                    },
+                    Color::TRANSPARENT,
+                );
+            });
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let mut children = layout.children();
+        let (header_tree, body_tree) = tree.children.split_at_mut(1);
+
+        let overlays: Vec<_> = [
+            self.header.as_widget_mut().overlay(
+                &mut header_tree[0],
+                children.next().unwrap(),
+                renderer,
+                translation,
+            ),
+            self.body.as_widget_mut().overlay(
+                &mut body_tree[0],
+                children.next().unwrap(),
+                renderer,
+                translation,
+            ),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+
+        (!overlays.is_empty())
+            .then(|| overlay::Group::with_children(overlays).overlay())
+    }
+}
+
+/// A row of a [`Table`].
+struct Entry<'a, Message, Theme, Renderer> {
+    content: Element<'a, Message, Theme, Renderer>,
+    is_selected: bool,
+    is_alternate: bool,
+    style: Style<Theme>,
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Entry<'a, Message, Theme, Renderer>
+where
+    Renderer: crate::core::Renderer,
+{
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let appearance = (self.style)(theme, Status::Active);
+
+        let background = if self.is_selected {
+            Some(appearance.selected_row_background)
+        } else if cursor.is_over(bounds) {
+            appearance.hovered_row_background
+        } else if self.is_alternate {
+            appearance.alternate_row_background
+        } else {
+            appearance.row_background
+        };
+
+        if let Some(background) = background {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    ..renderer::Quad::default()
+                },
+                background,
+            );
+        }
+
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            &renderer::Style {
+                text_color: if self.is_selected {
+                    appearance.selected_text_color
+                } else {
+                    style.text_color
+                },
+            },
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            translation,
+        )
+    }
+}
+
+/// The possible status of a [`Table`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The [`Table`] can be interacted with.
+    Active,
+    /// The [`Table`] is focused and can be navigated with the keyboard.
+    Focused,
+}
+
+/// The appearance of a table.
+#[derive(Debug, Clone, Copy)]
+pub struct Appearance {
+    /// The [`Background`] of the table.
+    pub background: Option<Background>,
+    /// The [`Border`] of the table.
+    pub border: Border,
+    /// The [`Background`] of the header of the table.
+    pub header_background: Option<Background>,
+    /// The text [`Color`] of the header of the table.
+    pub header_text_color: Option<Color>,
+    /// The [`Background`] of a row of the table.
+    pub row_background: Option<Background>,
+    /// The [`Background`] of every other row of the table.
+    pub alternate_row_background: Option<Background>,
+    /// The [`Background`] of a hovered row of the table.
+    pub hovered_row_background: Option<Background>,
+    /// The [`Background`] of a selected row of the table.
+    pub selected_row_background: Background,
+    /// The text [`Color`] of a selected row of the table.
+    pub selected_text_color: Color,
+    /// The [`Color`] of the separators of the table.
+    pub separator: Color,
+}
+
+/// The style of a [`Table`].
+pub type Style<Theme> = fn(&Theme, Status) -> Appearance;
+
+/// The default style of a [`Table`].
+pub trait DefaultStyle {
+    /// Returns the default style of a [`Table`].
+    fn default_style() -> Style<Self>;
+}
+
+impl DefaultStyle for Theme {
+    fn default_style() -> Style<Self> {
+        default
+    }
+}
+
+impl DefaultStyle for Appearance {
+    fn default_style() -> Style<Self> {
+        |appearance, _status| *appearance
+    }
+}
+
+/// The default style of a [`Table`].
+pub fn default(theme: &Theme, status: Status) -> Appearance {
+    let palette = theme.extended_palette();
+
+    Appearance {
+        background: Some(palette.background.base.color.into()),
+        border: Border {
+            color: match status {
+                Status::Active => palette.background.strong.color,
+                Status::Focused => palette.primary.strong.color,
+            },
+            width: 1.0,
+            radius: 2.0.into(),
+        },
+        header_background: Some(palette.background.weak.color.into()),
+        header_text_color: Some(palette.background.weak.text),
+        row_background: None,
+        alternate_row_background: Some(
+            Color {
+                a: 0.5,
+                ..palette.background.weak.color
+            }
+            .into(),
+        ),
+        hovered_row_background: Some(palette.background.weak.color.into()),
+        selected_row_background: palette.primary.base.color.into(),
+        selected_text_color: palette.primary.base.text,
+        separator: palette.background.strong.color,
+    }
+}
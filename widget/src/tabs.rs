@@ -0,0 +1,1100 @@
+//! Switch between different views using a bar of tabs.
+use crate::core::alignment;
+use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text::{self, Text};
+use crate::core::touch;
+use crate::core::widget::operation::Focusable;
+use crate::core::widget::tree;
+use crate::core::widget::{Id, Operation, Tree};
+use crate::core::{
+    Background, Border, Clipboard, Color, Element, Layout, Length, Padding,
+    Pixels, Point, Rectangle, Shell, Size, Theme, Vector, Widget,
+};
+
+/// A bar of tabs displayed on top of the contents of the active one.
+///
+/// Tabs can be selected by clicking them or, while a widget in the contents
+/// is focused, by pressing Ctrl+Tab and Ctrl+Shift+Tab. They can be closed if
+/// closable, and reordered by dragging them. When the tabs do not fit in the
+/// bar, it can be scrolled with the mouse wheel.
+///
+/// When [`Tabs`] widgets are nested, the shortcuts select the tabs of the
+/// innermost one.
+///
+/// Only the contents of the active tab are provided to a [`Tabs`] widget.
+#[allow(missing_debug_implementations)]
+pub struct Tabs<
+    'a,
+    Key,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> {
+    tabs: Vec<Tab<'a, Key, Message, Theme, Renderer>>,
+    active: Key,
+    content: Element<'a, Message, Theme, Renderer>,
+    width: Length,
+    height: Length,
+    padding: Padding,
+    spacing: f32,
+    on_select: Option<Box<dyn Fn(Key) -> Message + 'a>>,
+    on_close: Option<Box<dyn Fn(Key) -> Message + 'a>>,
+    on_reorder: Option<Box<dyn Fn(ReorderEvent) -> Message + 'a>>,
+    style: Style<Theme>,
+}
+
+impl<'a, Key, Message, Theme, Renderer> Tabs<'a, Key, Message, Theme, Renderer>
+where
+    Key: Clone + PartialEq,
+    Renderer: text::Renderer,
+{
+    /// The default [`Padding`] of a tab.
+    pub const DEFAULT_PADDING: Padding = Padding {
+        top: 6.0,
+        right: 12.0,
+        bottom: 6.0,
+        left: 12.0,
+    };
+
+    /// Creates a new [`Tabs`] widget with the given tabs, the key of the
+    /// active tab, and its contents.
+    pub fn new(
+        tabs: impl IntoIterator<Item = Tab<'a, Key, Message, Theme, Renderer>>,
+        active: Key,
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self
+    where
+        Theme: DefaultStyle,
+    {
+        Self {
+            tabs: tabs.into_iter().collect(),
+            active,
+            content: content.into(),
+            width: Length::Fill,
+            height: Length::Fill,
+            padding: Self::DEFAULT_PADDING,
+            spacing: 6.0,
+            on_select: None,
+            on_close: None,
+            on_reorder: None,
+            style: Theme::default_style(),
+        }
+    }
+
+    /// Sets the width of the [`Tabs`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Tabs`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the [`Padding`] of each tab.
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the spacing between the icon, the label, and the close button
+    /// of each tab.
+    pub fn spacing(mut self, amount: impl Into<Pixels>) -> Self {
+        self.spacing = amount.into().0;
+        self
+    }
+
+    /// Sets the message that should be produced when a tab is selected.
+    pub fn on_select(mut self, f: impl Fn(Key) -> Message + 'a) -> Self {
+        self.on_select = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the message that should be produced when a closable tab is
+    /// closed.
+    pub fn on_close(mut self, f: impl Fn(Key) -> Message + 'a) -> Self {
+        self.on_close = Some(Box::new(f));
+        self
+    }
+
+    /// Enables dragging tabs to reorder them, using the provided function to
+    /// produce messages.
+    pub fn on_reorder(
+        mut self,
+        f: impl Fn(ReorderEvent) -> Message + 'a,
+    ) -> Self {
+        self.on_reorder = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the style of the [`Tabs`].
+    pub fn style(mut self, style: fn(&Theme) -> Appearance) -> Self {
+        self.style = style;
+        self
+    }
+
+    fn active_index(&self) -> Option<usize> {
+        self.tabs.iter().position(|tab| tab.key == self.active)
+    }
+
+    fn draw_tab(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        appearance: &Appearance,
+        tab: &Tab<'a, Key, Message, Theme, Renderer>,
+        layout: Layout<'_>,
+        label: &Tree,
+        icon: Option<&Tree>,
+        cursor: mouse::Cursor,
+        is_dragged: bool,
+    ) {
+        let bounds = layout.bounds();
+        let is_active = tab.key == self.active;
+
+        let background = if is_active {
+            appearance.active_tab_background
+        } else if is_dragged || cursor.is_over(bounds) {
+            appearance.hovered_tab_background
+        } else {
+            appearance.tab_background
+        };
+
+        if let Some(background) = background {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border: Border::rounded(appearance.border_radius),
+                    ..renderer::Quad::default()
+                },
+                background,
+            );
+        }
+
+        if is_active {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        y: bounds.y + bounds.height - 2.0,
+                        height: 2.0,
+                        ..bounds
+                    },
+                    ..renderer::Quad::default()
+                },
+                appearance.indicator,
+            );
+        }
+
+        let text_color = if is_active {
+            appearance.active_text_color
+        } else {
+            appearance.text_color.unwrap_or(style.text_color)
+        };
+
+        let mut children = layout.children();
+
+        tab.label.as_widget().draw(
+            label,
+            renderer,
+            theme,
+            &renderer::Style { text_color },
+            children.next().unwrap(),
+            cursor,
+            &bounds,
+        );
+
+        if let (Some(icon), Some(tree)) = (&tab.icon, icon) {
+            icon.as_widget().draw(
+                tree,
+                renderer,
+                theme,
+                &renderer::Style { text_color },
+                children.next().unwrap(),
+                cursor,
+                &bounds,
+            );
+        }
+
+        if tab.is_closable {
+            let size = renderer.default_size();
+            let close = close_bounds(bounds, self.padding, size.0);
+
+            if cursor.is_over(close) {
+                if let Some(background) = appearance.hovered_tab_background {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: close,
+                            border: Border::rounded(appearance.border_radius),
+                            ..renderer::Quad::default()
+                        },
+                        background,
+                    );
+                }
+            }
+
+            renderer.fill_text(
+                Text {
+                    content: "×",
+                    size,
+                    line_height: text::LineHeight::default(),
+                    font: renderer.default_font(),
+                    bounds: close.size(),
+                    horizontal_alignment: alignment::Horizontal::Center,
+                    vertical_alignment: alignment::Vertical::Center,
+                    shaping: text::Shaping::Advanced,
+                },
+                close.center(),
+                text_color,
+                close,
+            );
+        }
+    }
+}
+
+/// A tab of a [`Tabs`] widget.
+#[allow(missing_debug_implementations)]
+pub struct Tab<
+    'a,
+    Key,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> {
+    key: Key,
+    label: Element<'a, Message, Theme, Renderer>,
+    icon: Option<Element<'a, Message, Theme, Renderer>>,
+    is_closable: bool,
+}
+
+impl<'a, Key, Message, Theme, Renderer> Tab<'a, Key, Message, Theme, Renderer> {
+    /// Creates a new [`Tab`] with the given key and label.
+    pub fn new(
+        key: Key,
+        label: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            key,
+            label: label.into(),
+            icon: None,
+            is_closable: false,
+        }
+    }
+
+    /// Sets the icon of the [`Tab`], displayed before its label.
+    pub fn icon(
+        mut self,
+        icon: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Sets whether the [`Tab`] displays a close button.
+    pub fn closable(mut self, is_closable: bool) -> Self {
+        self.is_closable = is_closable;
+        self
+    }
+}
+
+/// A tab being moved to a new position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReorderEvent {
+    /// The current index of the tab.
+    pub from: usize,
+    /// The index the tab should be moved to.
+    pub to: usize,
+}
+
+/// The distance the cursor must travel before a pressed tab is dragged.
+const DRAG_THRESHOLD: f32 = 5.0;
+
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    offset: f32,
+    drag: Option<Drag>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Drag {
+    index: usize,
+    origin: f32,
+    delta: f32,
+    is_dragging: bool,
+}
+
+impl State {
+    fn scroll(&mut self, delta: f32, bar: Layout<'_>) {
+        let max = (tabs_width(bar) - bar.bounds().width).max(0.0);
+
+        self.offset = (self.offset + delta).clamp(0.0, max);
+    }
+
+    fn reveal(&mut self, index: usize, bar: Layout<'_>) {
+        let Some(tab) = bar.children().nth(index) else {
+            return;
+        };
+
+        let start = tab.bounds().x - bar.bounds().x;
+        let end = start + tab.bounds().width;
+        let width = bar.bounds().width;
+
+        if start < self.offset {
+            self.offset = start;
+        } else if end > self.offset + width {
+            self.offset = end - width;
+        }
+
+        self.scroll(0.0, bar);
+    }
+}
+
+fn tabs_width(bar: Layout<'_>) -> f32 {
+    bar.children()
+        .last()
+        .map(|tab| tab.bounds().x + tab.bounds().width - bar.bounds().x)
+        .unwrap_or(0.0)
+}
+
+fn close_bounds(tab: Rectangle, padding: Padding, size: f32) -> Rectangle {
+    Rectangle {
+        x: tab.x + tab.width - padding.right - size,
+        y: tab.center_y() - size / 2.0,
+        width: size,
+        height: size,
+    }
+}
+
+/// Returns the index a dragged tab is dropped at, given the horizontal
+/// centers of all the tabs and the current center of the dragged one.
+fn drop_index(
+    centers: impl Iterator<Item = f32>,
+    from: usize,
+    center: f32,
+) -> usize {
+    centers
+        .enumerate()
+        .filter(|(index, other)| *index != from && *other < center)
+        .count()
+}
+
+/// Returns the index of the tab after the active one or, if `backwards`,
+/// before it; wrapping around at both ends.
+fn cycle(active: usize, count: usize, backwards: bool) -> usize {
+    if backwards {
+        (active + count - 1) % count
+    } else {
+        (active + 1) % count
+    }
+}
+
+/// Returns whether a widget of the given element is focused.
+fn is_focused_within<Message, Theme, Renderer>(
+    element: &Element<'_, Message, Theme, Renderer>,
+    tree: &mut Tree,
+    layout: Layout<'_>,
+    renderer: &Renderer,
+) -> bool
+where
+    Renderer: crate::core::Renderer,
+{
+    struct IsFocused(bool);
+
+    impl<T> Operation<T> for IsFocused {
+        fn focusable(&mut self, state: &mut dyn Focusable, _id: Option<&Id>) {
+            self.0 |= state.is_focused();
+        }
+
+        fn container(
+            &mut self,
+            _id: Option<&Id>,
+            _bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+        ) {
+            if !self.0 {
+                operate_on_children(self);
+            }
+        }
+    }
+
+    let mut operation = IsFocused(false);
+
+    element
+        .as_widget()
+        .operate(tree, layout, renderer, &mut operation);
+
+    operation.0
+}
+
+impl<'a, Key, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Tabs<'a, Key, Message, Theme, Renderer>
+where
+    Key: Clone + PartialEq,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        std::iter::once(&self.content)
+            .chain(self.tabs.iter().flat_map(|tab| {
+                std::iter::once(&tab.label).chain(tab.icon.as_ref())
+            }))
+            .map(Tree::new)
+            .collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let children: Vec<_> = std::iter::once(&self.content)
+            .chain(self.tabs.iter().flat_map(|tab| {
+                std::iter::once(&tab.label).chain(tab.icon.as_ref())
+            }))
+            .collect();
+
+        tree.diff_children(&children);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+        let max = limits.max();
+        let close_size = renderer.default_size().0;
+
+        let (content_tree, tab_trees) = tree.children.split_at_mut(1);
+        let mut tab_trees = tab_trees.iter_mut();
+
+        let item_limits = layout::Limits::new(
+            Size::ZERO,
+            Size::new(f32::INFINITY, max.height),
+        );
+
+        let headers: Vec<_> = self
+            .tabs
+            .iter()
+            .map(|tab| {
+                let label = tab.label.as_widget().layout(
+                    tab_trees.next().unwrap(),
+                    renderer,
+                    &item_limits,
+                );
+
+                let icon = tab.icon.as_ref().map(|icon| {
+                    icon.as_widget().layout(
+                        tab_trees.next().unwrap(),
+                        renderer,
+                        &item_limits,
+                    )
+                });
+
+                let height = label
+                    .size()
+                    .height
+                    .max(icon.as_ref().map_or(0.0, |icon| icon.size().height))
+                    .max(if tab.is_closable { close_size } else { 0.0 });
+
+                let mut x = self.padding.left;
+                let mut children = Vec::with_capacity(2);
+
+                let mut place = |node: layout::Node, x: &mut f32| {
+                    let size = node.size();
+
+                    children.push(node.move_to(Point::new(
+                        *x,
+                        self.padding.top + (height - size.height) / 2.0,
+                    )));
+
+                    *x += size.width + self.spacing;
+                };
+
+                if let Some(icon) = icon {
+                    place(icon, &mut x);
+                }
+
+                // The label goes first, matching the order of the trees
+                place(label, &mut x);
+                children.rotate_right(1);
+
+                if tab.is_closable {
+                    x += close_size;
+                } else {
+                    x -= self.spacing;
+                }
+
+                (x + self.padding.right, height, children)
+            })
+            .collect();
+
+        let bar_height = headers
+            .iter()
+            .map(|(_, height, _)| *height + self.padding.vertical())
+            .fold(0.0, f32::max);
+
+        let mut x = 0.0;
+
+        let headers = headers
+            .into_iter()
+            .map(|(width, height, children)| {
+                let offset =
+                    (bar_height - height - self.padding.vertical()) / 2.0;
+
+                let node = layout::Node::with_children(
+                    Size::new(width, bar_height),
+                    children
+                        .into_iter()
+                        .map(|child| child.translate(Vector::new(0.0, offset)))
+                        .collect(),
+                )
+                .move_to(Point::new(x, 0.0));
+
+                x += width;
+
+                node
+            })
+            .collect();
+
+        let content = self.content.as_widget().layout(
+            &mut content_tree[0],
+            renderer,
+            &limits.shrink(Size::new(0.0, bar_height)),
+        );
+
+        let size = limits.resolve(
+            self.width,
+            self.height,
+            Size::new(content.size().width, bar_height + content.size().height),
+        );
+
+        let bar = layout::Node::with_children(
+            Size::new(size.width, bar_height),
+            headers,
+        );
+
+        layout::Node::with_children(
+            size,
+            vec![bar, content.move_to(Point::new(0.0, bar_height))],
+        )
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        operation.container(None, layout.bounds(), &mut |operation| {
+            self.content.as_widget().operate(
+                &mut tree.children[0],
+                layout.children().nth(1).unwrap(),
+                renderer,
+                operation,
+            );
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let mut children = layout.children();
+        let bar = children.next().unwrap();
+        let content = children.next().unwrap();
+
+        let status = self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event.clone(),
+            content,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        if status == event::Status::Captured {
+            return event::Status::Captured;
+        }
+
+        let state = tree.state.downcast_mut::<State>();
+        let close_size = renderer.default_size().0;
+
+        if let Some(drag) = &mut state.drag {
+            match event {
+                Event::Mouse(mouse::Event::CursorMoved { position })
+                | Event::Touch(touch::Event::FingerMoved {
+                    position, ..
+                }) => {
+                    drag.delta = position.x - drag.origin;
+
+                    if !drag.is_dragging
+                        && self.on_reorder.is_some()
+                        && drag.delta.abs() > DRAG_THRESHOLD
+                    {
+                        drag.is_dragging = true;
+                    }
+
+                    return if drag.is_dragging {
+                        event::Status::Captured
+                    } else {
+                        event::Status::Ignored
+                    };
+                }
+                Event::Mouse(mouse::Event::ButtonReleased(
+                    mouse::Button::Left,
+                ))
+                | Event::Touch(touch::Event::FingerLifted { .. })
+                | Event::Touch(touch::Event::FingerLost { .. }) => {
+                    let drag = *drag;
+                    state.drag = None;
+
+                    if !drag.is_dragging {
+                        return event::Status::Ignored;
+                    }
+
+                    let tab = bar.children().nth(drag.index).unwrap().bounds();
+                    let center = tab.center_x() + drag.delta;
+
+                    let to = drop_index(
+                        bar.children().map(|tab| tab.bounds().center_x()),
+                        drag.index,
+                        center,
+                    );
+
+                    if to != drag.index {
+                        if let Some(on_reorder) = &self.on_reorder {
+                            shell.publish(on_reorder(ReorderEvent {
+                                from: drag.index,
+                                to,
+                            }));
+                        }
+                    }
+
+                    return event::Status::Captured;
+                }
+                _ => {}
+            }
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
+                if !cursor.is_over(bar.bounds()) {
+                    return event::Status::Ignored;
+                }
+
+                let delta = match delta {
+                    mouse::ScrollDelta::Lines { x, y } => {
+                        (if x.abs() > y.abs() { x } else { y }) * 60.0
+                    }
+                    mouse::ScrollDelta::Pixels { x, y } => {
+                        if x.abs() > y.abs() {
+                            x
+                        } else {
+                            y
+                        }
+                    }
+                };
+
+                state.scroll(-delta, bar);
+
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(
+                button @ (mouse::Button::Left | mouse::Button::Middle),
+            )) => {
+                let Some(position) = cursor.position_over(bar.bounds()) else {
+                    return event::Status::Ignored;
+                };
+
+                let position = position + Vector::new(state.offset, 0.0);
+
+                let Some((index, tab)) = bar
+                    .children()
+                    .enumerate()
+                    .find(|(_, tab)| tab.bounds().contains(position))
+                else {
+                    return event::Status::Ignored;
+                };
+
+                let key = &self.tabs[index].key;
+
+                let is_closing = self.tabs[index].is_closable
+                    && (button == mouse::Button::Middle
+                        || close_bounds(
+                            tab.bounds(),
+                            self.padding,
+                            close_size,
+                        )
+                        .contains(position));
+
+                if is_closing {
+                    if let Some(on_close) = &self.on_close {
+                        shell.publish(on_close(key.clone()));
+                    }
+                } else if button == mouse::Button::Left {
+                    if *key != self.active {
+                        if let Some(on_select) = &self.on_select {
+                            shell.publish(on_select(key.clone()));
+                        }
+                    }
+
+                    state.drag = Some(Drag {
+                        index,
+                        origin: position.x - state.offset,
+                        delta: 0.0,
+                        is_dragging: false,
+                    });
+
+                    state.reveal(index, bar);
+                }
+
+                event::Status::Captured
+            }
+            Event::Touch(touch::Event::FingerPressed { position, .. }) => {
+                if !bar.bounds().contains(position) {
+                    return event::Status::Ignored;
+                }
+
+                let position = position + Vector::new(state.offset, 0.0);
+
+                let Some(index) = bar
+                    .children()
+                    .position(|tab| tab.bounds().contains(position))
+                else {
+                    return event::Status::Ignored;
+                };
+
+                if let Some(on_select) = &self.on_select {
+                    shell.publish(on_select(self.tabs[index].key.clone()));
+                }
+
+                event::Status::Captured
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key::Named::Tab),
+                modifiers,
+                ..
+            }) if modifiers.control()
+                && is_focused_within(
+                    &self.content,
+                    &mut tree.children[0],
+                    content,
+                    renderer,
+                ) =>
+            {
+                let (Some(on_select), Some(active)) =
+                    (&self.on_select, self.active_index())
+                else {
+                    return event::Status::Ignored;
+                };
+
+                let index = cycle(active, self.tabs.len(), modifiers.shift());
+
+                if index != active {
+                    shell.publish(on_select(self.tabs[index].key.clone()));
+                    state.reveal(index, bar);
+                }
+
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        let mut children = layout.children();
+        let bar = children.next().unwrap();
+        let content = children.next().unwrap();
+
+        if state.drag.is_some_and(|drag| drag.is_dragging) {
+            return mouse::Interaction::Grabbing;
+        }
+
+        if cursor.is_over(bar.bounds()) {
+            return mouse::Interaction::Pointer;
+        }
+
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            content,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let appearance = (self.style)(theme);
+
+        let mut children = layout.children();
+        let bar = children.next().unwrap();
+        let content = children.next().unwrap();
+        let bar_bounds = bar.bounds();
+
+        if let Some(background) = appearance.bar_background {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: bar_bounds,
+                    ..renderer::Quad::default()
+                },
+                background,
+            );
+        }
+
+        let dragged = state
+            .drag
+            .filter(|drag| drag.is_dragging)
+            .map(|drag| (drag.index, drag.delta));
+
+        // Cursor relative to the scrolled tabs
+        let tab_cursor = match cursor.position_over(bar_bounds) {
+            Some(position) if dragged.is_none() => mouse::Cursor::Available(
+                position + Vector::new(state.offset, 0.0),
+            ),
+            _ => mouse::Cursor::Unavailable,
+        };
+
+        let mut tab_trees = tree.children[1..].iter();
+
+        let tabs: Vec<_> = self
+            .tabs
+            .iter()
+            .zip(bar.children())
+            .map(|(tab, layout)| {
+                let label = tab_trees.next().unwrap();
+                let icon = tab.icon.as_ref().map(|_| tab_trees.next().unwrap());
+
+                (tab, layout, label, icon)
+            })
+            .collect();
+
+        renderer.with_layer(bar_bounds, |renderer| {
+            renderer.with_translation(
+                Vector::new(-state.offset, 0.0),
+                |renderer| {
+                    for (index, (tab, layout, label, icon)) in
+                        tabs.iter().enumerate()
+                    {
+                        if dragged.is_some_and(|(dragged, _)| dragged == index)
+                        {
+                            continue;
+                        }
+
+                        self.draw_tab(
+                            renderer,
+                            theme,
+                            style,
+                            &appearance,
+                            tab,
+                            *layout,
+                            label,
+                            *icon,
+                            tab_cursor,
+                            false,
+                        );
+                    }
+                },
+            );
+
+            if let Some((index, delta)) = dragged {
+                let (tab, layout, label, icon) = tabs[index];
+
+                renderer.with_layer(bar_bounds, |renderer| {
+                    renderer.with_translation(
+                        Vector::new(delta - state.offset, 0.0),
+                        |renderer| {
+                            self.draw_tab(
+                                renderer,
+                                theme,
+                                style,
+                                &appearance,
+                                tab,
+                                layout,
+                                label,
+                                icon,
+                                tab_cursor,
+                                true,
+                            );
+                        },
+                    );
+                });
+            }
+        });
+
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            content,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout.children().nth(1).unwrap(),
+            renderer,
+            translation,
+        )
+    }
+}
+
+impl<'a, Key, Message, Theme, Renderer>
+    From<Tabs<'a, Key, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Key: Clone + PartialEq + 'a,
+    Message: 'a,
+    Theme: 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(tabs: Tabs<'a, Key, Message, Theme, Renderer>) -> Self {
+        Self::new(tabs)
+    }
+}
+
+/// The appearance of a tabs widget.
+#[derive(Debug, Clone, Copy)]
+pub struct Appearance {
+    /// The [`Background`] of the bar of tabs.
+    pub bar_background: Option<Background>,
+    /// The [`Background`] of a tab.
+    pub tab_background: Option<Background>,
+    /// The [`Background`] of a hovered tab.
+    pub hovered_tab_background: Option<Background>,
+    /// The [`Background`] of the active tab.
+    pub active_tab_background: Option<Background>,
+    /// The text [`Color`] of a tab, if different from the default one.
+    pub text_color: Option<Color>,
+    /// The text [`Color`] of the active tab.
+    pub active_text_color: Color,
+    /// The [`Color`] of the line indicating the active tab.
+    pub indicator: Color,
+    /// The border radius of a tab.
+    pub border_radius: f32,
+}
+
+/// The style of a [`Tabs`] widget.
+pub type Style<Theme> = fn(&Theme) -> Appearance;
+
+/// The default style of a [`Tabs`] widget.
+pub trait DefaultStyle {
+    /// Returns the default style of a [`Tabs`] widget.
+    fn default_style() -> Style<Self>;
+}
+
+impl DefaultStyle for Theme {
+    fn default_style() -> Style<Self> {
+        default
+    }
+}
+
+impl DefaultStyle for Appearance {
+    fn default_style() -> Style<Self> {
+        |appearance| *appearance
+    }
+}
+
+/// The default style of a [`Tabs`] widget.
+pub fn default(theme: &Theme) -> Appearance {
+    let palette = theme.extended_palette();
+
+    Appearance {
+        bar_background: Some(palette.background.weak.color.into()),
+        tab_background: None,
+        hovered_tab_background: Some(palette.background.strong.color.into()),
+        active_tab_background: Some(palette.background.base.color.into()),
+        text_color: Some(palette.background.weak.text),
+        active_text_color: palette.background.base.text,
+        indicator: palette.primary.strong.color,
+        border_radius: 0.0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CENTERS: [f32; 4] = [10.0, 30.0, 50.0, 70.0];
+
+    #[test]
+    fn dragged_tabs_drop_between_centers() {
+        let to = |from, center| drop_index(CENTERS.into_iter(), from, center);
+
+        assert_eq!(to(0, 35.0), 1);
+        assert_eq!(to(0, 75.0), 3);
+        assert_eq!(to(3, 5.0), 0);
+        assert_eq!(to(2, 25.0), 1);
+
+        // Tabs stay in place until they pass the center of a neighbor
+        assert_eq!(to(1, 45.0), 1);
+        assert_eq!(to(1, 15.0), 1);
+    }
+
+    #[test]
+    fn shortcuts_wrap_around() {
+        assert_eq!(cycle(0, 3, false), 1);
+        assert_eq!(cycle(2, 3, false), 0);
+        assert_eq!(cycle(0, 3, true), 2);
+        assert_eq!(cycle(1, 3, true), 0);
+        assert_eq!(cycle(0, 1, false), 0);
+        assert_eq!(cycle(0, 1, true), 0);
+    }
+}
@@ -2,6 +2,7 @@
 //!
 //! A [`TextInput`] has some local [`State`].
 mod editor;
+mod history;
 mod value;
 
 pub mod cursor;
@@ -10,6 +11,7 @@ pub use cursor::Cursor;
 pub use value::Value;
 
 use editor::Editor;
+use history::History;
 
 use crate::core::alignment;
 use crate::core::clipboard::{self, Clipboard};
@@ -74,10 +76,18 @@ pub struct TextInput<
     on_input: Option<Box<dyn Fn(String) -> Message + 'a>>,
     on_paste: Option<Box<dyn Fn(String) -> Message + 'a>>,
     on_submit: Option<Message>,
+    mask: Option<Box<Mask<'a>>>,
+    validate: Option<Box<Validator<'a>>>,
     icon: Option<Icon<Renderer::Font>>,
     style: Style<Theme>,
 }
 
+/// A function that rejects or transforms the contents of a [`TextInput`].
+type Mask<'a> = dyn Fn(&str) -> Option<String> + 'a;
+
+/// A function that checks whether the contents of a [`TextInput`] are valid.
+type Validator<'a> = dyn Fn(&str) -> bool + 'a;
+
 /// The default [`Padding`] of a [`TextInput`].
 pub const DEFAULT_PADDING: Padding = Padding::new(5.0);
 
@@ -115,6 +125,8 @@ where
             on_input: None,
             on_paste: None,
             on_submit: None,
+            mask: None,
+            validate: None,
             icon: None,
             style: style.into(),
         }
@@ -161,6 +173,56 @@ where
         self
     }
 
+    /// Sets the mask of the [`TextInput`].
+    ///
+    /// The mask is applied to the contents of the [`TextInput`] after every
+    /// edit, including pastes, before any message is produced. It can reject
+    /// the edit by returning `None`, or replace the contents by returning
+    /// them transformed; for instance, uppercased or with separators. The
+    /// cursor stays after the same amount of alphanumeric characters when
+    /// the contents are replaced.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # type TextInput<'a, Message> = iced_widget::TextInput<'a, Message>;
+    /// #
+    /// # #[derive(Clone)]
+    /// # enum Message { CodeChanged(String) }
+    /// #
+    /// // A license key, like `ABCD-1234-EFGH`
+    /// TextInput::new("License key", "")
+    ///     .on_input(Message::CodeChanged)
+    ///     .mask(|text| {
+    ///         let characters: Vec<char> = text
+    ///             .chars()
+    ///             .filter(char::is_ascii_alphanumeric)
+    ///             .map(|c| c.to_ascii_uppercase())
+    ///             .collect();
+    ///
+    ///         (characters.len() <= 12).then(|| {
+    ///             characters
+    ///                 .chunks(4)
+    ///                 .map(|chunk| chunk.iter().collect::<String>())
+    ///                 .collect::<Vec<_>>()
+    ///                 .join("-")
+    ///         })
+    ///     });
+    /// ```
+    pub fn mask(mut self, mask: impl Fn(&str) -> Option<String> + 'a) -> Self {
+        self.mask = Some(Box::new(mask));
+        self
+    }
+
+    /// Sets the validator of the [`TextInput`].
+    ///
+    /// The [`TextInput`] has an [`Status::Invalid`] status whenever the
+    /// validator returns `false` for its contents, which takes precedence
+    /// over [`Status::Hovered`] and [`Status::Focused`].
+    pub fn validate(mut self, validate: impl Fn(&str) -> bool + 'a) -> Self {
+        self.validate = Some(Box::new(validate));
+        self
+    }
+
     /// Sets the [`Font`] of the [`TextInput`].
     ///
     /// [`Font`]: text::Renderer::Font
@@ -319,21 +381,19 @@ where
         viewport: &Rectangle,
     ) {
         let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
-        let value = value.unwrap_or(&self.value);
         let is_disabled = self.on_input.is_none();
+        let is_mouse_over = cursor.is_over(layout.bounds());
 
-        let secure_value = self.is_secure.then(|| value.secure());
-        let value = secure_value.as_ref().unwrap_or(value);
-
-        let bounds = layout.bounds();
-
-        let mut children_layout = layout.children();
-        let text_bounds = children_layout.next().unwrap().bounds();
-
-        let is_mouse_over = cursor.is_over(bounds);
+        let is_invalid = self.validate.as_ref().is_some_and(|validate| {
+            !validate(&value.unwrap_or(&self.value).to_string())
+        });
 
         let status = if is_disabled {
             Status::Disabled
+        } else if is_invalid {
+            Status::Invalid {
+                is_focused: state.is_focused(),
+            }
         } else if state.is_focused() {
             Status::Focused
         } else if is_mouse_over {
@@ -344,6 +404,36 @@ where
 
         let appearance = (self.style)(theme, status);
 
+        self.draw_with_appearance(
+            tree, renderer, layout, value, appearance, viewport,
+        );
+    }
+
+    /// Draws the [`TextInput`] with the given [`Appearance`], ignoring its
+    /// style.
+    ///
+    /// This lets widgets embedding a [`TextInput`] style it with a status of
+    /// their own.
+    pub(crate) fn draw_with_appearance(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        value: Option<&Value>,
+        appearance: Appearance,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+        let value = value.unwrap_or(&self.value);
+
+        let secure_value = self.is_secure.then(|| value.secure());
+        let value = secure_value.as_ref().unwrap_or(value);
+
+        let bounds = layout.bounds();
+
+        let mut children_layout = layout.children();
+        let text_bounds = children_layout.next().unwrap().bounds();
+
         renderer.fill_quad(
             renderer::Quad {
                 bounds,
@@ -505,6 +595,8 @@ where
     fn diff(&self, tree: &mut Tree) {
         let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
 
+        state.history.sync(&self.value);
+
         // Unfocus text input if it becomes disabled
         if self.on_input.is_none() {
             state.last_click = None;
@@ -533,14 +625,16 @@ where
     fn operate(
         &self,
         tree: &mut Tree,
-        _layout: Layout<'_>,
+        layout: Layout<'_>,
         _renderer: &Renderer,
         operation: &mut dyn Operation<Message>,
     ) {
         let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        let id = self.id.as_ref().map(|id| &id.0);
 
-        operation.focusable(state, self.id.as_ref().map(|id| &id.0));
-        operation.text_input(state, self.id.as_ref().map(|id| &id.0));
+        operation.bounds(id, layout.bounds());
+        operation.focusable(state, id);
+        operation.text_input(state, id);
     }
 
     fn on_event(
@@ -724,23 +818,34 @@ where
                         keyboard::Key::Character("x")
                             if state.keyboard_modifiers.command() =>
                         {
-                            if let Some((start, end)) =
-                                state.cursor.selection(&self.value)
-                            {
-                                clipboard.write(
-                                    clipboard::Kind::Standard,
-                                    self.value.select(start, end).to_string(),
-                                );
-                            }
-
-                            let mut editor =
-                                Editor::new(&mut self.value, &mut state.cursor);
-                            editor.delete();
+                            let selection = state
+                                .cursor
+                                .selection(&self.value)
+                                .map(|(start, end)| {
+                                    self.value.select(start, end).to_string()
+                                });
+
+                            // The selection is only cut if the mask accepts
+                            // the remaining contents
+                            if let Some(contents) = edit(
+                                &mut self.value,
+                                &mut state.cursor,
+                                &mut state.history,
+                                history::Kind::Cut,
+                                self.mask.as_deref(),
+                                |editor| editor.delete(),
+                            ) {
+                                if let Some(selection) = selection {
+                                    clipboard.write(
+                                        clipboard::Kind::Standard,
+                                        selection,
+                                    );
+                                }
 
-                            let message = (on_input)(editor.contents());
-                            shell.publish(message);
+                                shell.publish((on_input)(contents));
 
-                            update_cache(state, &self.value);
+                                update_cache(state, &self.value);
+                            }
 
                             return event::Status::Captured;
                         }
@@ -762,21 +867,55 @@ where
                                 }
                             };
 
-                            let mut editor =
-                                Editor::new(&mut self.value, &mut state.cursor);
+                            if let Some(contents) = edit(
+                                &mut self.value,
+                                &mut state.cursor,
+                                &mut state.history,
+                                history::Kind::Paste,
+                                self.mask.as_deref(),
+                                |editor| editor.paste(content.clone()),
+                            ) {
+                                let message =
+                                    if let Some(paste) = &self.on_paste {
+                                        (paste)(contents)
+                                    } else {
+                                        (on_input)(contents)
+                                    };
+                                shell.publish(message);
+
+                                update_cache(state, &self.value);
+                            }
 
-                            editor.paste(content.clone());
+                            state.is_pasting = Some(content);
 
-                            let message = if let Some(paste) = &self.on_paste {
-                                (paste)(editor.contents())
+                            return event::Status::Captured;
+                        }
+                        keyboard::Key::Character("z" | "Z" | "y" | "Y")
+                            if state.keyboard_modifiers.command() =>
+                        {
+                            let is_redo = modifiers.shift()
+                                || matches!(
+                                    key.as_ref(),
+                                    keyboard::Key::Character("y" | "Y")
+                                );
+
+                            let is_changed = if is_redo {
+                                state
+                                    .history
+                                    .redo(&mut self.value, &mut state.cursor)
                             } else {
-                                (on_input)(editor.contents())
+                                state
+                                    .history
+                                    .undo(&mut self.value, &mut state.cursor)
                             };
-                            shell.publish(message);
 
-                            state.is_pasting = Some(content);
+                            if is_changed {
+                                shell.publish((on_input)(
+                                    self.value.to_string(),
+                                ));
 
-                            update_cache(state, &self.value);
+                                update_cache(state, &self.value);
+                            }
 
                             return event::Status::Captured;
                         }
@@ -796,17 +935,20 @@ where
                         if let Some(c) =
                             text.chars().next().filter(|c| !c.is_control())
                         {
-                            let mut editor =
-                                Editor::new(&mut self.value, &mut state.cursor);
-
-                            editor.insert(c);
-
-                            let message = (on_input)(editor.contents());
-                            shell.publish(message);
-
-                            focus.updated_at = Instant::now();
-
-                            update_cache(state, &self.value);
+                            if let Some(contents) = edit(
+                                &mut self.value,
+                                &mut state.cursor,
+                                &mut state.history,
+                                history::Kind::Insert,
+                                self.mask.as_deref(),
+                                |editor| editor.insert(c),
+                            ) {
+                                shell.publish((on_input)(contents));
+
+                                focus.updated_at = Instant::now();
+
+                                update_cache(state, &self.value);
+                            }
 
                             return event::Status::Captured;
                         }
@@ -833,14 +975,18 @@ where
                                 }
                             }
 
-                            let mut editor =
-                                Editor::new(&mut self.value, &mut state.cursor);
-                            editor.backspace();
-
-                            let message = (on_input)(editor.contents());
-                            shell.publish(message);
-
-                            update_cache(state, &self.value);
+                            if let Some(contents) = edit(
+                                &mut self.value,
+                                &mut state.cursor,
+                                &mut state.history,
+                                history::Kind::Delete,
+                                self.mask.as_deref(),
+                                |editor| editor.backspace(),
+                            ) {
+                                shell.publish((on_input)(contents));
+
+                                update_cache(state, &self.value);
+                            }
                         }
                         keyboard::Key::Named(key::Named::Delete) => {
                             if platform::is_jump_modifier_pressed(modifiers)
@@ -860,14 +1006,18 @@ where
                                 }
                             }
 
-                            let mut editor =
-                                Editor::new(&mut self.value, &mut state.cursor);
-                            editor.delete();
-
-                            let message = (on_input)(editor.contents());
-                            shell.publish(message);
-
-                            update_cache(state, &self.value);
+                            if let Some(contents) = edit(
+                                &mut self.value,
+                                &mut state.cursor,
+                                &mut state.history,
+                                history::Kind::Delete,
+                                self.mask.as_deref(),
+                                |editor| editor.delete(),
+                            ) {
+                                shell.publish((on_input)(contents));
+
+                                update_cache(state, &self.value);
+                            }
                         }
                         keyboard::Key::Named(key::Named::ArrowLeft) => {
                             if platform::is_jump_modifier_pressed(modifiers)
@@ -1155,6 +1305,7 @@ pub struct State<P: text::Paragraph> {
     is_pasting: Option<Value>,
     last_click: Option<mouse::Click>,
     cursor: Cursor,
+    history: History,
     keyboard_modifiers: keyboard::Modifiers,
     // TODO: Add stateful horizontal scrolling offset
 }
@@ -1189,6 +1340,7 @@ impl<P: text::Paragraph> State<P> {
             is_pasting: None,
             last_click: None,
             cursor: Cursor::default(),
+            history: History::default(),
             keyboard_modifiers: keyboard::Modifiers::default(),
         }
     }
@@ -1379,6 +1531,53 @@ fn replace_paragraph<Renderer>(
     });
 }
 
+/// Applies an edit of the given [`Kind`] to the [`Value`] of a [`TextInput`],
+/// running its new contents through the given mask, if any, and recording it
+/// in its [`History`].
+///
+/// Returns the new contents, or `None` if the mask rejected the edit; in
+/// which case, the [`Value`] and the [`Cursor`] are left untouched.
+///
+/// [`Kind`]: history::Kind
+fn edit(
+    value: &mut Value,
+    cursor: &mut Cursor,
+    history: &mut History,
+    kind: history::Kind,
+    mask: Option<&Mask<'_>>,
+    f: impl FnOnce(&mut Editor<'_>),
+) -> Option<String> {
+    let previous = (value.clone(), *cursor);
+
+    let mut editor = Editor::new(value, cursor);
+    f(&mut editor);
+
+    let mut contents = editor.contents();
+
+    if let Some(mask) = mask {
+        match mask(&contents) {
+            Some(masked) => {
+                if masked != contents {
+                    editor.replace(Value::new(&masked));
+                }
+
+                contents = masked;
+            }
+            None => {
+                (*value, *cursor) = previous;
+
+                return None;
+            }
+        }
+    }
+
+    if previous.0.to_string() != contents {
+        history.record(kind, previous, (value, cursor));
+    }
+
+    Some(contents)
+}
+
 const CURSOR_BLINK_INTERVAL_MILLIS: u128 = 500;
 
 /// The possible status of a [`TextInput`].
@@ -1392,6 +1591,11 @@ pub enum Status {
     Focused,
     /// The [`TextInput`] cannot be interacted with.
     Disabled,
+    /// The contents of the [`TextInput`] are not valid.
+    Invalid {
+        /// Whether the [`TextInput`] is focused.
+        is_focused: bool,
+    },
 }
 
 /// The appearance of a text input.
@@ -1470,5 +1674,105 @@ pub fn default(theme: &Theme, status: Status) -> Appearance {
             value: active.placeholder,
             ..active
         },
+        Status::Invalid { is_focused } => Appearance {
+            border: Border {
+                color: if is_focused {
+                    palette.danger.strong.color
+                } else {
+                    palette.danger.base.color
+                },
+                width: if is_focused { 2.0 } else { 1.0 },
+                ..active.border
+            },
+            selection: palette.danger.weak.color,
+            ..active
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn license_key(text: &str) -> Option<String> {
+        let characters: Vec<char> = text
+            .chars()
+            .filter(char::is_ascii_alphanumeric)
+            .map(|c| c.to_ascii_uppercase())
+            .collect();
+
+        (characters.len() <= 8).then(|| {
+            characters
+                .chunks(4)
+                .map(|chunk| chunk.iter().collect::<String>())
+                .collect::<Vec<_>>()
+                .join("-")
+        })
+    }
+
+    fn position(cursor: Cursor, value: &Value) -> usize {
+        match cursor.state(value) {
+            cursor::State::Index(index) => index,
+            cursor::State::Selection { end, .. } => end,
+        }
+    }
+
+    #[test]
+    fn mask_reformats_and_keeps_cursor() {
+        let mut value = Value::new("abcd");
+        let mut cursor = Cursor::default();
+        cursor.move_to(4);
+
+        let contents = edit(
+            &mut value,
+            &mut cursor,
+            &mut History::default(),
+            history::Kind::Insert,
+            Some(&license_key),
+            |editor| {
+                editor.insert('e');
+            },
+        );
+
+        assert_eq!(contents.as_deref(), Some("ABCD-E"));
+        assert_eq!(position(cursor, &value), 6);
+
+        cursor.move_to(2);
+
+        let contents = edit(
+            &mut value,
+            &mut cursor,
+            &mut History::default(),
+            history::Kind::Insert,
+            Some(&license_key),
+            |editor| {
+                editor.insert('x');
+            },
+        );
+
+        assert_eq!(contents.as_deref(), Some("ABXC-DE"));
+        assert_eq!(position(cursor, &value), 3);
+    }
+
+    #[test]
+    fn mask_rejects_edits() {
+        let mut value = Value::new("ABCD-EFGH");
+        let mut cursor = Cursor::default();
+        cursor.move_to(2);
+
+        let contents = edit(
+            &mut value,
+            &mut cursor,
+            &mut History::default(),
+            history::Kind::Insert,
+            Some(&license_key),
+            |editor| {
+                editor.paste(Value::new("xyz"));
+            },
+        );
+
+        assert_eq!(contents, None);
+        assert_eq!(value.to_string(), "ABCD-EFGH");
+        assert_eq!(position(cursor, &value), 2);
     }
 }
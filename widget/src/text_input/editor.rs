@@ -36,6 +36,25 @@ impl<'a> Editor<'a> {
         self.cursor.move_right_by_amount(self.value, length);
     }
 
+    /// Replaces the contents with the given [`Value`]; usually, a reformatted
+    /// version of them.
+    ///
+    /// The cursor stays after the same amount of alphanumeric graphemes, so
+    /// that any separators added or removed do not move it around.
+    pub fn replace(&mut self, content: Value) {
+        let position = self.cursor.end(self.value);
+
+        let position = if position >= self.value.len() {
+            content.len()
+        } else {
+            content
+                .after_alphanumerics(self.value.alphanumerics_before(position))
+        };
+
+        *self.value = content;
+        self.cursor.move_to(position);
+    }
+
     pub fn backspace(&mut self) {
         match self.cursor.selection(self.value) {
             Some((start, end)) => {
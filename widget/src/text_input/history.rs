@@ -0,0 +1,191 @@
+use crate::text_input::{Cursor, Value};
+
+/// The maximum amount of steps kept in a [`History`].
+const MAX_STEPS: usize = 100;
+
+/// The edit history of a text input.
+#[derive(Debug, Clone, Default)]
+pub struct History {
+    undo: Vec<Snapshot>,
+    redo: Vec<Snapshot>,
+    /// The kind of the last edit, and the cursor position right after it.
+    ///
+    /// Consecutive edits of the same kind are grouped into a single step, as
+    /// long as the cursor has not moved in between.
+    group: Option<(Kind, usize)>,
+    /// The contents of the text input after the last change known to the
+    /// history.
+    contents: Option<String>,
+}
+
+/// The kind of an edit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kind {
+    Insert,
+    Delete,
+    Paste,
+    Cut,
+}
+
+#[derive(Debug, Clone)]
+struct Snapshot {
+    value: Value,
+    cursor: Cursor,
+}
+
+impl History {
+    /// Records an edit of the given [`Kind`], which turned the `before`
+    /// contents into the `after` contents.
+    pub fn record(
+        &mut self,
+        kind: Kind,
+        before: (Value, Cursor),
+        after: (&Value, &Cursor),
+    ) {
+        let (value, cursor) = before;
+        let position = cursor.end(&value);
+
+        let is_grouped = matches!(kind, Kind::Insert | Kind::Delete)
+            && cursor.selection(&value).is_none()
+            && self.group == Some((kind, position));
+
+        if !is_grouped {
+            if self.undo.len() == MAX_STEPS {
+                let _ = self.undo.remove(0);
+            }
+
+            self.undo.push(Snapshot { value, cursor });
+        }
+
+        self.redo.clear();
+        self.group = Some((kind, after.1.end(after.0)));
+        self.contents = Some(after.0.to_string());
+    }
+
+    /// Reverts the last step, restoring the given [`Value`] and [`Cursor`].
+    ///
+    /// Returns `false` if there was nothing to undo.
+    pub fn undo(&mut self, value: &mut Value, cursor: &mut Cursor) -> bool {
+        let Some(snapshot) = self.undo.pop() else {
+            return false;
+        };
+
+        let current = self.restore(snapshot, value, cursor);
+        self.redo.push(current);
+
+        true
+    }
+
+    /// Reapplies the last step reverted, restoring the given [`Value`] and
+    /// [`Cursor`].
+    ///
+    /// Returns `false` if there was nothing to redo.
+    pub fn redo(&mut self, value: &mut Value, cursor: &mut Cursor) -> bool {
+        let Some(snapshot) = self.redo.pop() else {
+            return false;
+        };
+
+        let current = self.restore(snapshot, value, cursor);
+        self.undo.push(current);
+
+        true
+    }
+
+    /// Clears the [`History`] if the given [`Value`] was changed by someone
+    /// else; that is, not by an edit of the text input.
+    pub fn sync(&mut self, value: &Value) {
+        let contents = value.to_string();
+
+        if self
+            .contents
+            .as_ref()
+            .is_some_and(|known| *known != contents)
+        {
+            self.undo.clear();
+            self.redo.clear();
+            self.group = None;
+        }
+
+        self.contents = Some(contents);
+    }
+
+    fn restore(
+        &mut self,
+        snapshot: Snapshot,
+        value: &mut Value,
+        cursor: &mut Cursor,
+    ) -> Snapshot {
+        let current = Snapshot {
+            value: std::mem::replace(value, snapshot.value),
+            cursor: std::mem::replace(cursor, snapshot.cursor),
+        };
+
+        self.group = None;
+        self.contents = Some(value.to_string());
+
+        current
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn type_text(
+        history: &mut History,
+        value: &mut Value,
+        cursor: &mut Cursor,
+        text: &str,
+    ) {
+        for c in text.chars() {
+            let before = (value.clone(), *cursor);
+
+            value.insert(cursor.end(value), c);
+            cursor.move_right(value);
+
+            history.record(Kind::Insert, before, (value, cursor));
+        }
+    }
+
+    #[test]
+    fn typing_is_grouped() {
+        let mut history = History::default();
+        let mut value = Value::new("");
+        let mut cursor = Cursor::default();
+
+        type_text(&mut history, &mut value, &mut cursor, "hello");
+
+        cursor.move_to(0);
+        type_text(&mut history, &mut value, &mut cursor, "oh ");
+
+        assert_eq!(value.to_string(), "oh hello");
+
+        assert!(history.undo(&mut value, &mut cursor));
+        assert_eq!(value.to_string(), "hello");
+        assert_eq!(cursor.end(&value), 0);
+
+        assert!(history.undo(&mut value, &mut cursor));
+        assert_eq!(value.to_string(), "");
+        assert!(!history.undo(&mut value, &mut cursor));
+
+        assert!(history.redo(&mut value, &mut cursor));
+        assert!(history.redo(&mut value, &mut cursor));
+        assert_eq!(value.to_string(), "oh hello");
+        assert!(!history.redo(&mut value, &mut cursor));
+    }
+
+    #[test]
+    fn external_changes_clear_history() {
+        let mut history = History::default();
+        let mut value = Value::new("");
+        let mut cursor = Cursor::default();
+
+        type_text(&mut history, &mut value, &mut cursor, "abc");
+
+        history.sync(&value);
+        assert!(!history.undo.is_empty());
+
+        history.sync(&Value::new("xyz"));
+        assert!(!history.undo(&mut value, &mut cursor));
+    }
+}
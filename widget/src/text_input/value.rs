@@ -31,6 +31,31 @@ impl Value {
         self.graphemes.len()
     }
 
+    /// Returns the amount of alphanumeric graphemes before the given grapheme
+    /// `index`.
+    pub fn alphanumerics_before(&self, index: usize) -> usize {
+        self.graphemes[..index.min(self.len())]
+            .iter()
+            .filter(|grapheme| is_alphanumeric(grapheme))
+            .count()
+    }
+
+    /// Returns the position right after the given amount of alphanumeric
+    /// graphemes, or the end of the [`Value`] if it does not contain that
+    /// many.
+    pub fn after_alphanumerics(&self, amount: usize) -> usize {
+        if amount == 0 {
+            return 0;
+        }
+
+        self.graphemes
+            .iter()
+            .enumerate()
+            .filter(|(_, grapheme)| is_alphanumeric(grapheme))
+            .nth(amount - 1)
+            .map_or(self.len(), |(index, _)| index + 1)
+    }
+
     /// Returns the position of the previous start of a word from the given
     /// grapheme `index`.
     pub fn previous_start_of_word(&self, index: usize) -> usize {
@@ -132,3 +157,7 @@ impl std::fmt::Display for Value {
         f.write_str(&self.graphemes.concat())
     }
 }
+
+fn is_alphanumeric(grapheme: &str) -> bool {
+    grapheme.chars().any(char::is_alphanumeric)
+}
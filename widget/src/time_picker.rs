@@ -0,0 +1,646 @@
+//! Pick a time of the day.
+use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text;
+use crate::core::touch;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::Operation;
+use crate::core::{
+    Border, Clipboard, Element, Layout, Length, Padding, Pixels, Point,
+    Rectangle, Shell, Size, Vector, Widget,
+};
+use crate::date_picker::{
+    cell_size, draw_cell, draw_field, layout_field, position_popup, Field,
+    POPUP_PADDING,
+};
+
+pub use crate::date_picker::{
+    default, Appearance, DefaultStyle, Status, Style,
+};
+
+use std::fmt;
+
+/// An input-like field that opens a popup to pick a [`Time`].
+///
+/// The popup shows the hours of the day next to the minutes, in steps of
+/// [`TimePicker::minute_step`]. Picking an hour moves on to the minutes,
+/// and picking a minute picks the time.
+///
+/// The popup can be navigated with the keyboard: the arrow keys move between
+/// values, Tab switches between hours and minutes, Enter picks a value, and
+/// Escape closes the popup.
+#[allow(missing_debug_implementations)]
+pub struct TimePicker<
+    'a,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> where
+    Renderer: text::Renderer,
+{
+    selected: Option<Time>,
+    on_select: Box<dyn Fn(Time) -> Message + 'a>,
+    minute_step: u8,
+    placeholder: Option<String>,
+    width: Length,
+    padding: Padding,
+    text_size: Option<Pixels>,
+    font: Option<Renderer::Font>,
+    style: Style<Theme>,
+}
+
+impl<'a, Message, Theme, Renderer> TimePicker<'a, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    /// The default [`Padding`] of a [`TimePicker`].
+    pub const DEFAULT_PADDING: Padding = Padding::new(5.0);
+
+    /// Creates a new [`TimePicker`] with the selected [`Time`], if any, and
+    /// the message to produce when a time is picked.
+    pub fn new(
+        selected: Option<Time>,
+        on_select: impl Fn(Time) -> Message + 'a,
+    ) -> Self
+    where
+        Theme: DefaultStyle,
+    {
+        Self {
+            selected,
+            on_select: Box::new(on_select),
+            minute_step: 5,
+            placeholder: None,
+            width: Length::Shrink,
+            padding: Self::DEFAULT_PADDING,
+            text_size: None,
+            font: None,
+            style: Theme::default_style(),
+        }
+    }
+
+    /// Sets the step between the minutes that can be picked.
+    ///
+    /// By default, minutes can be picked in steps of 5.
+    pub fn minute_step(mut self, step: u8) -> Self {
+        self.minute_step = step.clamp(1, 30);
+        self
+    }
+
+    /// Sets the placeholder of the [`TimePicker`].
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    /// Sets the width of the [`TimePicker`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`TimePicker`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the [`TimePicker`].
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(size.into());
+        self
+    }
+
+    /// Sets the font of the [`TimePicker`].
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the style of the [`TimePicker`].
+    pub fn style(mut self, style: fn(&Theme, Status) -> Appearance) -> Self {
+        self.style = style;
+        self
+    }
+
+    fn minutes(&self) -> usize {
+        usize::from(60u8.div_ceil(self.minute_step))
+    }
+}
+
+/// The amount of columns of the grids of a [`TimePicker`].
+const COLUMNS: usize = 6;
+
+/// The space between the hours and the minutes.
+const SPACING: f32 = 12.0;
+
+/// The values of a [`TimePicker`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum Part {
+    #[default]
+    Hours,
+    Minutes,
+}
+
+/// The local state of a [`TimePicker`].
+#[derive(Debug, Clone, Default)]
+struct State {
+    field: Field,
+    /// The time being picked.
+    pending: Time,
+    part: Part,
+    is_keyboard: bool,
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for TimePicker<'a, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout_field(
+            renderer,
+            limits,
+            self.width,
+            self.padding,
+            self.text_size,
+            self.font,
+            "00:00",
+            self.placeholder.as_deref(),
+        )
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        operation.bounds(None, layout.bounds());
+        operation.focusable(&mut state.field, None);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        _shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        let (status, is_opened) =
+            state.field.update(&event, layout.bounds(), cursor);
+
+        if is_opened {
+            let time = self.selected.unwrap_or_default();
+
+            state.pending = Time {
+                minute: time.minute - time.minute % self.minute_step,
+                ..time
+            };
+            state.part = Part::Hours;
+            state.is_keyboard = false;
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+
+        let appearance =
+            (self.style)(theme, state.field.status(cursor.is_over(bounds)));
+
+        draw_field(
+            renderer,
+            &appearance,
+            bounds,
+            self.padding,
+            self.text_size,
+            self.font,
+            self.selected.map(|time| time.to_string()).as_deref(),
+            self.placeholder.as_deref(),
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = tree.state.downcast_mut::<State>();
+
+        if !state.field.is_open {
+            return None;
+        }
+
+        let bounds = layout.bounds();
+
+        Some(overlay::Element::new(Box::new(Clock {
+            picker: self,
+            state,
+            position: bounds.position() + translation,
+            target_height: bounds.height,
+        })))
+    }
+}
+
+impl<'a, Message, Theme, Renderer>
+    From<TimePicker<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(time_picker: TimePicker<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(time_picker)
+    }
+}
+
+/// The popup of an open [`TimePicker`].
+struct Clock<'a, 'b, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    picker: &'b TimePicker<'a, Message, Theme, Renderer>,
+    state: &'b mut State,
+    position: Point,
+    target_height: f32,
+}
+
+impl<'a, 'b, Message, Theme, Renderer> Clock<'a, 'b, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    /// Returns the bounds of every value of the given [`Part`].
+    fn cells(
+        &self,
+        bounds: Rectangle,
+        cell: f32,
+        part: Part,
+    ) -> impl Iterator<Item = (u8, Rectangle)> + '_ {
+        let (x, count, step) = match part {
+            Part::Hours => (bounds.x + POPUP_PADDING, 24, 1),
+            Part::Minutes => (
+                bounds.x + POPUP_PADDING + COLUMNS as f32 * cell + SPACING,
+                self.picker.minutes(),
+                self.picker.minute_step,
+            ),
+        };
+
+        (0..count).map(move |i| {
+            (
+                i as u8 * step,
+                Rectangle {
+                    x: x + (i % COLUMNS) as f32 * cell,
+                    y: bounds.y + POPUP_PADDING + (i / COLUMNS) as f32 * cell,
+                    width: cell,
+                    height: cell,
+                },
+            )
+        })
+    }
+
+    fn pick(&mut self, part: Part, value: u8, shell: &mut Shell<'_, Message>) {
+        match part {
+            Part::Hours => {
+                self.state.pending.hour = value;
+                self.state.part = Part::Minutes;
+            }
+            Part::Minutes => {
+                self.state.pending.minute = value;
+                self.state.field.is_open = false;
+
+                shell.publish((self.picker.on_select)(self.state.pending));
+            }
+        }
+    }
+
+    /// Moves the value of the focused [`Part`] by the given amount of cells.
+    fn step(&mut self, amount: isize) {
+        let (value, count, step) = match self.state.part {
+            Part::Hours => (&mut self.state.pending.hour, 24, 1),
+            Part::Minutes => (
+                &mut self.state.pending.minute,
+                self.picker.minutes(),
+                self.picker.minute_step,
+            ),
+        };
+
+        let index = (usize::from(*value / step) as isize + amount)
+            .clamp(0, count as isize - 1);
+
+        *value = index as u8 * step;
+        self.state.is_keyboard = true;
+    }
+}
+
+impl<'a, 'b, Message, Theme, Renderer>
+    overlay::Overlay<Message, Theme, Renderer>
+    for Clock<'a, 'b, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> layout::Node {
+        let cell = cell_size(
+            self.picker
+                .text_size
+                .unwrap_or_else(|| renderer.default_size()),
+        );
+
+        let rows = 4.max(self.picker.minutes().div_ceil(COLUMNS));
+
+        let size = Size::new(
+            2.0 * COLUMNS as f32 * cell + SPACING + 2.0 * POPUP_PADDING,
+            rows as f32 * cell + 2.0 * POPUP_PADDING,
+        );
+
+        layout::Node::new(size).move_to(position_popup(
+            self.position,
+            self.target_height,
+            size,
+            bounds,
+        ))
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                self.state.is_keyboard = false;
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if !cursor.is_over(bounds) {
+                    return event::Status::Ignored;
+                }
+
+                let cell = cell_size(
+                    self.picker
+                        .text_size
+                        .unwrap_or_else(|| renderer.default_size()),
+                );
+
+                let picked =
+                    [Part::Hours, Part::Minutes].into_iter().find_map(|part| {
+                        self.cells(bounds, cell, part)
+                            .find(|(_, bounds)| cursor.is_over(*bounds))
+                            .map(|(value, _)| (part, value))
+                    });
+
+                if let Some((part, value)) = picked {
+                    self.pick(part, value, shell);
+                }
+
+                return event::Status::Captured;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed { key, .. }) => {
+                match key.as_ref() {
+                    keyboard::Key::Named(key::Named::ArrowLeft) => {
+                        self.step(-1);
+                    }
+                    keyboard::Key::Named(key::Named::ArrowRight) => {
+                        self.step(1);
+                    }
+                    keyboard::Key::Named(key::Named::ArrowUp) => {
+                        self.step(-(COLUMNS as isize));
+                    }
+                    keyboard::Key::Named(key::Named::ArrowDown) => {
+                        self.step(COLUMNS as isize);
+                    }
+                    keyboard::Key::Named(key::Named::Tab) => {
+                        self.state.part = match self.state.part {
+                            Part::Hours => Part::Minutes,
+                            Part::Minutes => Part::Hours,
+                        };
+                        self.state.is_keyboard = true;
+                    }
+                    keyboard::Key::Named(
+                        key::Named::Enter | key::Named::Space,
+                    ) => {
+                        let value = match self.state.part {
+                            Part::Hours => self.state.pending.hour,
+                            Part::Minutes => self.state.pending.minute,
+                        };
+
+                        self.pick(self.state.part, value, shell);
+                        self.state.is_keyboard = true;
+                    }
+                    keyboard::Key::Named(key::Named::Escape) => {
+                        self.state.field.is_open = false;
+                    }
+                    _ => return event::Status::Ignored,
+                }
+
+                return event::Status::Captured;
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let cell = cell_size(
+            self.picker
+                .text_size
+                .unwrap_or_else(|| renderer.default_size()),
+        );
+
+        let is_over_value =
+            [Part::Hours, Part::Minutes].into_iter().any(|part| {
+                self.cells(layout.bounds(), cell, part)
+                    .any(|(_, bounds)| cursor.is_over(bounds))
+            });
+
+        if is_over_value {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) {
+        let appearance = (self.picker.style)(theme, Status::Opened);
+        let bounds = layout.bounds();
+        let text_size = self
+            .picker
+            .text_size
+            .unwrap_or_else(|| renderer.default_size());
+        let font = self.picker.font.unwrap_or_else(|| renderer.default_font());
+        let cell = cell_size(text_size);
+        let radius = Border::rounded(appearance.popup_border.radius);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: appearance.popup_border,
+                ..renderer::Quad::default()
+            },
+            appearance.popup_background,
+        );
+
+        for part in [Part::Hours, Part::Minutes] {
+            let pending = match part {
+                Part::Hours => self.state.pending.hour,
+                Part::Minutes => self.state.pending.minute,
+            };
+
+            for (value, cell_bounds) in self.cells(bounds, cell, part) {
+                let is_selected = value == pending;
+                let is_focused = self.state.is_keyboard
+                    && self.state.part == part
+                    && is_selected;
+
+                let background = if is_selected {
+                    Some(appearance.selected_background)
+                } else if cursor.is_over(cell_bounds) {
+                    Some(appearance.hovered_background)
+                } else {
+                    None
+                };
+
+                let border = if is_focused {
+                    Border {
+                        color: appearance.today_border,
+                        width: 2.0,
+                        ..radius
+                    }
+                } else {
+                    radius
+                };
+
+                draw_cell(
+                    renderer,
+                    cell_bounds,
+                    &format!("{value:02}"),
+                    text_size,
+                    font,
+                    if is_selected {
+                        appearance.selected_text_color
+                    } else {
+                        appearance.text_color
+                    },
+                    background,
+                    border,
+                );
+            }
+        }
+    }
+}
+
+/// A time of the day, with a precision of minutes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Time {
+    hour: u8,
+    minute: u8,
+}
+
+impl Time {
+    /// Creates a new [`Time`] from its hour (from 0 to 23) and minute (from 0
+    /// to 59).
+    ///
+    /// Returns `None` if the time does not exist.
+    pub fn from_hm(hour: u8, minute: u8) -> Option<Self> {
+        (hour < 24 && minute < 60).then_some(Self { hour, minute })
+    }
+
+    /// Returns the hour of the [`Time`], from 0 to 23.
+    pub fn hour(self) -> u8 {
+        self.hour
+    }
+
+    /// Returns the minute of the [`Time`], from 0 to 59.
+    pub fn minute(self) -> u8 {
+        self.minute
+    }
+}
+
+impl fmt::Display for Time {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}:{:02}", self.hour, self.minute)
+    }
+}
@@ -0,0 +1,1028 @@
+//! Display toast notifications over some content.
+use crate::core::alignment;
+use crate::core::event::{self, Event};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text::{self, Paragraph as _, Text};
+use crate::core::time::{Duration, Instant};
+use crate::core::touch;
+use crate::core::widget::{tree, Operation, Tree};
+use crate::core::window;
+use crate::core::{
+    Background, Border, Clipboard, Color, Element, Layout, Length, Pixels,
+    Point, Rectangle, Shadow, Shell, Size, Theme, Vector, Widget,
+};
+
+/// The default amount of time a [`Toast`] is shown before being dismissed.
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(5);
+
+const PADDING: f32 = 10.0;
+const SPACING: f32 = 6.0;
+const ACCENT_WIDTH: f32 = 4.0;
+const ACTION_PADDING: [f32; 2] = [4.0, 8.0];
+
+/// A notification shown by a [`Manager`].
+#[derive(Debug, Clone)]
+pub struct Toast<Message> {
+    title: String,
+    body: String,
+    severity: Severity,
+    actions: Vec<(String, Message)>,
+    timeout: Timeout,
+}
+
+impl<Message> Toast<Message> {
+    /// Creates a new [`Toast`] with the given title and body.
+    pub fn new(title: impl Into<String>, body: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            body: body.into(),
+            severity: Severity::default(),
+            actions: Vec::new(),
+            timeout: Timeout::Default,
+        }
+    }
+
+    /// Sets the [`Severity`] of the [`Toast`].
+    pub fn severity(mut self, severity: Severity) -> Self {
+        self.severity = severity;
+        self
+    }
+
+    /// Adds an action button to the [`Toast`], producing the given message
+    /// when pressed.
+    ///
+    /// Pressing an action also dismisses the [`Toast`].
+    pub fn action(
+        mut self,
+        label: impl Into<String>,
+        on_press: Message,
+    ) -> Self {
+        self.actions.push((label.into(), on_press));
+        self
+    }
+
+    /// Sets the amount of time the [`Toast`] is shown before being
+    /// dismissed, overriding the timeout of the [`Manager`].
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Timeout::After(timeout);
+        self
+    }
+
+    /// Makes the [`Toast`] stay until it is dismissed by the user.
+    pub fn persistent(mut self) -> Self {
+        self.timeout = Timeout::Never;
+        self
+    }
+
+    /// Returns the title of the [`Toast`].
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    /// Returns the body of the [`Toast`].
+    pub fn body(&self) -> &str {
+        &self.body
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Timeout {
+    Default,
+    After(Duration),
+    Never,
+}
+
+/// The severity of a [`Toast`], which determines its style.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Severity {
+    /// A primary notification.
+    #[default]
+    Primary,
+    /// A secondary notification, of lesser importance.
+    Secondary,
+    /// A notification of a successful operation.
+    Success,
+    /// A notification of an error or a dangerous situation.
+    Danger,
+}
+
+/// The position of the toasts of a [`Manager`], relative to its content.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Position {
+    /// The top left corner.
+    TopLeft,
+    /// The center of the top edge.
+    Top,
+    /// The top right corner.
+    TopRight,
+    /// The center of the right edge.
+    Right,
+    /// The bottom right corner.
+    #[default]
+    BottomRight,
+    /// The center of the bottom edge.
+    Bottom,
+    /// The bottom left corner.
+    BottomLeft,
+    /// The center of the left edge.
+    Left,
+}
+
+/// A widget that shows a stack of [`Toast`] notifications over some content.
+///
+/// Toasts are dismissed automatically after their timeout, which is paused
+/// while the cursor is over them. Every time a [`Toast`] is dismissed,
+/// either automatically or by the user, the [`Manager`] produces a message
+/// with its index so that you can remove it from your list.
+///
+/// The [`Manager`] keeps track of the remaining time of each [`Toast`] by
+/// its index. Therefore, the list of toasts must only change by removing
+/// the dismissed toasts and by pushing new ones to its end. Otherwise, the
+/// remaining times may be applied to the wrong toasts.
+#[allow(missing_debug_implementations)]
+pub struct Manager<
+    'a,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> where
+    Renderer: text::Renderer,
+{
+    content: Element<'a, Message, Theme, Renderer>,
+    toasts: &'a [Toast<Message>],
+    on_close: Box<dyn Fn(usize) -> Message + 'a>,
+    position: Position,
+    timeout: Duration,
+    max_visible: Option<usize>,
+    width: f32,
+    spacing: f32,
+    text_size: Option<Pixels>,
+    font: Option<Renderer::Font>,
+    style: Style<Theme>,
+}
+
+impl<'a, Message, Theme, Renderer> Manager<'a, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    /// The default width of the toasts of a [`Manager`].
+    pub const DEFAULT_WIDTH: f32 = 300.0;
+
+    /// Creates a new [`Manager`] showing the given toasts over some content,
+    /// producing a message with the index of a [`Toast`] when it is
+    /// dismissed.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        toasts: &'a [Toast<Message>],
+        on_close: impl Fn(usize) -> Message + 'a,
+    ) -> Self
+    where
+        Theme: DefaultStyle,
+    {
+        Self {
+            content: content.into(),
+            toasts,
+            on_close: Box::new(on_close),
+            position: Position::default(),
+            timeout: DEFAULT_TIMEOUT,
+            max_visible: None,
+            width: Self::DEFAULT_WIDTH,
+            spacing: 10.0,
+            text_size: None,
+            font: None,
+            style: Theme::default_style(),
+        }
+    }
+
+    /// Sets the [`Position`] of the toasts of the [`Manager`].
+    pub fn position(mut self, position: Position) -> Self {
+        self.position = position;
+        self
+    }
+
+    /// Sets the amount of time each [`Toast`] is shown before being
+    /// dismissed, unless it has its own.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Sets the maximum amount of toasts visible at the same time.
+    ///
+    /// The rest of toasts are queued, and their timeout does not start
+    /// until they become visible.
+    pub fn max_visible(mut self, max_visible: usize) -> Self {
+        self.max_visible = Some(max_visible);
+        self
+    }
+
+    /// Sets the width of the toasts of the [`Manager`].
+    pub fn width(mut self, width: impl Into<Pixels>) -> Self {
+        self.width = width.into().0;
+        self
+    }
+
+    /// Sets the spacing between the toasts of the [`Manager`].
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    /// Sets the text size of the toasts of the [`Manager`].
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(size.into());
+        self
+    }
+
+    /// Sets the font of the toasts of the [`Manager`].
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the style of the toasts of the [`Manager`].
+    pub fn style(mut self, style: fn(&Theme, Severity) -> Appearance) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+/// Local state of the [`Manager`].
+#[derive(Debug, Default)]
+struct State {
+    timers: Vec<Timer>,
+    last_tick: Option<Instant>,
+    hovered: Option<usize>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Timer {
+    remaining: Option<Duration>,
+    is_closed: bool,
+}
+
+impl State {
+    fn sync<Message>(&mut self, toasts: &[Toast<Message>], timeout: Duration) {
+        // Closed toasts are removed by the application, so dropping their
+        // timers keeps the rest aligned
+        self.timers.retain(|timer| !timer.is_closed);
+        self.timers.truncate(toasts.len());
+
+        let new = toasts[self.timers.len()..].iter().map(|toast| Timer {
+            remaining: match toast.timeout {
+                Timeout::Default => Some(timeout),
+                Timeout::After(timeout) => Some(timeout),
+                Timeout::Never => None,
+            },
+            is_closed: false,
+        });
+
+        self.timers.extend(new);
+    }
+
+    /// Advances the timers of the visible toasts, except the hovered one,
+    /// returning the index of the first expired one.
+    fn tick(&mut self, now: Instant, visible: usize) -> Option<usize> {
+        let elapsed = self
+            .last_tick
+            .map(|last_tick| now.saturating_duration_since(last_tick))
+            .unwrap_or_default();
+
+        self.last_tick = Some(now);
+
+        for (index, timer) in self.timers.iter_mut().take(visible).enumerate() {
+            if self.hovered == Some(index) {
+                continue;
+            }
+
+            if let Some(remaining) = &mut timer.remaining {
+                *remaining = remaining.saturating_sub(elapsed);
+            }
+        }
+
+        self.timers.iter().take(visible).position(|timer| {
+            !timer.is_closed && timer.remaining == Some(Duration::ZERO)
+        })
+    }
+
+    /// Returns the time left until the next visible toast expires.
+    fn next_expiration(&self, visible: usize) -> Option<Duration> {
+        self.timers
+            .iter()
+            .take(visible)
+            .enumerate()
+            .filter(|(index, timer)| {
+                !timer.is_closed && self.hovered != Some(*index)
+            })
+            .filter_map(|(_, timer)| timer.remaining)
+            .min()
+    }
+
+    fn is_closing(&self) -> bool {
+        self.timers.iter().any(|timer| timer.is_closed)
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Manager<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        let mut state = State::default();
+        state.sync(self.toasts, self.timeout);
+
+        tree::State::new(state)
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.state
+            .downcast_mut::<State>()
+            .sync(self.toasts, self.timeout);
+
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let content = self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            translation,
+        );
+
+        let visible = self
+            .max_visible
+            .map_or(self.toasts.len(), |max| max.min(self.toasts.len()));
+
+        let toasts = (visible > 0).then(|| {
+            overlay::Element::new(Box::new(Overlay {
+                state: tree.state.downcast_mut::<State>(),
+                toasts: &self.toasts[..visible],
+                on_close: &self.on_close,
+                bounds: layout.bounds() + translation,
+                position: self.position,
+                width: self.width,
+                spacing: self.spacing,
+                text_size: self
+                    .text_size
+                    .unwrap_or_else(|| renderer.default_size()),
+                font: self.font.unwrap_or_else(|| renderer.default_font()),
+                style: self.style,
+            }))
+        });
+
+        match (content, toasts) {
+            (Some(content), Some(toasts)) => Some(
+                overlay::Group::with_children(vec![content, toasts]).overlay(),
+            ),
+            (content, toasts) => content.or(toasts),
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Manager<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(manager: Manager<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(manager)
+    }
+}
+
+/// The visible toasts of a [`Manager`].
+struct Overlay<'a, 'b, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    state: &'b mut State,
+    toasts: &'a [Toast<Message>],
+    on_close: &'b dyn Fn(usize) -> Message,
+    bounds: Rectangle,
+    position: Position,
+    width: f32,
+    spacing: f32,
+    text_size: Pixels,
+    font: Renderer::Font,
+    style: Style<Theme>,
+}
+
+impl<'a, 'b, Message, Theme, Renderer> Overlay<'a, 'b, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    fn text<'c>(
+        &self,
+        content: &'c str,
+        bounds: Size,
+    ) -> Text<'c, Renderer::Font> {
+        Text {
+            content,
+            bounds,
+            size: self.text_size,
+            line_height: text::LineHeight::default(),
+            font: self.font,
+            horizontal_alignment: alignment::Horizontal::Left,
+            vertical_alignment: alignment::Vertical::Top,
+            shaping: text::Shaping::Advanced,
+        }
+    }
+
+    fn measure(&self, content: &str, max_width: f32) -> Size {
+        Renderer::Paragraph::with_text(
+            self.text(content, Size::new(max_width, f32::INFINITY)),
+        )
+        .min_bounds()
+    }
+
+    /// Lays out a [`Toast`], whose children are its title, its close button,
+    /// its body, and its actions.
+    fn layout_toast(&self, toast: &Toast<Message>) -> layout::Node {
+        let left = ACCENT_WIDTH + PADDING;
+        let inner_width = (self.width - left - PADDING).max(0.0);
+
+        let close = self.measure("×", f32::INFINITY);
+        let title = self.measure(
+            &toast.title,
+            (inner_width - close.width - SPACING).max(0.0),
+        );
+        let body = self.measure(&toast.body, inner_width);
+
+        let header_height = title.height.max(close.height);
+        let body_y = PADDING + header_height + SPACING;
+
+        let mut children = vec![
+            layout::Node::new(title).move_to(Point::new(left, PADDING)),
+            layout::Node::new(close).move_to(Point::new(
+                self.width - PADDING - close.width,
+                PADDING,
+            )),
+            layout::Node::new(body).move_to(Point::new(left, body_y)),
+        ];
+
+        let mut height = body_y + body.height + PADDING;
+
+        if !toast.actions.is_empty() {
+            let [vertical, horizontal] = ACTION_PADDING;
+            let y = height - PADDING + SPACING;
+            let mut x = self.width - PADDING;
+            let mut action_height = 0.0f32;
+
+            let actions: Vec<_> = toast
+                .actions
+                .iter()
+                .rev()
+                .map(|(label, _)| {
+                    let label = self.measure(label, f32::INFINITY);
+                    let size = Size::new(
+                        label.width + horizontal * 2.0,
+                        label.height + vertical * 2.0,
+                    );
+
+                    x -= size.width;
+                    action_height = action_height.max(size.height);
+
+                    let node =
+                        layout::Node::new(size).move_to(Point::new(x, y));
+
+                    x -= SPACING;
+
+                    node
+                })
+                .collect();
+
+            children.extend(actions.into_iter().rev());
+            height = y + action_height + PADDING;
+        }
+
+        layout::Node::with_children(Size::new(self.width, height), children)
+    }
+
+    fn dismiss(&mut self, index: usize, shell: &mut Shell<'_, Message>) {
+        if let Some(timer) = self.state.timers.get_mut(index) {
+            timer.is_closed = true;
+        }
+
+        shell.publish((self.on_close)(index));
+    }
+}
+
+impl<'a, 'b, Message, Theme, Renderer>
+    overlay::Overlay<Message, Theme, Renderer>
+    for Overlay<'a, 'b, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: text::Renderer,
+{
+    fn layout(&mut self, _renderer: &Renderer, bounds: Size) -> layout::Node {
+        let toasts: Vec<_> = self
+            .toasts
+            .iter()
+            .map(|toast| self.layout_toast(toast))
+            .collect();
+
+        let total_height =
+            toasts.iter().map(|toast| toast.size().height).sum::<f32>()
+                + self.spacing * toasts.len().saturating_sub(1) as f32;
+
+        let area = Rectangle {
+            x: self.bounds.x + self.spacing,
+            y: self.bounds.y + self.spacing,
+            width: (self.bounds.width - self.spacing * 2.0).max(0.0),
+            height: (self.bounds.height - self.spacing * 2.0).max(0.0),
+        };
+
+        let x = match self.position {
+            Position::TopLeft | Position::Left | Position::BottomLeft => area.x,
+            Position::Top | Position::Bottom => {
+                area.center_x() - self.width / 2.0
+            }
+            Position::TopRight | Position::Right | Position::BottomRight => {
+                area.x + area.width - self.width
+            }
+        };
+
+        let mut y = match self.position {
+            Position::TopLeft | Position::Top | Position::TopRight => area.y,
+            Position::Left | Position::Right => {
+                area.center_y() - total_height / 2.0
+            }
+            Position::BottomLeft | Position::Bottom | Position::BottomRight => {
+                area.y + area.height - total_height
+            }
+        };
+
+        let children = toasts
+            .into_iter()
+            .map(|toast| {
+                let height = toast.size().height;
+                let toast = toast.move_to(Point::new(x, y));
+
+                y += height + self.spacing;
+
+                toast
+            })
+            .collect();
+
+        layout::Node::with_children(bounds, children)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let visible = self.toasts.len();
+
+        let now = match event {
+            Event::Window(_, window::Event::RedrawRequested(now)) => now,
+            _ => Instant::now(),
+        };
+
+        // Only one toast is dismissed at a time, since the indices of the
+        // rest change once the application removes it
+        if !self.state.is_closing() {
+            if let Some(index) = self.state.tick(now, visible) {
+                self.dismiss(index, shell);
+            }
+        } else {
+            let _ = self.state.tick(now, visible);
+        }
+
+        self.state.hovered = cursor.position().and_then(|position| {
+            layout
+                .children()
+                .position(|toast| toast.bounds().contains(position))
+        });
+
+        if let Some(remaining) = self.state.next_expiration(visible) {
+            shell.request_redraw(window::RedrawRequest::At(now + remaining));
+        }
+
+        let (Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerPressed { .. })) = event
+        else {
+            return event::Status::Ignored;
+        };
+
+        let Some(position) = cursor.position() else {
+            return event::Status::Ignored;
+        };
+
+        for (index, (toast, layout)) in
+            self.toasts.iter().zip(layout.children()).enumerate()
+        {
+            if !layout.bounds().contains(position) {
+                continue;
+            }
+
+            if !self.state.is_closing() {
+                let mut parts = layout.children().skip(1);
+
+                if parts
+                    .next()
+                    .is_some_and(|close| close.bounds().contains(position))
+                {
+                    self.dismiss(index, shell);
+                }
+
+                for ((_, on_press), action) in
+                    toast.actions.iter().zip(parts.skip(1))
+                {
+                    if action.bounds().contains(position) {
+                        shell.publish(on_press.clone());
+                        self.dismiss(index, shell);
+                    }
+                }
+            }
+
+            return event::Status::Captured;
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let is_over_button = layout.children().any(|toast| {
+            toast.children().enumerate().any(|(i, part)| {
+                i != 0 && i != 2 && cursor.is_over(part.bounds())
+            })
+        });
+
+        if is_over_button {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) {
+        for (toast, layout) in self.toasts.iter().zip(layout.children()) {
+            let bounds = layout.bounds();
+            let appearance = (self.style)(theme, toast.severity);
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border: appearance.border,
+                    shadow: appearance.shadow,
+                },
+                appearance.background,
+            );
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        width: ACCENT_WIDTH,
+                        ..bounds
+                    },
+                    border: Border {
+                        radius: appearance.border.radius,
+                        ..Border::default()
+                    },
+                    ..renderer::Quad::default()
+                },
+                appearance.accent,
+            );
+
+            let mut parts = layout.children();
+
+            let draw_text = |renderer: &mut Renderer,
+                             content: &str,
+                             part: Layout<'_>,
+                             color: Color| {
+                let part = part.bounds();
+
+                renderer.fill_text(
+                    self.text(content, part.size()),
+                    part.position(),
+                    color,
+                    bounds,
+                );
+            };
+
+            if let Some(title) = parts.next() {
+                draw_text(renderer, &toast.title, title, appearance.text_color);
+            }
+
+            if let Some(close) = parts.next() {
+                let color = if cursor.is_over(close.bounds()) {
+                    appearance.accent
+                } else {
+                    appearance.text_color.scale_alpha(0.6)
+                };
+
+                draw_text(renderer, "×", close, color);
+            }
+
+            if let Some(body) = parts.next() {
+                draw_text(renderer, &toast.body, body, appearance.text_color);
+            }
+
+            for ((label, _), action) in toast.actions.iter().zip(parts) {
+                let action_bounds = action.bounds();
+
+                if cursor.is_over(action_bounds) {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: action_bounds,
+                            border: Border {
+                                radius: appearance.border.radius,
+                                ..Border::default()
+                            },
+                            ..renderer::Quad::default()
+                        },
+                        appearance.accent.scale_alpha(0.15),
+                    );
+                }
+
+                let [vertical, horizontal] = ACTION_PADDING;
+
+                renderer.fill_text(
+                    self.text(label, action_bounds.size()),
+                    action_bounds.position()
+                        + Vector::new(horizontal, vertical),
+                    appearance.accent,
+                    bounds,
+                );
+            }
+        }
+    }
+
+    fn is_over(
+        &self,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        cursor_position: Point,
+    ) -> bool {
+        layout
+            .children()
+            .any(|toast| toast.bounds().contains(cursor_position))
+    }
+}
+
+/// The appearance of the toasts of a [`Manager`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Appearance {
+    /// The [`Background`] of a toast.
+    pub background: Background,
+    /// The text [`Color`] of a toast.
+    pub text_color: Color,
+    /// The [`Color`] of the accent bar and the actions of a toast.
+    pub accent: Color,
+    /// The [`Border`] of a toast.
+    pub border: Border,
+    /// The [`Shadow`] of a toast.
+    pub shadow: Shadow,
+}
+
+/// The style of the toasts of a [`Manager`].
+pub type Style<Theme> = fn(&Theme, Severity) -> Appearance;
+
+/// The default style of the toasts of a [`Manager`].
+pub trait DefaultStyle {
+    /// Returns the default style of the toasts of a [`Manager`].
+    fn default_style() -> Style<Self>;
+}
+
+impl DefaultStyle for Theme {
+    fn default_style() -> Style<Self> {
+        default
+    }
+}
+
+impl DefaultStyle for Appearance {
+    fn default_style() -> Style<Self> {
+        |appearance, _severity| *appearance
+    }
+}
+
+/// The default style of the toasts of a [`Manager`], using the pairs of the
+/// extended palette of the [`Theme`] for each [`Severity`].
+pub fn default(theme: &Theme, severity: Severity) -> Appearance {
+    let palette = theme.extended_palette();
+
+    let accent = match severity {
+        Severity::Primary => palette.primary.base.color,
+        Severity::Secondary => palette.secondary.base.color,
+        Severity::Success => palette.success.base.color,
+        Severity::Danger => palette.danger.base.color,
+    };
+
+    Appearance {
+        background: palette.background.base.color.into(),
+        text_color: palette.background.base.text,
+        accent,
+        border: Border {
+            width: 1.0,
+            radius: 4.0.into(),
+            color: palette.background.strong.color,
+        },
+        shadow: Shadow {
+            color: Color::BLACK.scale_alpha(0.2),
+            offset: Vector::new(0.0, 2.0),
+            blur_radius: 8.0,
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn toasts(count: usize) -> Vec<Toast<()>> {
+        (0..count)
+            .map(|i| Toast::new(format!("Toast {i}"), "Body"))
+            .collect()
+    }
+
+    #[test]
+    fn timers_pause_on_hover() {
+        let toasts = toasts(2);
+        let mut state = State::default();
+        let start = Instant::now();
+
+        state.sync(&toasts, Duration::from_secs(5));
+        state.hovered = Some(0);
+
+        assert_eq!(state.tick(start, 2), None);
+        assert_eq!(state.tick(start + Duration::from_secs(5), 2), Some(1));
+        assert_eq!(
+            state.timers[0].remaining,
+            Some(Duration::from_secs(5)),
+            "The hovered toast must be paused"
+        );
+
+        state.hovered = None;
+
+        assert_eq!(state.tick(start + Duration::from_secs(10), 2), Some(0));
+    }
+
+    #[test]
+    fn queued_toasts_wait() {
+        let toasts = toasts(3);
+        let mut state = State::default();
+        let start = Instant::now();
+
+        state.sync(&toasts, Duration::from_secs(5));
+
+        let _ = state.tick(start, 1);
+        assert_eq!(state.tick(start + Duration::from_secs(5), 1), Some(0));
+        assert_eq!(state.timers[1].remaining, Some(Duration::from_secs(5)));
+        assert_eq!(state.next_expiration(1), Some(Duration::ZERO));
+    }
+
+    #[test]
+    fn sync_drops_closed_toasts() {
+        let mut toasts = toasts(3);
+        let mut state = State::default();
+
+        state.sync(&toasts, Duration::from_secs(5));
+        state.timers[1].is_closed = true;
+        state.timers[2].remaining = None;
+
+        assert!(state.is_closing());
+
+        let _ = toasts.remove(1);
+        state.sync(&toasts, Duration::from_secs(5));
+
+        assert!(!state.is_closing());
+        assert_eq!(state.timers.len(), 2);
+        assert_eq!(state.timers[1].remaining, None);
+
+        toasts.push(Toast::new("New", "Body").persistent());
+        state.sync(&toasts, Duration::from_secs(5));
+
+        assert_eq!(state.timers.len(), 3);
+        assert_eq!(state.timers[2].remaining, None);
+    }
+}
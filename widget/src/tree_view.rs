@@ -0,0 +1,1083 @@
+//! Display hierarchical data with expandable nodes.
+use crate::core::alignment;
+use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::mouse::click;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text::{self, Text};
+use crate::core::time::{Duration, Instant};
+use crate::core::touch;
+use crate::core::widget::operation::{self, Operation};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::{
+    Background, Border, Clipboard, Color, Element, Layout, Length, Padding,
+    Pixels, Point, Rectangle, Shell, Size, Theme, Vector, Widget,
+};
+
+/// A widget that displays a hierarchy of nodes that can be expanded and
+/// collapsed.
+///
+/// The expanded nodes and the selected node of a [`TreeView`] are owned by
+/// your application. The children of a [`Node`] are only built when the
+/// [`Node`] is expanded.
+#[allow(missing_debug_implementations)]
+pub struct TreeView<
+    'a,
+    Key,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> {
+    entries: Vec<Entry<'a, Key, Message, Theme, Renderer>>,
+    selected: Option<Key>,
+    width: Length,
+    indent: f32,
+    padding: Padding,
+    on_expand: Option<Box<dyn Fn(Key) -> Message + 'a>>,
+    on_collapse: Option<Box<dyn Fn(Key) -> Message + 'a>>,
+    on_select: Option<Box<dyn Fn(Key) -> Message + 'a>>,
+    on_activate: Option<Box<dyn Fn(Key) -> Message + 'a>>,
+    style: Style<Theme>,
+}
+
+impl<'a, Key, Message, Theme, Renderer>
+    TreeView<'a, Key, Message, Theme, Renderer>
+where
+    Key: Clone + PartialEq,
+    Renderer: text::Renderer,
+{
+    /// The default indentation of each level of a [`TreeView`].
+    pub const DEFAULT_INDENT: f32 = 16.0;
+
+    /// The default [`Padding`] of each node of a [`TreeView`].
+    pub const DEFAULT_PADDING: Padding = Padding {
+        top: 2.0,
+        right: 4.0,
+        bottom: 2.0,
+        left: 4.0,
+    };
+
+    /// Creates a new [`TreeView`] with the given root nodes.
+    pub fn new(
+        roots: impl IntoIterator<Item = Node<'a, Key, Message, Theme, Renderer>>,
+    ) -> Self
+    where
+        Theme: DefaultStyle,
+    {
+        let mut entries = Vec::new();
+
+        for root in roots {
+            flatten(root, 0, None, &mut entries);
+        }
+
+        Self {
+            entries,
+            selected: None,
+            width: Length::Fill,
+            indent: Self::DEFAULT_INDENT,
+            padding: Self::DEFAULT_PADDING,
+            on_expand: None,
+            on_collapse: None,
+            on_select: None,
+            on_activate: None,
+            style: Theme::default_style(),
+        }
+    }
+
+    /// Sets the key of the selected [`Node`] of the [`TreeView`].
+    pub fn selected(mut self, key: Option<Key>) -> Self {
+        self.selected = key;
+        self
+    }
+
+    /// Sets the width of the [`TreeView`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the indentation of each level of the [`TreeView`].
+    pub fn indent(mut self, indent: impl Into<Pixels>) -> Self {
+        self.indent = indent.into().0;
+        self
+    }
+
+    /// Sets the [`Padding`] of each node of the [`TreeView`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the message that should be produced when a [`Node`] is expanded.
+    pub fn on_expand(mut self, f: impl Fn(Key) -> Message + 'a) -> Self {
+        self.on_expand = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the message that should be produced when a [`Node`] is
+    /// collapsed.
+    pub fn on_collapse(mut self, f: impl Fn(Key) -> Message + 'a) -> Self {
+        self.on_collapse = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the message that should be produced when a [`Node`] is selected.
+    pub fn on_select(mut self, f: impl Fn(Key) -> Message + 'a) -> Self {
+        self.on_select = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the message that should be produced when a [`Node`] is
+    /// activated; either by double clicking it or by pressing Enter while
+    /// it is selected.
+    pub fn on_activate(mut self, f: impl Fn(Key) -> Message + 'a) -> Self {
+        self.on_activate = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the style of the [`TreeView`].
+    pub fn style(mut self, style: fn(&Theme, Status) -> Appearance) -> Self {
+        self.style = style;
+        self
+    }
+
+    fn selected_index(&self) -> Option<usize> {
+        let selected = self.selected.as_ref()?;
+
+        self.entries.iter().position(|entry| &entry.key == selected)
+    }
+}
+
+/// A node of a [`TreeView`].
+#[allow(missing_debug_implementations)]
+pub struct Node<
+    'a,
+    Key,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> {
+    key: Key,
+    label: Element<'a, Message, Theme, Renderer>,
+    name: Option<String>,
+    is_expanded: bool,
+    children: Option<Children<'a, Key, Message, Theme, Renderer>>,
+}
+
+type Children<'a, Key, Message, Theme, Renderer> =
+    Box<dyn FnOnce() -> Vec<Node<'a, Key, Message, Theme, Renderer>> + 'a>;
+
+impl<'a, Key, Message, Theme, Renderer>
+    Node<'a, Key, Message, Theme, Renderer>
+{
+    /// Creates a new [`Node`] with the given key and label.
+    ///
+    /// A [`Node`] is a leaf until [`Node::children`] is set.
+    pub fn new(
+        key: Key,
+        label: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            key,
+            label: label.into(),
+            name: None,
+            is_expanded: false,
+            children: None,
+        }
+    }
+
+    /// Sets the name of the [`Node`], used to find it by typing while the
+    /// [`TreeView`] is focused.
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Sets whether the [`Node`] is expanded.
+    pub fn expanded(mut self, is_expanded: bool) -> Self {
+        self.is_expanded = is_expanded;
+        self
+    }
+
+    /// Sets the function that produces the children of the [`Node`].
+    ///
+    /// The function will only be called if the [`Node`] is expanded.
+    pub fn children(
+        mut self,
+        children: impl FnOnce() -> Vec<Node<'a, Key, Message, Theme, Renderer>> + 'a,
+    ) -> Self {
+        self.children = Some(Box::new(children));
+        self
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Leaf,
+    Collapsed,
+    Expanded,
+}
+
+struct Entry<'a, Key, Message, Theme, Renderer> {
+    key: Key,
+    label: Element<'a, Message, Theme, Renderer>,
+    name: Option<String>,
+    depth: usize,
+    parent: Option<usize>,
+    kind: Kind,
+}
+
+fn flatten<'a, Key, Message, Theme, Renderer>(
+    node: Node<'a, Key, Message, Theme, Renderer>,
+    depth: usize,
+    parent: Option<usize>,
+    entries: &mut Vec<Entry<'a, Key, Message, Theme, Renderer>>,
+) {
+    let index = entries.len();
+
+    let (kind, children) = match node.children {
+        None => (Kind::Leaf, None),
+        Some(_) if !node.is_expanded => (Kind::Collapsed, None),
+        Some(children) => (Kind::Expanded, Some(children)),
+    };
+
+    entries.push(Entry {
+        key: node.key,
+        label: node.label,
+        name: node.name,
+        depth,
+        parent,
+        kind,
+    });
+
+    for child in children.map(|children| children()).unwrap_or_default() {
+        flatten(child, depth + 1, Some(index), entries);
+    }
+}
+
+/// The time that must pass after a key press for type-ahead to start a new
+/// search.
+const TYPEAHEAD_TIMEOUT: Duration = Duration::from_millis(1000);
+
+#[derive(Debug, Clone, Default)]
+struct State {
+    is_focused: bool,
+    last_click: Option<mouse::Click>,
+    typeahead: String,
+    typed_at: Option<Instant>,
+}
+
+impl State {
+    /// Appends the given text to the type-ahead query, starting a new query
+    /// if more than [`TYPEAHEAD_TIMEOUT`] passed since the last key press.
+    fn type_ahead(&mut self, text: &str, now: Instant) {
+        if !self.typed_at.is_some_and(|typed_at| {
+            now.duration_since(typed_at) <= TYPEAHEAD_TIMEOUT
+        }) {
+            self.typeahead.clear();
+        }
+
+        self.typeahead.push_str(&text.to_lowercase());
+        self.typed_at = Some(now);
+    }
+}
+
+/// The effect of a navigation key in a [`TreeView`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Action {
+    Select(usize),
+    Toggle(usize),
+}
+
+/// Returns the [`Action`] of the given navigation key, given the index of
+/// the selected entry.
+fn navigate<Key, Message, Theme, Renderer>(
+    entries: &[Entry<'_, Key, Message, Theme, Renderer>],
+    selected: Option<usize>,
+    key: key::Named,
+) -> Option<Action> {
+    let last = entries.len().checked_sub(1)?;
+
+    match key {
+        key::Named::ArrowUp => Some(Action::Select(
+            selected.map_or(last, |index| index.saturating_sub(1)),
+        )),
+        key::Named::ArrowDown => Some(Action::Select(
+            selected.map_or(0, |index| (index + 1).min(last)),
+        )),
+        key::Named::Home => Some(Action::Select(0)),
+        key::Named::End => Some(Action::Select(last)),
+        key::Named::ArrowRight => {
+            let index = selected?;
+
+            match entries[index].kind {
+                Kind::Collapsed => Some(Action::Toggle(index)),
+                Kind::Expanded
+                    if entries
+                        .get(index + 1)
+                        .is_some_and(|child| child.parent == Some(index)) =>
+                {
+                    Some(Action::Select(index + 1))
+                }
+                _ => None,
+            }
+        }
+        key::Named::ArrowLeft => {
+            let index = selected?;
+            let entry = &entries[index];
+
+            if entry.kind == Kind::Expanded {
+                Some(Action::Toggle(index))
+            } else {
+                entry.parent.map(Action::Select)
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Returns the index of the next entry whose name starts with the given
+/// type-ahead query, wrapping around.
+///
+/// A new search starts after the selected entry, while a longer one may
+/// still match it.
+fn find_by_name<Key, Message, Theme, Renderer>(
+    entries: &[Entry<'_, Key, Message, Theme, Renderer>],
+    selected: Option<usize>,
+    query: &str,
+) -> Option<usize> {
+    let start = match selected {
+        Some(index) if query.chars().count() == 1 => index + 1,
+        Some(index) => index,
+        None => 0,
+    };
+
+    let count = entries.len();
+
+    (0..count).map(|i| (start + i) % count).find(|i| {
+        entries[*i]
+            .name
+            .as_ref()
+            .is_some_and(|name| name.to_lowercase().starts_with(query))
+    })
+}
+
+impl operation::Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
+impl<'a, Key, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for TreeView<'a, Key, Message, Theme, Renderer>
+where
+    Key: Clone + PartialEq,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.entries
+            .iter()
+            .map(|entry| Tree::new(&entry.label))
+            .collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let labels: Vec<_> =
+            self.entries.iter().map(|entry| &entry.label).collect();
+
+        tree.diff_children(&labels);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(Length::Shrink);
+        let max = limits.max();
+
+        let mut y = 0.0;
+        let mut width: f32 = 0.0;
+
+        let rows = self
+            .entries
+            .iter()
+            .zip(&mut tree.children)
+            .map(|(entry, tree)| {
+                let offset =
+                    self.padding.left + self.indent * (entry.depth + 1) as f32;
+
+                let label = entry.label.as_widget().layout(
+                    tree,
+                    renderer,
+                    &layout::Limits::new(
+                        Size::ZERO,
+                        Size::new(
+                            (max.width - offset - self.padding.right).max(0.0),
+                            max.height,
+                        ),
+                    ),
+                );
+
+                let size = label.size();
+                let height = (size.height + self.padding.vertical())
+                    .max(renderer.default_size().0);
+
+                width = width.max(offset + size.width + self.padding.right);
+
+                let row = layout::Node::with_children(
+                    Size::new(max.width, height),
+                    vec![label.move_to(Point::new(
+                        offset,
+                        (height - size.height) / 2.0,
+                    ))],
+                )
+                .move_to(Point::new(0.0, y));
+
+                y += height;
+
+                row
+            })
+            .collect();
+
+        let size =
+            limits.resolve(self.width, Length::Shrink, Size::new(width, y));
+
+        layout::Node::with_children(size, rows)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        operation.focusable(state, None);
+
+        operation.container(None, layout.bounds(), &mut |operation| {
+            self.entries
+                .iter()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .for_each(|((entry, state), row)| {
+                    entry.label.as_widget().operate(
+                        state,
+                        row.children().next().unwrap(),
+                        renderer,
+                        operation,
+                    );
+                });
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        if let Event::Mouse(mouse::Event::ButtonPressed(_))
+        | Event::Touch(touch::Event::FingerPressed { .. }) = event
+        {
+            let state = tree.state.downcast_mut::<State>();
+
+            state.is_focused = cursor.is_over(layout.bounds());
+        }
+
+        let status = self
+            .entries
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .map(|((entry, state), row)| {
+                entry.label.as_widget_mut().on_event(
+                    state,
+                    event.clone(),
+                    row.children().next().unwrap(),
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge);
+
+        if status == event::Status::Captured {
+            return event::Status::Captured;
+        }
+
+        let state = tree.state.downcast_mut::<State>();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(position) = cursor.position_over(layout.bounds())
+                else {
+                    return event::Status::Ignored;
+                };
+
+                let Some((index, row)) = layout
+                    .children()
+                    .enumerate()
+                    .find(|(_, row)| row.bounds().contains(position))
+                else {
+                    return event::Status::Ignored;
+                };
+
+                let entry = &self.entries[index];
+                let toggle_x = row.bounds().x
+                    + self.padding.left
+                    + self.indent * entry.depth as f32;
+
+                if entry.kind != Kind::Leaf
+                    && position.x >= toggle_x
+                    && position.x < toggle_x + self.indent
+                {
+                    self.toggle(index, shell);
+
+                    return event::Status::Captured;
+                }
+
+                let click = mouse::Click::new(position, state.last_click);
+
+                match click.kind() {
+                    click::Kind::Single => {
+                        self.select(index, shell);
+                    }
+                    click::Kind::Double | click::Kind::Triple => {
+                        if let Some(on_activate) = &self.on_activate {
+                            shell.publish(on_activate(entry.key.clone()));
+                        }
+                    }
+                }
+
+                state.last_click = Some(click);
+
+                event::Status::Captured
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key,
+                modifiers,
+                ..
+            }) if state.is_focused => {
+                let selected = self.selected_index();
+
+                match key.as_ref() {
+                    keyboard::Key::Named(key::Named::Enter) => {
+                        let Some(index) = selected else {
+                            return event::Status::Ignored;
+                        };
+
+                        if let Some(on_activate) = &self.on_activate {
+                            shell.publish(on_activate(
+                                self.entries[index].key.clone(),
+                            ));
+                        }
+                    }
+                    keyboard::Key::Named(
+                        key::Named::ArrowLeft | key::Named::ArrowRight,
+                    ) if selected.is_none() => {
+                        return event::Status::Ignored;
+                    }
+                    keyboard::Key::Named(
+                        named @ (key::Named::ArrowUp
+                        | key::Named::ArrowDown
+                        | key::Named::ArrowLeft
+                        | key::Named::ArrowRight
+                        | key::Named::Home
+                        | key::Named::End),
+                    ) => match navigate(&self.entries, selected, named) {
+                        Some(Action::Select(index)) => {
+                            self.select(index, shell);
+                        }
+                        Some(Action::Toggle(index)) => {
+                            self.toggle(index, shell);
+                        }
+                        None => {}
+                    },
+                    keyboard::Key::Character(c)
+                        if !modifiers.command() && !modifiers.alt() =>
+                    {
+                        state.type_ahead(c, Instant::now());
+
+                        if let Some(index) = find_by_name(
+                            &self.entries,
+                            selected,
+                            &state.typeahead,
+                        ) {
+                            self.select(index, shell);
+                        }
+                    }
+                    _ => return event::Status::Ignored,
+                }
+
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let interaction = self
+            .entries
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .map(|((entry, state), row)| {
+                entry.label.as_widget().mouse_interaction(
+                    state,
+                    row.children().next().unwrap(),
+                    cursor,
+                    viewport,
+                    renderer,
+                )
+            })
+            .max()
+            .unwrap_or_default();
+
+        if interaction == mouse::Interaction::default()
+            && cursor.is_over(layout.bounds())
+        {
+            mouse::Interaction::Pointer
+        } else {
+            interaction
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+
+        let status = if state.is_focused {
+            Status::Focused
+        } else {
+            Status::Active
+        };
+
+        let appearance = (self.style)(theme, status);
+        let selected = self.selected_index();
+
+        for (index, ((entry, state), row)) in self
+            .entries
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .enumerate()
+        {
+            let bounds = row.bounds();
+
+            if !bounds.intersects(viewport) {
+                continue;
+            }
+
+            let is_selected = selected == Some(index);
+
+            let background = if is_selected {
+                Some(appearance.selected_background)
+            } else if cursor.is_over(bounds) {
+                appearance.hovered_background
+            } else {
+                None
+            };
+
+            if let Some(background) = background {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border: Border::rounded(appearance.border_radius),
+                        ..renderer::Quad::default()
+                    },
+                    background,
+                );
+            }
+
+            let text_color = if is_selected {
+                appearance.selected_text_color
+            } else {
+                style.text_color
+            };
+
+            for level in 0..entry.depth {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: (bounds.x
+                                + self.padding.left
+                                + self.indent * (level as f32 + 0.5))
+                                .round(),
+                            y: bounds.y,
+                            width: 1.0,
+                            height: bounds.height,
+                        },
+                        ..renderer::Quad::default()
+                    },
+                    appearance.guide,
+                );
+            }
+
+            if entry.kind != Kind::Leaf {
+                let size = renderer.default_size();
+
+                renderer.fill_text(
+                    Text {
+                        content: if entry.kind == Kind::Expanded {
+                            "▼"
+                        } else {
+                            "▶"
+                        },
+                        size: Pixels(size.0 * 0.6),
+                        line_height: text::LineHeight::default(),
+                        font: renderer.default_font(),
+                        bounds: Size::new(self.indent, bounds.height),
+                        horizontal_alignment: alignment::Horizontal::Center,
+                        vertical_alignment: alignment::Vertical::Center,
+                        shaping: text::Shaping::Advanced,
+                    },
+                    Point::new(
+                        bounds.x
+                            + self.padding.left
+                            + self.indent * (entry.depth as f32 + 0.5),
+                        bounds.center_y(),
+                    ),
+                    appearance.toggle.unwrap_or(text_color),
+                    bounds,
+                );
+            }
+
+            entry.label.as_widget().draw(
+                state,
+                renderer,
+                theme,
+                &renderer::Style { text_color },
+                row.children().next().unwrap(),
+                cursor,
+                viewport,
+            );
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let children = self
+            .entries
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .filter_map(|((entry, state), row)| {
+                entry.label.as_widget_mut().overlay(
+                    state,
+                    row.children().next().unwrap(),
+                    renderer,
+                    translation,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        (!children.is_empty())
+            .then(|| overlay::Group::with_children(children).overlay())
+    }
+}
+
+impl<'a, Key, Message, Theme, Renderer>
+    TreeView<'a, Key, Message, Theme, Renderer>
+where
+    Key: Clone + PartialEq,
+    Renderer: text::Renderer,
+{
+    fn select(&self, index: usize, shell: &mut Shell<'_, Message>) {
+        if let Some(on_select) = &self.on_select {
+            shell.publish(on_select(self.entries[index].key.clone()));
+        }
+    }
+
+    fn toggle(&self, index: usize, shell: &mut Shell<'_, Message>) {
+        let entry = &self.entries[index];
+
+        let on_toggle = match entry.kind {
+            Kind::Collapsed => &self.on_expand,
+            Kind::Expanded => &self.on_collapse,
+            Kind::Leaf => return,
+        };
+
+        if let Some(on_toggle) = on_toggle {
+            shell.publish(on_toggle(entry.key.clone()));
+        }
+    }
+}
+
+impl<'a, Key, Message, Theme, Renderer>
+    From<TreeView<'a, Key, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Key: Clone + PartialEq + 'a,
+    Message: 'a,
+    Theme: 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(tree_view: TreeView<'a, Key, Message, Theme, Renderer>) -> Self {
+        Self::new(tree_view)
+    }
+}
+
+/// The possible status of a [`TreeView`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The [`TreeView`] can be interacted with.
+    Active,
+    /// The [`TreeView`] is focused and can be navigated with the keyboard.
+    Focused,
+}
+
+/// The appearance of a tree view.
+#[derive(Debug, Clone, Copy)]
+pub struct Appearance {
+    /// The [`Background`] of a hovered node.
+    pub hovered_background: Option<Background>,
+    /// The [`Background`] of the selected node.
+    pub selected_background: Background,
+    /// The text [`Color`] of the selected node.
+    pub selected_text_color: Color,
+    /// The border radius of the background of a node.
+    pub border_radius: f32,
+    /// The [`Color`] of the indentation guides.
+    pub guide: Color,
+    /// The [`Color`] of the expand and collapse toggles, if different from
+    /// the text color.
+    pub toggle: Option<Color>,
+}
+
+/// The style of a [`TreeView`].
+pub type Style<Theme> = fn(&Theme, Status) -> Appearance;
+
+/// The default style of a [`TreeView`].
+pub trait DefaultStyle {
+    /// Returns the default style of a [`TreeView`].
+    fn default_style() -> Style<Self>;
+}
+
+impl DefaultStyle for Theme {
+    fn default_style() -> Style<Self> {
+        default
+    }
+}
+
+impl DefaultStyle for Appearance {
+    fn default_style() -> Style<Self> {
+        |appearance, _status| *appearance
+    }
+}
+
+/// The default style of a [`TreeView`].
+pub fn default(theme: &Theme, status: Status) -> Appearance {
+    let palette = theme.extended_palette();
+
+    let selected = match status {
+        Status::Active => palette.primary.weak,
+        Status::Focused => palette.primary.base,
+    };
+
+    Appearance {
+        hovered_background: Some(palette.background.weak.color.into()),
+        selected_background: selected.color.into(),
+        selected_text_color: selected.text,
+        border_radius: 2.0,
+        guide: palette.background.strong.color,
+        toggle: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::renderer::Null;
+    use crate::Space;
+
+    type TestNode = Node<'static, u32, (), Theme, Null>;
+    type TestEntry = Entry<'static, u32, (), Theme, Null>;
+
+    fn node(key: u32, name: &str) -> TestNode {
+        Node::new(key, Space::new(Length::Shrink, Length::Shrink)).name(name)
+    }
+
+    /// Flattens the following tree:
+    ///
+    /// ```text
+    /// 0 src
+    ///   1 main.rs
+    ///   2 widget
+    ///     3 tree.rs
+    ///   4 lib.rs
+    /// 5 tests (collapsed)
+    /// 6 README
+    /// ```
+    fn entries() -> Vec<TestEntry> {
+        let src = node(0, "src").expanded(true).children(|| {
+            vec![
+                node(1, "main.rs"),
+                node(2, "widget")
+                    .expanded(true)
+                    .children(|| vec![node(3, "tree.rs")]),
+                node(4, "lib.rs"),
+            ]
+        });
+
+        let tests = node(5, "tests")
+            .children(|| panic!("collapsed children must not be built"));
+
+        let mut entries = Vec::new();
+
+        for root in [src, tests, node(6, "README")] {
+            flatten(root, 0, None, &mut entries);
+        }
+
+        entries
+    }
+
+    #[test]
+    fn flatten_tracks_depths_and_parents() {
+        let entries = entries();
+
+        let keys: Vec<_> = entries.iter().map(|entry| entry.key).collect();
+        let depths: Vec<_> = entries.iter().map(|entry| entry.depth).collect();
+        let parents: Vec<_> =
+            entries.iter().map(|entry| entry.parent).collect();
+
+        assert_eq!(keys, [0, 1, 2, 3, 4, 5, 6]);
+        assert_eq!(depths, [0, 1, 1, 2, 1, 0, 0]);
+        assert_eq!(
+            parents,
+            [None, Some(0), Some(0), Some(2), Some(0), None, None]
+        );
+        assert_eq!(entries[5].kind, Kind::Collapsed);
+        assert_eq!(entries[6].kind, Kind::Leaf);
+    }
+
+    #[test]
+    fn arrows_move_the_selection() {
+        let entries = entries();
+        let go = |selected, key| navigate(&entries, selected, key);
+
+        assert_eq!(go(None, key::Named::ArrowDown), Some(Action::Select(0)));
+        assert_eq!(go(None, key::Named::ArrowUp), Some(Action::Select(6)));
+        assert_eq!(go(Some(0), key::Named::ArrowUp), Some(Action::Select(0)));
+        assert_eq!(go(Some(6), key::Named::ArrowDown), Some(Action::Select(6)));
+        assert_eq!(go(Some(3), key::Named::Home), Some(Action::Select(0)));
+        assert_eq!(go(Some(3), key::Named::End), Some(Action::Select(6)));
+        assert_eq!(go(None, key::Named::ArrowLeft), None);
+        assert_eq!(navigate(&entries[..0], None, key::Named::End), None);
+    }
+
+    #[test]
+    fn arrows_expand_collapse_and_go_to_parent() {
+        let entries = entries();
+        let go = |selected, key| navigate(&entries, Some(selected), key);
+
+        // Right expands collapsed nodes and enters expanded ones
+        assert_eq!(go(5, key::Named::ArrowRight), Some(Action::Toggle(5)));
+        assert_eq!(go(2, key::Named::ArrowRight), Some(Action::Select(3)));
+        assert_eq!(go(6, key::Named::ArrowRight), None);
+
+        // Left collapses expanded nodes and goes up from the rest
+        assert_eq!(go(2, key::Named::ArrowLeft), Some(Action::Toggle(2)));
+        assert_eq!(go(3, key::Named::ArrowLeft), Some(Action::Select(2)));
+        assert_eq!(go(4, key::Named::ArrowLeft), Some(Action::Select(0)));
+        assert_eq!(go(6, key::Named::ArrowLeft), None);
+    }
+
+    #[test]
+    fn type_ahead_finds_names() {
+        let entries = entries();
+
+        // New searches start after the selected entry and wrap around
+        assert_eq!(find_by_name(&entries, None, "t"), Some(3));
+        assert_eq!(find_by_name(&entries, Some(3), "t"), Some(5));
+        assert_eq!(find_by_name(&entries, Some(5), "t"), Some(3));
+
+        // Longer searches may keep the selected entry
+        assert_eq!(find_by_name(&entries, Some(3), "tr"), Some(3));
+        assert_eq!(find_by_name(&entries, Some(3), "te"), Some(5));
+        assert_eq!(find_by_name(&entries, Some(0), "x"), None);
+    }
+
+    #[test]
+    fn type_ahead_restarts_after_timeout() {
+        let mut state = State::default();
+        let start = Instant::now();
+
+        state.type_ahead("T", start);
+        state.type_ahead("r", start + Duration::from_millis(500));
+
+        assert_eq!(state.typeahead, "tr");
+
+        state.type_ahead(
+            "m",
+            start + Duration::from_millis(500) + TYPEAHEAD_TIMEOUT,
+        );
+
+        assert_eq!(state.typeahead, "trm");
+
+        state.type_ahead(
+            "l",
+            start + Duration::from_millis(501) + TYPEAHEAD_TIMEOUT * 2,
+        );
+
+        assert_eq!(state.typeahead, "l");
+    }
+}
@@ -2,22 +2,24 @@
 use std::ops::RangeInclusive;
 
 pub use crate::slider::{
-    default, Appearance, DefaultStyle, Handle, HandleShape, Status, Style,
+    default, Appearance, DefaultStyle, Handle, HandleShape, Mapping, Status,
+    Style,
 };
 
-use crate::core;
 use crate::core::event::{self, Event};
 use crate::core::keyboard;
 use crate::core::keyboard::key::{self, Key};
 use crate::core::layout::{self, Layout};
 use crate::core::mouse;
 use crate::core::renderer;
+use crate::core::text;
 use crate::core::touch;
 use crate::core::widget::tree::{self, Tree};
 use crate::core::{
     Border, Clipboard, Element, Length, Pixels, Point, Rectangle, Shell, Size,
     Widget,
 };
+use crate::slider::Ticks;
 
 /// An vertical bar and a handle that selects a single value from a range of
 /// values.
@@ -40,6 +42,9 @@ use crate::core::{
 ///
 /// VerticalSlider::new(0.0..=100.0, value, Message::SliderChanged);
 /// ```
+///
+/// Like a [`Slider`](crate::Slider), it can draw labeled tick marks; in
+/// this case, to its right. Hence, it also needs a [`text::Renderer`].
 #[allow(missing_debug_implementations)]
 pub struct VerticalSlider<'a, T, Message, Theme = crate::Theme> {
     range: RangeInclusive<T>,
@@ -51,6 +56,8 @@ pub struct VerticalSlider<'a, T, Message, Theme = crate::Theme> {
     on_release: Option<Message>,
     width: f32,
     height: Length,
+    ticks: Ticks<'a, T>,
+    mapping: Mapping,
     style: Style<Theme>,
 }
 
@@ -97,6 +104,8 @@ where
             on_release: None,
             width: Self::DEFAULT_WIDTH,
             height: Length::Fill,
+            ticks: Ticks::default(),
+            mapping: Mapping::default(),
             style: Theme::default_style(),
         }
     }
@@ -151,6 +160,27 @@ where
         self.shift_step = Some(shift_step.into());
         self
     }
+
+    /// Sets the [`Mapping`] between the position of the handle and the value
+    /// of the [`VerticalSlider`].
+    pub fn mapping(mut self, mapping: Mapping) -> Self {
+        self.mapping = mapping;
+        self
+    }
+
+    /// Sets the values where tick marks are drawn along the
+    /// [`VerticalSlider`].
+    pub fn ticks(mut self, ticks: impl IntoIterator<Item = T>) -> Self {
+        self.ticks.values = ticks.into_iter().collect();
+        self
+    }
+
+    /// Sets the function used to label the tick marks of the
+    /// [`VerticalSlider`].
+    pub fn tick_label(mut self, label: impl Fn(T) -> String + 'a) -> Self {
+        self.ticks.label = Some(Box::new(label));
+        self
+    }
 }
 
 impl<'a, T, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
@@ -158,7 +188,7 @@ impl<'a, T, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
 where
     T: Copy + Into<f64> + num_traits::FromPrimitive,
     Message: Clone,
-    Renderer: core::Renderer,
+    Renderer: text::Renderer,
 {
     fn tag(&self) -> tree::Tag {
         tree::Tag::of::<State>()
@@ -178,10 +208,14 @@ where
     fn layout(
         &self,
         _tree: &mut Tree,
-        _renderer: &Renderer,
+        renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        layout::atomic(limits, self.width, self.height)
+        layout::atomic(
+            limits,
+            self.width + self.ticks.cross_size(renderer, true),
+            self.height,
+        )
     }
 
     fn on_event(
@@ -221,10 +255,7 @@ where
                     - f64::from(cursor_position.y - bounds.y)
                         / f64::from(bounds.height);
 
-                let steps = (percent * (end - start) / step).round();
-                let value = steps * step + start;
-
-                T::from_f64(value)
+                T::from_f64(self.mapping.locate(percent, start, end, step))
             };
 
             new_value
@@ -238,14 +269,12 @@ where
             }
             .into();
 
-            let steps = (value.into() / step).round();
-            let new_value = step * (steps + 1.0);
-
-            if new_value > (*self.range.end()).into() {
-                return Some(*self.range.end());
-            }
-
-            T::from_f64(new_value)
+            T::from_f64(self.mapping.increment(
+                value.into(),
+                (*self.range.start()).into(),
+                (*self.range.end()).into(),
+                step,
+            ))
         };
 
         let decrement = |value: T| -> Option<T> {
@@ -256,14 +285,12 @@ where
             }
             .into();
 
-            let steps = (value.into() / step).round();
-            let new_value = step * (steps - 1.0);
-
-            if new_value < (*self.range.start()).into() {
-                return Some(*self.range.start());
-            }
-
-            T::from_f64(new_value)
+            T::from_f64(self.mapping.increment(
+                value.into(),
+                (*self.range.start()).into(),
+                (*self.range.end()).into(),
+                -step,
+            ))
         };
 
         let change = |new_value: T| {
@@ -342,14 +369,18 @@ where
         tree: &Tree,
         renderer: &mut Renderer,
         theme: &Theme,
-        _style: &renderer::Style,
+        renderer_style: &renderer::Style,
         layout: Layout<'_>,
         cursor: mouse::Cursor,
-        _viewport: &Rectangle,
+        viewport: &Rectangle,
     ) {
         let state = tree.state.downcast_ref::<State>();
-        let bounds = layout.bounds();
-        let is_mouse_over = cursor.is_over(bounds);
+        let is_mouse_over = cursor.is_over(layout.bounds());
+
+        let bounds = Rectangle {
+            width: self.width,
+            ..layout.bounds()
+        };
 
         let style = (self.style)(
             theme,
@@ -380,13 +411,32 @@ where
             (start.into() as f32, end.into() as f32)
         };
 
-        let offset = if range_start >= range_end {
-            0.0
-        } else {
-            (bounds.height - handle_width) * (value - range_end)
-                / (range_start - range_end)
+        let position = |value: f32| {
+            if range_start >= range_end {
+                0.0
+            } else {
+                (bounds.height - handle_width)
+                    * (1.0
+                        - self.mapping.to_position(
+                            f64::from(value),
+                            f64::from(range_start),
+                            f64::from(range_end),
+                        ) as f32)
+            }
         };
 
+        let offset = position(value);
+
+        self.ticks.draw(
+            renderer,
+            bounds,
+            |value| bounds.y + handle_width / 2.0 + position(value),
+            true,
+            style.rail.colors.1,
+            renderer_style.text_color,
+            viewport,
+        );
+
         let rail_x = bounds.x + bounds.width / 2.0;
 
         renderer.fill_quad(
@@ -465,7 +515,7 @@ where
     T: Copy + Into<f64> + num_traits::FromPrimitive + 'a,
     Message: Clone + 'a,
     Theme: 'a,
-    Renderer: core::Renderer + 'a,
+    Renderer: text::Renderer + 'a,
 {
     fn from(
         slider: VerticalSlider<'a, T, Message, Theme>,